@@ -0,0 +1,124 @@
+//! Counts the allocations each variant makes instead of timing it.
+//!
+//! Every allocation goes through [`Counting`], the numbers are read before and after each scenario
+//! and printed per operation. Growing an allocation in place or by moving it counts as one
+//! allocation of the additional bytes.
+use benchmarks::{variants, Deque, Workload};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting every allocation it hands out.
+struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size.saturating_sub(layout.size()), Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Counts {
+    allocations: usize,
+    bytes: usize,
+}
+
+impl Counts {
+    fn now() -> Self {
+        Self {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+        }
+    }
+
+    fn since(self, start: Counts) -> Self {
+        Self {
+            allocations: self.allocations - start.allocations,
+            bytes: self.bytes - start.bytes,
+        }
+    }
+}
+
+type Big = [usize; 32];
+
+const N: usize = 1000;
+
+/// Pushes `N` elements, then pops and pushes `N` times over, reporting what each of the two took.
+/// `start` has to be set right before the variant is run, so whatever the list and its arena
+/// allocate up front counts towards the pushes.
+struct Scenario {
+    start: Cell<Counts>,
+}
+
+impl Workload<Big> for Scenario {
+    type Output = (Counts, Counts);
+
+    fn run(&self, queue: &mut impl Deque<Big>, n: usize) -> (Counts, Counts) {
+        for _ in 0..n {
+            queue.push_back(Big::default());
+        }
+        let push = Counts::now().since(self.start.get());
+
+        let start = Counts::now();
+        for _ in 0..n {
+            queue.pop_front();
+            queue.push_back(Big::default());
+        }
+        let churn = Counts::now().since(start);
+
+        (push, churn)
+    }
+}
+
+fn main() {
+    println!(
+        "allocations and bytes per operation, {} elements of {} bytes",
+        N,
+        std::mem::size_of::<Big>()
+    );
+    println!(
+        "{:<12} {:>8} {:>10}  {:>8} {:>10}",
+        "", "push", "bytes", "churn", "bytes"
+    );
+
+    let per_op = |counts: Counts| {
+        format!(
+            "{:>8.3} {:>10.1}",
+            counts.allocations as f64 / N as f64,
+            counts.bytes as f64 / N as f64
+        )
+    };
+
+    let scenario = Scenario {
+        start: Cell::new(Counts::now()),
+    };
+    for (name, run) in variants::<Big, _>() {
+        scenario.start.set(Counts::now());
+        let (push, churn) = run(&scenario, N);
+        println!("{:<12} {}  {}", name, per_op(push), per_op(churn));
+    }
+}
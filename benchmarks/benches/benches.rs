@@ -1,140 +1,330 @@
-use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use benchmarks::{variants, Deque, Workload};
+use bumpalo::Bump;
+use criterion::measurement::WallTime;
+use criterion::{
+    black_box, criterion_group, criterion_main, BatchSize, BenchmarkGroup, BenchmarkId, Criterion,
+    Throughput,
+};
 use ghost_cell::GhostToken;
-use std::collections::VecDeque;
-use typed_arena::Arena;
+use std::time::{Duration, Instant};
 
-#[derive(Default)]
-struct Big([usize; 32]);
+type Small = u64;
+type Medium = [usize; 4];
+type Big = [usize; 32];
 
-fn push_back_first_big(n: usize) {
-    let mut list = first::LinkedList::new();
+/// The list lengths the push and pop benchmarks run with, for every payload size.
+const SIZES: [usize; 5] = [100, 1_000, 10_000, 100_000, 1_000_000];
 
-    for _ in 0..n {
-        list.push_back(Big::default());
+/// Adds a benchmark of `workload` with `n` elements for every variant, `elements` is how many
+/// elements one run of it handles.
+fn bench_variants<T: Clone, W: Workload<T, Output = Duration>>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    n: usize,
+    elements: u64,
+    workload: &W,
+) {
+    for (name, run) in variants::<T, W>() {
+        group
+            .bench_with_input(BenchmarkId::new(name, n), &n, |b, &n| {
+                b.iter_custom(|iters| (0..iters).map(|_| run(workload, n)).sum())
+            })
+            .throughput(Throughput::Elements(elements));
     }
 }
 
-fn push_back_second_big(n: usize) {
-    GhostToken::new(|ref mut token| {
-        let mut list = second::LinkedList::new();
+struct PushBack;
+
+impl<T: Default> Workload<T> for PushBack {
+    type Output = Duration;
 
+    fn run(&self, list: &mut impl Deque<T>, n: usize) -> Duration {
+        let start = Instant::now();
         for _ in 0..n {
-            list.push_back(Big::default(), token);
+            list.push_back(T::default());
         }
+        start.elapsed()
+    }
+}
 
-        list.clear(token)
-    });
+/// The three ways elements leave a list that get benchmarked.
+#[derive(Clone, Copy)]
+enum Removal {
+    PopFront,
+    PopBack,
+    /// A queue in steady state, every pushed element is followed by popping the oldest one.
+    PushPop,
 }
 
-fn push_back_third_big(n: usize) {
-    let mut list = third::LinkedList::with_capacity(n);
+/// Fills the list with `n` elements, then times only the removals.
+impl<T: Default> Workload<T> for Removal {
+    type Output = Duration;
+
+    fn run(&self, list: &mut impl Deque<T>, n: usize) -> Duration {
+        for _ in 0..n {
+            list.push_back(T::default());
+        }
 
-    for _ in 0..n {
-        list.push_back(Big::default());
+        let start = Instant::now();
+        match self {
+            Removal::PopFront => {
+                for _ in 0..n {
+                    black_box(list.pop_front());
+                }
+            }
+            Removal::PopBack => {
+                for _ in 0..n {
+                    black_box(list.pop_back());
+                }
+            }
+            Removal::PushPop => {
+                for _ in 0..n {
+                    list.push_back(T::default());
+                    black_box(list.pop_front());
+                }
+            }
+        }
+        start.elapsed()
     }
 }
 
-fn push_back_fourth_big(n: usize) {
+fn push_back_sixth_batch<T: Default>(n: usize) -> Duration {
     GhostToken::new(|ref mut token| {
-        let arena = Arena::with_capacity(n);
-        let mut list = fourth::LinkedList::new(&arena);
+        let list = sixth::LinkedList::with_capacity(n);
 
-        for _ in 0..n {
-            list.push_back(Big::default(), token);
-        }
-    });
+        let start = Instant::now();
+        list.batch(token, |batch| {
+            for _ in 0..n {
+                batch.push_back(T::default());
+            }
+        });
+        start.elapsed()
+    })
 }
 
-fn push_back_fifth_big(n: usize) {
+fn push_back_twelfth_batch<T: Default>(n: usize) -> Duration {
     GhostToken::new(|ref mut token| {
-        let arena = Arena::with_capacity(n);
-        let mut list = fifth::LinkedList::new(&arena);
+        let bump = Bump::new();
+        let mut list = twelfth::LinkedList::new(&bump);
 
-        for _ in 0..n {
-            list.push_back(Big::default(), token);
+        let start = Instant::now();
+        list.extend_batch((0..n).map(|_| T::default()), token);
+        start.elapsed()
+    })
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    push_back_benchmark::<Small>(c, "push_back_small");
+    push_back_benchmark::<Medium>(c, "push_back_medium");
+    push_back_benchmark::<Big>(c, "push_back_big");
+}
+
+fn push_back_benchmark<T: Default + Clone>(c: &mut Criterion, group_name: &str) {
+    let mut group = c.benchmark_group(group_name);
+    group.sample_size(10);
+    for n in SIZES {
+        bench_variants::<T, _>(&mut group, n, n as u64, &PushBack);
+
+        // the batch APIs aren't part of `ListOps`
+        for (name, run) in [
+            (
+                "sixth_batch",
+                push_back_sixth_batch::<T> as fn(usize) -> Duration,
+            ),
+            ("twelfth_batch", push_back_twelfth_batch::<T>),
+        ] {
+            group
+                .bench_with_input(BenchmarkId::new(name, n), &n, |b, &n| {
+                    b.iter_custom(|iters| (0..iters).map(|_| run(n)).sum())
+                })
+                .throughput(Throughput::Elements(n as u64));
         }
-    });
+    }
+    group.finish();
 }
 
-fn push_back_sixth_big(n: usize) {
-    GhostToken::new(|ref mut token| {
-        let list = sixth::LinkedList::with_capacity(n);
+fn removal_benchmark(c: &mut Criterion) {
+    removal_payload_benchmark::<Small>(c, "small");
+    removal_payload_benchmark::<Medium>(c, "medium");
+    removal_payload_benchmark::<Big>(c, "big");
+}
 
-        for _ in 0..n {
-            list.push_back(Big::default(), token);
+fn removal_payload_benchmark<T: Default + Clone>(c: &mut Criterion, payload: &str) {
+    for (group_name, removal) in [
+        ("pop_front", Removal::PopFront),
+        ("pop_back", Removal::PopBack),
+        ("push_pop", Removal::PushPop),
+    ] {
+        let mut group = c.benchmark_group(format!("{}_{}", group_name, payload));
+        group.sample_size(10);
+        for n in SIZES {
+            bench_variants::<T, _>(&mut group, n, n as u64, &removal);
         }
-    });
+        group.finish();
+    }
 }
 
-fn push_back_std_big(n: usize) {
-    let mut list = std::collections::LinkedList::new();
+#[derive(Clone, Copy)]
+enum Traversal {
+    Forward,
+    Reverse,
+    Sum,
+}
+
+/// Fills the list with `0..n`, then times visiting every element in the order asked for.
+impl Workload<u64> for Traversal {
+    type Output = Duration;
+
+    fn run(&self, list: &mut impl Deque<u64>, n: usize) -> Duration {
+        for i in 0..n as u64 {
+            list.push_back(i);
+        }
+
+        let start = Instant::now();
+        match self {
+            Traversal::Forward => list.for_each(|value| {
+                black_box(value);
+            }),
+            Traversal::Reverse => list.for_each_rev(|value| {
+                black_box(value);
+            }),
+            Traversal::Sum => {
+                let mut sum = 0;
+                list.for_each(|value| sum += value);
+                black_box(sum);
+            }
+        }
+        start.elapsed()
+    }
+}
 
-    for _ in 0..n {
-        list.push_back(Big::default());
+fn iteration_benchmark(c: &mut Criterion) {
+    for (group_name, traversal) in [
+        ("iter", Traversal::Forward),
+        ("iter_rev", Traversal::Reverse),
+        ("iter_sum", Traversal::Sum),
+    ] {
+        let mut group = c.benchmark_group(group_name);
+        for n in [100, 1000, 10000] {
+            bench_variants::<u64, _>(&mut group, n, n as u64, &traversal);
+        }
+        group.finish();
     }
 }
 
-fn push_back_vecdeque_big(n: usize) {
-    let mut list = VecDeque::with_capacity(n);
+/// How often [`Middle`] edits the list in one run.
+const MIDDLE_EDITS: usize = 100;
+
+/// Fills the list with `n` elements, then times inserting an element after the middle and
+/// removing it again, [`MIDDLE_EDITS`] times.
+///
+/// `fourth` and `tenth` get there with a cursor, `third` and `ninth` by index. The other variants
+/// pop everything after the middle and push it again.
+struct Middle;
+
+impl<T: Default> Workload<T> for Middle {
+    type Output = Duration;
+
+    fn run(&self, list: &mut impl Deque<T>, n: usize) -> Duration {
+        for _ in 0..n {
+            list.push_back(T::default());
+        }
 
-    for _ in 0..n {
-        list.push_back(Big::default());
+        let start = Instant::now();
+        for _ in 0..MIDDLE_EDITS {
+            list.insert(n / 2 + 1, T::default());
+            black_box(list.remove(n / 2 + 1));
+        }
+        start.elapsed()
     }
 }
 
-fn criterion_benchmark(c: &mut Criterion) {
-    let mut group = c.benchmark_group("push_back_big");
-    for i in [100, 300, 500, 700].iter() {
-        group
-            .bench_with_input(BenchmarkId::new("first", i), i, |b, i| {
-                b.iter(|| push_back_first_big(*i))
-            })
-            .throughput(Throughput::Elements(*i as u64));
+fn middle_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("middle_big");
+    for n in [100, 1000, 10000] {
+        bench_variants::<Big, _>(&mut group, n, MIDDLE_EDITS as u64, &Middle);
 
+        // not a list, but what editing the middle usually gets compared to
+        let mut vec: Vec<_> = (0..n).map(|_| Big::default()).collect();
         group
-            .bench_with_input(BenchmarkId::new("second", i), i, |b, i| {
-                b.iter(|| push_back_second_big(*i))
+            .bench_function(BenchmarkId::new("vec", n), |b| {
+                b.iter(|| {
+                    for _ in 0..MIDDLE_EDITS {
+                        vec.insert(n / 2 + 1, Big::default());
+                        black_box(vec.remove(n / 2 + 1));
+                    }
+                })
             })
-            .throughput(Throughput::Elements(*i as u64));
+            .throughput(Throughput::Elements(MIDDLE_EDITS as u64));
+    }
+    group.finish();
+}
 
-        group
-            .bench_with_input(BenchmarkId::new("third", i), i, |b, i| {
-                b.iter(|| push_back_third_big(*i))
-            })
-            .throughput(Throughput::Elements(*i as u64));
+fn replay_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("replay");
+    for i in [100, 1000, 10000].iter() {
+        let log = ops::generate(0x5eed, *i);
 
-        group
-            .bench_with_input(BenchmarkId::new("fourth", i), i, |b, i| {
-                b.iter(|| push_back_fourth_big(*i))
-            })
-            .throughput(Throughput::Elements(*i as u64));
+        for (name, interpret) in ops::interpreters() {
+            group
+                .bench_with_input(BenchmarkId::new(name, i), &log, |b, log| {
+                    b.iter(|| interpret(log))
+                })
+                .throughput(Throughput::Elements(*i as u64));
+        }
 
         group
-            .bench_with_input(BenchmarkId::new("fifth", i), i, |b, i| {
-                b.iter(|| push_back_fifth_big(*i))
+            .bench_with_input(BenchmarkId::new("vecdeque", i), &log, |b, log| {
+                b.iter(|| ops::model(log))
             })
             .throughput(Throughput::Elements(*i as u64));
+    }
+    group.finish();
+}
 
-        group
-            .bench_with_input(BenchmarkId::new("sixth", i), i, |b, i| {
-                b.iter(|| push_back_sixth_big(*i))
+fn sort_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sort");
+    for i in [100, 1000, 10000].iter() {
+        // xorshift64, so every run sorts the same input
+        let mut state = 0x5eed_u64;
+        let values: Vec<u64> = (0..*i)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state
             })
-            .throughput(Throughput::Elements(*i as u64));
+            .collect();
 
         group
-            .bench_with_input(BenchmarkId::new("std", i), i, |b, i| {
-                b.iter(|| push_back_std_big(*i))
+            .bench_with_input(BenchmarkId::new("third", i), &values, |b, values| {
+                b.iter_batched(
+                    || values.iter().copied().collect::<third::LinkedList<_>>(),
+                    |mut list| list.sort(),
+                    BatchSize::SmallInput,
+                )
             })
             .throughput(Throughput::Elements(*i as u64));
 
         group
-            .bench_with_input(BenchmarkId::new("vecdeque", i), i, |b, i| {
-                b.iter(|| push_back_vecdeque_big(*i))
+            .bench_with_input(BenchmarkId::new("vec", i), &values, |b, values| {
+                b.iter_batched(
+                    || values.clone(),
+                    |mut vec| vec.sort(),
+                    BatchSize::SmallInput,
+                )
             })
             .throughput(Throughput::Elements(*i as u64));
     }
     group.finish();
 }
 
-criterion_group!(benches, criterion_benchmark);
+criterion_group!(
+    benches,
+    criterion_benchmark,
+    removal_benchmark,
+    iteration_benchmark,
+    middle_benchmark,
+    replay_benchmark,
+    sort_benchmark
+);
 criterion_main!(benches);
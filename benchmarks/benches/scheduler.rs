@@ -0,0 +1,364 @@
+//! A macro benchmark that runs a simulated task scheduler on every variant.
+//!
+//! Tasks are spawned onto a woken list, which is spliced onto the run queue every now and then.
+//! The run queue is rotated, tasks at its front complete and random running tasks are cancelled.
+//! The variants that hand out handles also run it cancelling through them instead of searching
+//! the queue. The mix follows a fixed seed, so every variant does the same work.
+use benchmarks::{variants, Deque, Workload};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ghost_cell::GhostToken;
+use typed_arena::Arena;
+
+/// A run queue and the tasks woken since they were last spliced onto it.
+///
+/// A handle stays valid while its task is queued, except that [`rotate`](Scheduler::rotate) and
+/// [`wake`](Scheduler::wake) report new handles for the tasks they move.
+trait Scheduler {
+    type Handle: Copy;
+
+    /// Adds a new task to the woken list.
+    fn spawn(&mut self, task: usize) -> Self::Handle;
+    /// Moves all woken tasks to the back of the run queue, calling `moved` for every task that
+    /// got a new handle on the way.
+    fn wake(&mut self, moved: impl FnMut(usize, Self::Handle));
+    /// Moves the task at the front of the run queue to its back.
+    fn rotate(&mut self) -> Option<(usize, Self::Handle)>;
+    /// Removes the task at the front of the run queue.
+    fn complete(&mut self) -> Option<usize>;
+    /// Removes a running task from anywhere in the run queue.
+    fn cancel(&mut self, handle: Self::Handle) -> Option<usize>;
+}
+
+/// The variants that key their elements.
+struct Keyed<L> {
+    run: L,
+    woken: L,
+}
+
+impl Scheduler for Keyed<third::LinkedList<usize>> {
+    type Handle = third::DefaultKey;
+
+    fn spawn(&mut self, task: usize) -> Self::Handle {
+        self.woken.push_back(task)
+    }
+
+    fn wake(&mut self, mut moved: impl FnMut(usize, Self::Handle)) {
+        while let Some(task) = self.woken.pop_front() {
+            moved(task, self.run.push_back(task));
+        }
+    }
+
+    fn rotate(&mut self) -> Option<(usize, Self::Handle)> {
+        let task = self.run.pop_front()?;
+        Some((task, self.run.push_back(task)))
+    }
+
+    fn complete(&mut self) -> Option<usize> {
+        self.run.pop_front()
+    }
+
+    fn cancel(&mut self, handle: Self::Handle) -> Option<usize> {
+        self.run.remove_key(handle)
+    }
+}
+
+impl Scheduler for Keyed<thirteenth::LinkedList<usize>> {
+    type Handle = usize;
+
+    fn spawn(&mut self, task: usize) -> Self::Handle {
+        self.woken.push_back(task)
+    }
+
+    fn wake(&mut self, mut moved: impl FnMut(usize, Self::Handle)) {
+        while let Some(task) = self.woken.pop_front() {
+            moved(task, self.run.push_back(task));
+        }
+    }
+
+    fn rotate(&mut self) -> Option<(usize, Self::Handle)> {
+        let task = self.run.pop_front()?;
+        Some((task, self.run.push_back(task)))
+    }
+
+    fn complete(&mut self) -> Option<usize> {
+        self.run.pop_front()
+    }
+
+    fn cancel(&mut self, handle: Self::Handle) -> Option<usize> {
+        self.run.remove_key(handle)
+    }
+}
+
+impl Scheduler for Keyed<fourteenth::LinkedList<usize>> {
+    type Handle = fourteenth::Index;
+
+    fn spawn(&mut self, task: usize) -> Self::Handle {
+        self.woken.push_back(task)
+    }
+
+    fn wake(&mut self, mut moved: impl FnMut(usize, Self::Handle)) {
+        while let Some(task) = self.woken.pop_front() {
+            moved(task, self.run.push_back(task));
+        }
+    }
+
+    fn rotate(&mut self) -> Option<(usize, Self::Handle)> {
+        let task = self.run.pop_front()?;
+        Some((task, self.run.push_back(task)))
+    }
+
+    fn complete(&mut self) -> Option<usize> {
+        self.run.pop_front()
+    }
+
+    fn cancel(&mut self, handle: Self::Handle) -> Option<usize> {
+        self.run.remove_key(handle)
+    }
+}
+
+/// Any variant through the shared harness. A task is its own handle, so cancelling has to search
+/// the run queue for it, and woken tasks wait in a `Vec`.
+struct Searched<'a, D> {
+    run: &'a mut D,
+    woken: Vec<usize>,
+}
+
+impl<'a, D: Deque<usize>> Scheduler for Searched<'a, D> {
+    type Handle = usize;
+
+    fn spawn(&mut self, task: usize) -> Self::Handle {
+        self.woken.push(task);
+        task
+    }
+
+    fn wake(&mut self, _: impl FnMut(usize, Self::Handle)) {
+        for task in self.woken.drain(..) {
+            self.run.push_back(task);
+        }
+    }
+
+    fn rotate(&mut self) -> Option<(usize, Self::Handle)> {
+        let task = self.run.pop_front()?;
+        self.run.push_back(task);
+        Some((task, task))
+    }
+
+    fn complete(&mut self) -> Option<usize> {
+        self.run.pop_front()
+    }
+
+    fn cancel(&mut self, handle: Self::Handle) -> Option<usize> {
+        let (mut index, mut found) = (0, None);
+        self.run.for_each(|&task| {
+            if task == handle {
+                found.get_or_insert(index);
+            }
+            index += 1;
+        });
+        self.run.remove(found?)
+    }
+}
+
+/// Runs [`simulate`] on a [`Searched`] scheduler.
+struct Simulation {
+    ops: usize,
+}
+
+impl Workload<usize> for Simulation {
+    type Output = usize;
+
+    fn run(&self, run: &mut impl Deque<usize>, _: usize) -> usize {
+        simulate(
+            &mut Searched {
+                run,
+                woken: Vec::new(),
+            },
+            self.ops,
+        )
+    }
+}
+
+/// Splices the woken list in `O(1)` and keeps every handle. A cancelled task's node is moved to
+/// the woken list and popped there, so the next spawn reuses it. Cancelling walks to the nearer
+/// end of the run queue to check that the handle belongs to it.
+struct Fourth<'a, 'arena, 'id> {
+    run: fourth::LinkedList<'arena, 'id, usize>,
+    woken: fourth::LinkedList<'arena, 'id, usize>,
+    token: &'a mut GhostToken<'id>,
+}
+
+impl<'a, 'arena, 'id> Scheduler for Fourth<'a, 'arena, 'id> {
+    type Handle = fourth::NodeHandle<'arena, 'id, usize>;
+
+    fn spawn(&mut self, task: usize) -> Self::Handle {
+        self.woken.push_back(task, self.token);
+        self.woken.back_handle(self.token).unwrap()
+    }
+
+    fn wake(&mut self, _: impl FnMut(usize, Self::Handle)) {
+        self.run
+            .cursor_back_mut(self.token)
+            .splice_after(&mut self.woken);
+    }
+
+    fn rotate(&mut self) -> Option<(usize, Self::Handle)> {
+        let task = self.run.pop_front(self.token)?;
+        self.run.push_back(task, self.token);
+        Some((task, self.run.back_handle(self.token).unwrap()))
+    }
+
+    fn complete(&mut self) -> Option<usize> {
+        self.run.pop_front(self.token)
+    }
+
+    fn cancel(&mut self, handle: Self::Handle) -> Option<usize> {
+        self.woken.take_node_from(&mut self.run, handle, self.token);
+        self.woken.pop_back(self.token)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Slot<H> {
+    Done,
+    Woken(H),
+    Running(H),
+}
+
+const TASKS: usize = 1000;
+
+/// Spawns and wakes `TASKS` tasks, then runs `ops` random scheduler operations. Returns how many
+/// tasks completed or were cancelled.
+fn simulate<S: Scheduler>(scheduler: &mut S, ops: usize) -> usize {
+    let mut slots = Vec::new();
+    let mut woken = Vec::new();
+    let mut finished = 0;
+
+    for task in 0..TASKS {
+        slots.push(Slot::Woken(scheduler.spawn(task)));
+        woken.push(task);
+    }
+    wake(scheduler, &mut slots, &mut woken);
+
+    // xorshift64, so every variant sees the same operations
+    let mut state = 0x5eed_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state as usize
+    };
+
+    for _ in 0..ops {
+        match next() % 16 {
+            0..=6 => {
+                if let Some((task, handle)) = scheduler.rotate() {
+                    slots[task] = Slot::Running(handle);
+                }
+            }
+            7..=9 => {
+                let task = slots.len();
+                slots.push(Slot::Woken(scheduler.spawn(task)));
+                woken.push(task);
+            }
+            10..=11 => {
+                if let Some(task) = scheduler.complete() {
+                    slots[task] = Slot::Done;
+                    finished += 1;
+                }
+            }
+            // one of the more recent tasks, older ones are likely done anyway
+            12..=14 => {
+                let task = slots.len() - 1 - next() % slots.len().min(2 * TASKS);
+                if let Slot::Running(handle) = slots[task] {
+                    scheduler.cancel(handle);
+                    slots[task] = Slot::Done;
+                    finished += 1;
+                }
+            }
+            _ => wake(scheduler, &mut slots, &mut woken),
+        }
+    }
+
+    finished
+}
+
+fn wake<S: Scheduler>(scheduler: &mut S, slots: &mut [Slot<S::Handle>], woken: &mut Vec<usize>) {
+    for task in woken.drain(..) {
+        if let Slot::Woken(handle) = slots[task] {
+            slots[task] = Slot::Running(handle);
+        }
+    }
+    scheduler.wake(|task, handle| slots[task] = Slot::Running(handle));
+}
+
+fn scheduler_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scheduler");
+    group.sample_size(10);
+    for ops in [100_000, 300_000] {
+        group.throughput(Throughput::Elements(ops as u64));
+
+        for (name, run) in variants::<usize, _>() {
+            group.bench_with_input(BenchmarkId::new(name, ops), &ops, |b, &ops| {
+                b.iter(|| black_box(run(&Simulation { ops }, TASKS)))
+            });
+        }
+
+        // the handles aren't part of `ListOps`
+
+        group.bench_with_input(BenchmarkId::new("third_handles", ops), &ops, |b, &ops| {
+            b.iter(|| {
+                let mut scheduler = Keyed {
+                    run: third::LinkedList::new(),
+                    woken: third::LinkedList::new(),
+                };
+                black_box(simulate(&mut scheduler, ops))
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("fourth_handles", ops), &ops, |b, &ops| {
+            b.iter(|| {
+                GhostToken::new(|ref mut token| {
+                    let arena = Arena::new();
+                    let mut scheduler = Fourth {
+                        run: fourth::LinkedList::new(&arena),
+                        woken: fourth::LinkedList::new(&arena),
+                        token,
+                    };
+                    black_box(simulate(&mut scheduler, ops))
+                })
+            })
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("thirteenth_handles", ops),
+            &ops,
+            |b, &ops| {
+                b.iter(|| {
+                    let mut scheduler = Keyed {
+                        run: thirteenth::LinkedList::new(),
+                        woken: thirteenth::LinkedList::new(),
+                    };
+                    black_box(simulate(&mut scheduler, ops))
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("fourteenth_handles", ops),
+            &ops,
+            |b, &ops| {
+                b.iter(|| {
+                    let mut scheduler = Keyed {
+                        run: fourteenth::LinkedList::new(),
+                        woken: fourteenth::LinkedList::new(),
+                    };
+                    black_box(simulate(&mut scheduler, ops))
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, scheduler_benchmark);
+criterion_main!(benches);
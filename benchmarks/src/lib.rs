@@ -0,0 +1,183 @@
+//! The harness the benchmarks share: every variant behind one [`Deque`] interface, and
+//! [`variants`] to run a [`Workload`] on each of them.
+//!
+//! Adding a variant to [`variants`] adds it to every benchmark that goes through it.
+use bumpalo::Bump;
+use ghost_cell::GhostToken;
+use list_traits::ListOps;
+use std::collections::{LinkedList, VecDeque};
+use typed_arena::Arena;
+
+/// What the benchmarks need from a list, implemented for every variant through [`ListOps`] and
+/// for `std`'s `LinkedList`.
+pub trait Deque<T> {
+    fn push_back(&mut self, value: T);
+    fn pop_front(&mut self) -> Option<T>;
+    fn pop_back(&mut self) -> Option<T>;
+    fn for_each(&self, f: impl FnMut(&T));
+    fn for_each_rev(&self, f: impl FnMut(&T));
+    /// `index <= len`
+    fn insert(&mut self, index: usize, value: T);
+    /// `index < len`
+    fn remove(&mut self, index: usize) -> Option<T>;
+}
+
+impl<T, L: ListOps<T>> Deque<T> for (L, &mut L::Context) {
+    fn push_back(&mut self, value: T) {
+        self.0.push_back(value, self.1)
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        self.0.pop_front(self.1)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        self.0.pop_back(self.1)
+    }
+
+    fn for_each(&self, f: impl FnMut(&T)) {
+        self.0.for_each(f, self.1)
+    }
+
+    fn for_each_rev(&self, f: impl FnMut(&T)) {
+        self.0.for_each_rev(f, self.1)
+    }
+
+    fn insert(&mut self, index: usize, value: T) {
+        self.0.insert(index, value, self.1)
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        self.0.remove(index, self.1)
+    }
+}
+
+/// Editing the middle splits the list and appends the back again, there's no stable cursor.
+impl<T> Deque<T> for LinkedList<T> {
+    fn push_back(&mut self, value: T) {
+        LinkedList::push_back(self, value)
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        LinkedList::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        LinkedList::pop_back(self)
+    }
+
+    fn for_each(&self, f: impl FnMut(&T)) {
+        self.iter().for_each(f)
+    }
+
+    fn for_each_rev(&self, f: impl FnMut(&T)) {
+        self.iter().rev().for_each(f)
+    }
+
+    fn insert(&mut self, index: usize, value: T) {
+        let mut back = self.split_off(index);
+        self.push_back(value);
+        self.append(&mut back);
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        let mut back = self.split_off(index);
+        let removed = back.pop_front();
+        self.append(&mut back);
+        removed
+    }
+}
+
+/// The body of a benchmark, run on an empty list of every variant by [`variants`].
+pub trait Workload<T> {
+    /// What a run reports, e.g. how long the part that's measured took.
+    type Output;
+
+    /// Does the work for `n` elements.
+    fn run(&self, list: &mut impl Deque<T>, n: usize) -> Self::Output;
+}
+
+/// Runs `workload` on `list`, then pops whatever is left, so lists that have to be cleared
+/// before they are dropped are.
+fn measure<T, W: Workload<T>>(workload: &W, list: &mut impl Deque<T>, n: usize) -> W::Output {
+    let output = workload.run(list, n);
+
+    while list.pop_front().is_some() {}
+    output
+}
+
+/// Creates a list with room for `n` elements and runs a workload on it.
+pub type Variant<T, W> = fn(&W, usize) -> <W as Workload<T>>::Output;
+
+/// Every variant with what it needs to get going, by crate name, followed by `std`'s
+/// `LinkedList` and `VecDeque`.
+pub fn variants<T: Clone, W: Workload<T>>() -> [(&'static str, Variant<T, W>); 16] {
+    [
+        ("first", |w, n| {
+            measure(w, &mut (first::LinkedList::new(), &mut ()), n)
+        }),
+        ("second", |w, n| {
+            GhostToken::new(|ref mut token| measure(w, &mut (second::LinkedList::new(), token), n))
+        }),
+        ("third", |w, n| {
+            measure(w, &mut (third::LinkedList::with_capacity(n), &mut ()), n)
+        }),
+        ("fourth", |w, n| {
+            GhostToken::new(|ref mut token| {
+                let arena = Arena::with_capacity(n);
+                measure(w, &mut (fourth::LinkedList::new(&arena), token), n)
+            })
+        }),
+        ("fifth", |w, n| {
+            GhostToken::new(|ref mut token| {
+                let arena = Arena::with_capacity(n);
+                measure(w, &mut (fifth::LinkedList::new(&arena), token), n)
+            })
+        }),
+        ("sixth", |w, n| {
+            GhostToken::new(|ref mut token| {
+                let list = sixth::LinkedList::with_capacity(n);
+                measure(w, &mut (&list, token), n)
+            })
+        }),
+        ("seventh", |w, n| {
+            measure(w, &mut (seventh::LinkedList::with_capacity(n), &mut ()), n)
+        }),
+        ("eighth", |w, n| {
+            measure(w, &mut (eighth::LinkedList::with_capacity(n), &mut ()), n)
+        }),
+        ("ninth", |w, n| {
+            measure(w, &mut (ninth::LinkedList::new(), &mut ()), n)
+        }),
+        ("tenth", |w, n| {
+            measure(w, &mut (tenth::LinkedList::new(), &mut ()), n)
+        }),
+        ("eleventh", |w, n| {
+            measure(w, &mut (eleventh::LinkedList::new(), &mut ()), n)
+        }),
+        ("twelfth", |w, n| {
+            GhostToken::new(|ref mut token| {
+                let bump = Bump::new();
+                measure(w, &mut (twelfth::LinkedList::new(&bump), token), n)
+            })
+        }),
+        ("thirteenth", |w, n| {
+            measure(
+                w,
+                &mut (thirteenth::LinkedList::with_capacity(n), &mut ()),
+                n,
+            )
+        }),
+        ("fourteenth", |w, n| {
+            measure(
+                w,
+                &mut (fourteenth::LinkedList::with_capacity(n), &mut ()),
+                n,
+            )
+        }),
+        ("std", |w, n| measure(w, &mut LinkedList::new(), n)),
+        ("vecdeque", |w, n| {
+            measure(w, &mut (VecDeque::with_capacity(n), &mut ()), n)
+        }),
+    ]
+}
@@ -0,0 +1,343 @@
+#![no_std]
+#![forbid(unsafe_code)]
+
+//! A battery of tests every [`ListOps`] implementation has to pass.
+//!
+//! [`list_conformance_tests!`] expands into a module with one `#[test]` per check below. The
+//! caller only has to say how to set up an empty list and its context:
+//!
+//! ```ignore
+//! list_conformance_tests!(second, |suite| {
+//!     GhostToken::new(|ref mut token| suite.run(&mut ::second::LinkedList::new(), token))
+//! });
+//! ```
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+pub use list_traits::ListOps;
+
+/// One check, handed to the setup code given to [`list_conformance_tests!`].
+pub trait Suite {
+    fn run<L: ListOps<i32>>(self, list: &mut L, cx: &mut L::Context);
+}
+
+#[macro_export]
+macro_rules! list_conformance_tests {
+    ($name:ident, |$suite:ident| $setup:expr) => {
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            fn setup<S: $crate::Suite>($suite: S) {
+                $setup;
+            }
+
+            $crate::list_conformance_tests!(@tests setup:
+                empty,
+                single,
+                push_back_pop_front,
+                push_front_pop_back,
+                push_back_pop_back,
+                push_front_pop_front,
+                len,
+                to_vec,
+                for_each,
+                clear,
+                reuse,
+                many,
+                pop_if,
+                roundtrip,
+                model
+            );
+        }
+    };
+    (@tests $setup:ident: $($check:ident),*) => {
+        $(
+            #[test]
+            fn $check() {
+                struct Check;
+
+                impl $crate::Suite for Check {
+                    fn run<L: $crate::ListOps<i32>>(self, list: &mut L, cx: &mut L::Context) {
+                        $crate::$check(list, cx);
+                        // some variants must not be dropped with elements still in them
+                        list.clear(cx);
+                    }
+                }
+
+                $setup(Check);
+            }
+        )*
+    };
+}
+
+pub fn empty<L: ListOps<i32>>(list: &mut L, cx: &mut L::Context) {
+    assert!(list.is_empty(cx));
+    assert_eq!(list.len(cx), 0);
+    assert_eq!(list.to_vec(cx), []);
+    assert_eq!(list.pop_front(cx), None);
+    assert_eq!(list.pop_back(cx), None);
+    list.clear(cx);
+    assert!(list.is_empty(cx));
+}
+
+pub fn single<L: ListOps<i32>>(list: &mut L, cx: &mut L::Context) {
+    list.push_back(1, cx);
+    assert_eq!(list.to_vec(cx), [1]);
+    assert_eq!(list.pop_back(cx), Some(1));
+    assert_eq!(list.pop_front(cx), None);
+
+    list.push_front(2, cx);
+    assert_eq!(list.to_vec(cx), [2]);
+    assert_eq!(list.pop_front(cx), Some(2));
+    assert_eq!(list.pop_back(cx), None);
+
+    list.push_front(3, cx);
+    assert_eq!(list.pop_back(cx), Some(3));
+    list.push_back(4, cx);
+    assert_eq!(list.pop_front(cx), Some(4));
+    assert!(list.is_empty(cx));
+}
+
+pub fn push_back_pop_front<L: ListOps<i32>>(list: &mut L, cx: &mut L::Context) {
+    for i in 0..10 {
+        list.push_back(i, cx);
+    }
+    for i in 0..10 {
+        assert_eq!(list.pop_front(cx), Some(i));
+    }
+    assert_eq!(list.pop_front(cx), None);
+}
+
+pub fn push_front_pop_back<L: ListOps<i32>>(list: &mut L, cx: &mut L::Context) {
+    for i in 0..10 {
+        list.push_front(i, cx);
+    }
+    for i in 0..10 {
+        assert_eq!(list.pop_back(cx), Some(i));
+    }
+    assert_eq!(list.pop_back(cx), None);
+}
+
+pub fn push_back_pop_back<L: ListOps<i32>>(list: &mut L, cx: &mut L::Context) {
+    for i in 0..10 {
+        list.push_back(i, cx);
+    }
+    for i in (0..10).rev() {
+        assert_eq!(list.pop_back(cx), Some(i));
+    }
+    assert_eq!(list.pop_back(cx), None);
+}
+
+pub fn push_front_pop_front<L: ListOps<i32>>(list: &mut L, cx: &mut L::Context) {
+    for i in 0..10 {
+        list.push_front(i, cx);
+    }
+    for i in (0..10).rev() {
+        assert_eq!(list.pop_front(cx), Some(i));
+    }
+    assert_eq!(list.pop_front(cx), None);
+}
+
+pub fn len<L: ListOps<i32>>(list: &mut L, cx: &mut L::Context) {
+    for i in 0..5 {
+        list.push_back(i, cx);
+        assert_eq!(list.len(cx), i as usize * 2 + 1);
+        list.push_front(i, cx);
+        assert_eq!(list.len(cx), i as usize * 2 + 2);
+        assert!(!list.is_empty(cx));
+    }
+    for i in (0..10).rev() {
+        if i % 2 == 0 {
+            list.pop_front(cx);
+        } else {
+            list.pop_back(cx);
+        }
+        assert_eq!(list.len(cx), i);
+    }
+    assert!(list.is_empty(cx));
+
+    // popping an empty list doesn't underflow
+    list.pop_front(cx);
+    list.pop_back(cx);
+    assert_eq!(list.len(cx), 0);
+}
+
+pub fn to_vec<L: ListOps<i32>>(list: &mut L, cx: &mut L::Context) {
+    list.push_back(3, cx);
+    list.push_front(2, cx);
+    list.push_back(4, cx);
+    list.push_front(1, cx);
+    assert_eq!(list.to_vec(cx), [1, 2, 3, 4]);
+
+    // to_vec doesn't consume anything
+    assert_eq!(list.len(cx), 4);
+    assert_eq!(list.to_vec(cx), [1, 2, 3, 4]);
+
+    list.pop_front(cx);
+    list.pop_back(cx);
+    assert_eq!(list.to_vec(cx), [2, 3]);
+}
+
+pub fn for_each<L: ListOps<i32>>(list: &mut L, cx: &mut L::Context) {
+    let mut seen = Vec::new();
+    list.for_each(|&value| seen.push(value), cx);
+    list.for_each_rev(|&value| seen.push(value), cx);
+    assert_eq!(seen, []);
+
+    for i in 1..=4 {
+        list.push_back(i, cx);
+    }
+    list.for_each(|&value| seen.push(value), cx);
+    assert_eq!(seen, [1, 2, 3, 4]);
+
+    seen.clear();
+    list.for_each_rev(|&value| seen.push(value), cx);
+    assert_eq!(seen, [4, 3, 2, 1]);
+}
+
+pub fn clear<L: ListOps<i32>>(list: &mut L, cx: &mut L::Context) {
+    for i in 0..10 {
+        list.push_back(i, cx);
+    }
+    list.clear(cx);
+    assert!(list.is_empty(cx));
+    assert_eq!(list.to_vec(cx), []);
+    assert_eq!(list.pop_front(cx), None);
+    assert_eq!(list.pop_back(cx), None);
+
+    list.clear(cx);
+    assert!(list.is_empty(cx));
+}
+
+pub fn reuse<L: ListOps<i32>>(list: &mut L, cx: &mut L::Context) {
+    for round in 0..3 {
+        for i in 0..5 {
+            list.push_back(round * 10 + i, cx);
+        }
+        assert_eq!(list.len(cx), 5);
+        assert_eq!(list.pop_front(cx), Some(round * 10));
+        assert_eq!(list.pop_back(cx), Some(round * 10 + 4));
+        list.clear(cx);
+        assert!(list.is_empty(cx));
+    }
+}
+
+pub fn many<L: ListOps<i32>>(list: &mut L, cx: &mut L::Context) {
+    for i in 0..1000 {
+        if i % 2 == 0 {
+            list.push_back(i, cx);
+        } else {
+            list.push_front(i, cx);
+        }
+    }
+    assert_eq!(list.len(cx), 1000);
+
+    let expected: Vec<i32> = (0..1000)
+        .rev()
+        .filter(|i| i % 2 == 1)
+        .chain((0..1000).filter(|i| i % 2 == 0))
+        .collect();
+    assert_eq!(list.to_vec(cx), expected);
+
+    for i in (0..1000).rev() {
+        if i % 2 == 0 {
+            assert_eq!(list.pop_back(cx), Some(i));
+        } else {
+            assert_eq!(list.pop_front(cx), Some(i));
+        }
+    }
+    assert!(list.is_empty(cx));
+}
+
+pub fn pop_if<L: ListOps<i32>>(list: &mut L, cx: &mut L::Context) {
+    assert_eq!(list.pop_front_if(|_| true, cx), None);
+    assert_eq!(list.pop_back_if(|_| true, cx), None);
+
+    for deadline in [1, 3, 5, 7] {
+        list.push_back(deadline, cx);
+    }
+
+    assert_eq!(list.pop_front_if(|d| *d <= 2, cx), Some(1));
+    assert_eq!(list.pop_front_if(|d| *d <= 2, cx), None);
+    assert_eq!(list.pop_back_if(|d| *d > 6, cx), Some(7));
+    assert_eq!(list.pop_back_if(|d| *d > 6, cx), None);
+    assert_eq!(list.to_vec(cx), [3, 5]);
+
+    // `pred` may change the element it rejects
+    assert_eq!(
+        list.pop_front_if(
+            |d| {
+                *d *= 10;
+                false
+            },
+            cx
+        ),
+        None
+    );
+    assert_eq!(list.to_vec(cx), [30, 5]);
+}
+
+pub fn roundtrip<L: ListOps<i32>>(list: &mut L, cx: &mut L::Context) {
+    for i in 1..=3 {
+        list.push_back(i, cx);
+    }
+    let bytes = list.snapshot(cx).unwrap();
+    // the format only depends on the elements, so any variant can restore it
+    assert_eq!(bytes, snapshot::encode(&[1, 2, 3]).unwrap());
+
+    list.restore(&bytes, cx).unwrap();
+    assert_eq!(list.to_vec(cx), [1, 2, 3, 1, 2, 3]);
+
+    let mut truncated = snapshot::encode(&[4, 5]).unwrap();
+    truncated.pop();
+    assert_eq!(
+        list.restore(&truncated, cx),
+        Err(snapshot::Error::UnexpectedEnd)
+    );
+    assert_eq!(list.len(cx), 6);
+
+    list.clear(cx);
+    list.restore(&snapshot::encode::<i32, _>(&[]).unwrap(), cx)
+        .unwrap();
+    assert!(list.is_empty(cx));
+}
+
+/// A fixed pseudo random sequence of operations checked step by step against a `VecDeque`.
+pub fn model<L: ListOps<i32>>(list: &mut L, cx: &mut L::Context) {
+    let mut model = VecDeque::new();
+    let mut state = 0x2545_f491_u32;
+
+    for i in 0..2000 {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+
+        match state % 9 {
+            0 | 1 => {
+                list.push_front(i, cx);
+                model.push_front(i);
+            }
+            2 | 3 => {
+                list.push_back(i, cx);
+                model.push_back(i);
+            }
+            4 | 5 => assert_eq!(list.pop_front(cx), model.pop_front()),
+            6 | 7 => assert_eq!(list.pop_back(cx), model.pop_back()),
+            _ => {
+                if state.is_multiple_of(8) {
+                    list.clear(cx);
+                    model.clear();
+                }
+            }
+        }
+
+        assert_eq!(list.len(cx), model.len());
+        assert_eq!(list.is_empty(cx), model.is_empty());
+        if i % 100 == 0 {
+            assert!(list.to_vec(cx).iter().eq(model.iter()));
+        }
+    }
+    assert_eq!(list.to_vec(cx), Vec::from(model));
+}
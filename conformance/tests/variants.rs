@@ -0,0 +1,92 @@
+use bumpalo::Bump;
+use ghost_cell::GhostToken;
+use listslistslists_conformance::{list_conformance_tests, ListOps};
+use typed_arena::Arena;
+
+list_conformance_tests!(first, |suite| suite
+    .run(&mut ::first::LinkedList::new(), &mut ()));
+
+list_conformance_tests!(third, |suite| suite
+    .run(&mut ::third::LinkedList::new(), &mut ()));
+
+list_conformance_tests!(second, |suite| {
+    GhostToken::new(|ref mut token| suite.run(&mut ::second::LinkedList::new(), token))
+});
+
+list_conformance_tests!(fourth, |suite| {
+    GhostToken::new(|ref mut token| {
+        let arena = Arena::new();
+        suite.run(&mut ::fourth::LinkedList::new(&arena), token)
+    })
+});
+
+list_conformance_tests!(fifth, |suite| {
+    GhostToken::new(|ref mut token| {
+        let arena = Arena::new();
+        suite.run(&mut ::fifth::LinkedList::new(&arena), token)
+    })
+});
+
+list_conformance_tests!(sixth, |suite| {
+    GhostToken::new(|ref mut token| {
+        let list = ::sixth::LinkedList::new();
+        suite.run(&mut &list, token)
+    })
+});
+
+list_conformance_tests!(seventh, |suite| suite
+    .run(&mut ::seventh::LinkedList::new(), &mut ()));
+
+list_conformance_tests!(eighth, |suite| suite
+    .run(&mut ::eighth::LinkedList::new(), &mut ()));
+
+list_conformance_tests!(ninth, |suite| suite
+    .run(&mut ::ninth::LinkedList::new(), &mut ()));
+
+list_conformance_tests!(tenth, |suite| suite
+    .run(&mut ::tenth::LinkedList::new(), &mut ()));
+
+list_conformance_tests!(eleventh, |suite| suite
+    .run(&mut ::eleventh::LinkedList::new(), &mut ()));
+
+list_conformance_tests!(twelfth, |suite| {
+    GhostToken::new(|ref mut token| {
+        let bump = Bump::new();
+        suite.run(&mut ::twelfth::LinkedList::new(&bump), token)
+    })
+});
+
+list_conformance_tests!(thirteenth, |suite| suite
+    .run(&mut ::thirteenth::LinkedList::new(), &mut ()));
+
+list_conformance_tests!(fourteenth, |suite| suite
+    .run(&mut ::fourteenth::LinkedList::new(), &mut ()));
+
+#[test]
+fn third_restore_is_deterministic() {
+    let mut list = ::third::LinkedList::new();
+    list.push_back(String::from("b"));
+    list.push_front(String::from("a"));
+    let key = list.push_back(String::from("c"));
+    list.remove_key(key);
+    list.push_back(String::from("d"));
+
+    list.assert_roundtrip();
+
+    let bytes = ListOps::snapshot(&list, &()).unwrap();
+    let mut a = ::third::LinkedList::new();
+    let mut b = ::third::LinkedList::new();
+    a.restore(&bytes, &mut ()).unwrap();
+    b.restore(&bytes, &mut ()).unwrap();
+
+    assert_eq!(a.iter().collect::<Vec<_>>(), ["a", "b", "d"]);
+
+    // keys handed out after the restore stay in sync as long as both lists see the same ops
+    let front = a.keys().next().unwrap();
+    assert_eq!(a.remove_key(front), b.remove_key(front));
+    assert_eq!(
+        a.push_back(String::from("e")),
+        b.push_back(String::from("e"))
+    );
+    assert!(a.keys().eq(b.keys()));
+}
@@ -0,0 +1,202 @@
+#![cfg_attr(not(test), no_std)]
+#![forbid(unsafe_code)]
+
+//! The structural invariants every list variant has to uphold, checked by their
+//! `check_invariants` methods.
+//!
+//! [`check_links`] walks any doubly linked chain given a way to read a node's links, the variants
+//! add whatever is specific to them (free lists, subtree sizes, ...) on top.
+use core::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvariantError {
+    /// Following `next` from the head comes back around to a node that was already visited.
+    Cycle,
+    /// The node at `index` would be reached through a link to a slot without a node in it.
+    Dangling { index: usize },
+    /// The head has a `prev` link.
+    HeadHasPrev,
+    /// The `prev` link of the node after `index` doesn't point back at it.
+    BrokenLink { index: usize },
+    /// Following `next` from the head ends at `index`, which isn't the tail.
+    WrongTail { index: usize },
+    /// The list says it holds `len` elements, but `found` were reached.
+    LenMismatch { len: usize, found: usize },
+    /// An invariant particular to one variant.
+    Other(&'static str),
+}
+
+impl fmt::Display for InvariantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvariantError::Cycle => f.write_str("following `next` from the head runs in a cycle"),
+            InvariantError::Dangling { index } => {
+                write!(f, "the link to node {} points to an empty slot", index)
+            }
+            InvariantError::HeadHasPrev => f.write_str("the head has a `prev` link"),
+            InvariantError::BrokenLink { index } => write!(
+                f,
+                "node {} doesn't link back to node {} through `prev`",
+                index + 1,
+                index
+            ),
+            InvariantError::WrongTail { index } => {
+                write!(f, "the list ends at node {}, which isn't the tail", index)
+            }
+            InvariantError::LenMismatch { len, found } => {
+                write!(f, "length is {}, but {} nodes are linked", len, found)
+            }
+            InvariantError::Other(msg) => f.write_str(msg),
+        }
+    }
+}
+
+/// Checks a chain of nodes from `head` to `tail`, returning how many nodes it has.
+///
+/// `links` returns a node's `(prev, next)`, or `None` if the handle doesn't resolve to a node,
+/// `same` tells whether two handles refer to the same node. `len` is compared against the walk if
+/// the list keeps track of it.
+///
+/// Before anything else the chain is checked for a cycle along `next`, so none of the walks can
+/// run forever.
+pub fn check_links<N: Clone>(
+    head_tail: Option<(N, N)>,
+    len: Option<usize>,
+    mut links: impl FnMut(&N) -> Option<(Option<N>, Option<N>)>,
+    mut same: impl FnMut(&N, &N) -> bool,
+) -> Result<usize, InvariantError> {
+    let Some((head, tail)) = head_tail else {
+        return match len {
+            Some(len) if len != 0 => Err(InvariantError::LenMismatch { len, found: 0 }),
+            _ => Ok(0),
+        };
+    };
+
+    // Floyd's tortoise and hare, a dangling link just ends the walk and is reported below
+    let mut slow = head.clone();
+    let mut fast = head.clone();
+    'walk: loop {
+        for _ in 0..2 {
+            match links(&fast).and_then(|(_, next)| next) {
+                Some(next) => fast = next,
+                None => break 'walk,
+            }
+        }
+
+        slow = links(&slow).and_then(|(_, next)| next).unwrap();
+        if same(&slow, &fast) {
+            return Err(InvariantError::Cycle);
+        }
+    }
+
+    let (head_prev, mut next) = links(&head).ok_or(InvariantError::Dangling { index: 0 })?;
+    if head_prev.is_some() {
+        return Err(InvariantError::HeadHasPrev);
+    }
+
+    let mut index = 0;
+    let mut current = head;
+    while let Some(node) = next {
+        let (prev, node_next) =
+            links(&node).ok_or(InvariantError::Dangling { index: index + 1 })?;
+        if !prev.is_some_and(|prev| same(&prev, &current)) {
+            return Err(InvariantError::BrokenLink { index });
+        }
+
+        current = node;
+        next = node_next;
+        index += 1;
+    }
+
+    if !same(&current, &tail) {
+        return Err(InvariantError::WrongTail { index });
+    }
+
+    let found = index + 1;
+    match len {
+        Some(len) if len != found => Err(InvariantError::LenMismatch { len, found }),
+        _ => Ok(found),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// `(prev, next)` per slot, `None` for an empty slot.
+    fn check(
+        nodes: &[Option<(Option<usize>, Option<usize>)>],
+        head_tail: Option<(usize, usize)>,
+        len: Option<usize>,
+    ) -> Result<usize, InvariantError> {
+        check_links(
+            head_tail,
+            len,
+            |&i| nodes.get(i).copied().flatten(),
+            |a, b| a == b,
+        )
+    }
+
+    #[test]
+    fn valid() {
+        let nodes = [Some((Some(2), None)), None, Some((None, Some(0)))];
+
+        assert_eq!(check(&nodes, Some((2, 0)), Some(2)), Ok(2));
+        assert_eq!(check(&nodes, Some((2, 0)), None), Ok(2));
+        assert_eq!(check(&[], None, Some(0)), Ok(0));
+        assert_eq!(
+            check(&[], None, Some(1)),
+            Err(InvariantError::LenMismatch { len: 1, found: 0 })
+        );
+    }
+
+    #[test]
+    fn broken() {
+        let nodes = [Some((None, Some(1))), Some((None, None))];
+        assert_eq!(
+            check(&nodes, Some((0, 1)), None),
+            Err(InvariantError::BrokenLink { index: 0 })
+        );
+
+        let nodes = [Some((Some(1), Some(1))), Some((Some(0), None))];
+        assert_eq!(
+            check(&nodes, Some((0, 1)), None),
+            Err(InvariantError::HeadHasPrev)
+        );
+
+        let nodes = [Some((None, Some(1))), Some((Some(0), Some(2))), None];
+        assert_eq!(
+            check(&nodes, Some((0, 1)), None),
+            Err(InvariantError::Dangling { index: 2 })
+        );
+
+        let nodes = [Some((None, Some(1))), Some((Some(0), None))];
+        assert_eq!(
+            check(&nodes, Some((0, 0)), None),
+            Err(InvariantError::WrongTail { index: 1 })
+        );
+        assert_eq!(
+            check(&nodes, Some((0, 1)), Some(3)),
+            Err(InvariantError::LenMismatch { len: 3, found: 2 })
+        );
+    }
+
+    #[test]
+    fn cycle() {
+        let nodes = [
+            Some((None, Some(1))),
+            Some((Some(0), Some(2))),
+            Some((Some(1), Some(1))),
+        ];
+        assert_eq!(
+            check(&nodes, Some((0, 2)), None),
+            Err(InvariantError::Cycle)
+        );
+
+        let nodes = [Some((Some(0), Some(0)))];
+        assert_eq!(
+            check(&nodes, Some((0, 0)), None),
+            Err(InvariantError::Cycle)
+        );
+    }
+}
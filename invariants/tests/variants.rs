@@ -0,0 +1,80 @@
+use bumpalo::Bump;
+use ghost_cell::GhostToken;
+use typed_arena::Arena;
+
+/// Pushes and pops on both ends of a list, checking its invariants after every step.
+macro_rules! exercise {
+    ($list:expr, $check:expr, $($token:expr)?) => {{
+        assert_eq!($check, Ok(()));
+        for i in 0..8 {
+            $list.push_back(i $(, $token)?);
+            $list.push_front(i $(, $token)?);
+            assert_eq!($check, Ok(()));
+        }
+        for _ in 0..3 {
+            $list.pop_front($($token)?);
+            $list.pop_back($($token)?);
+            assert_eq!($check, Ok(()));
+        }
+        $list.push_back(8 $(, $token)?);
+        assert_eq!($check, Ok(()));
+        while $list.pop_front($($token)?).is_some() {
+            assert_eq!($check, Ok(()));
+        }
+        assert_eq!($check, Ok(()));
+    }};
+}
+
+#[test]
+fn owned() {
+    let mut list = first::LinkedList::new();
+    exercise!(list, list.check_invariants(),);
+
+    let mut list = third::LinkedList::new();
+    exercise!(list, list.check_invariants(),);
+
+    let mut list = seventh::LinkedList::new();
+    exercise!(list, list.check_invariants(),);
+
+    let mut list = eighth::LinkedList::new();
+    exercise!(list, list.check_invariants(),);
+
+    let mut list = ninth::LinkedList::new();
+    exercise!(list, list.check_invariants(),);
+
+    let list = tenth::LinkedList::new();
+    exercise!(list, list.check_invariants(),);
+
+    let mut list = eleventh::LinkedList::new();
+    exercise!(list, list.check_invariants(),);
+
+    let mut list = thirteenth::LinkedList::new();
+    exercise!(list, list.check_invariants(),);
+
+    let mut list = fourteenth::LinkedList::new();
+    exercise!(list, list.check_invariants(),);
+}
+
+#[test]
+fn branded() {
+    GhostToken::new(|ref mut token| {
+        let mut list = second::LinkedList::new();
+        exercise!(list, list.check_invariants(token), token);
+        list.clear(token);
+
+        let arena = Arena::new();
+        let mut list = fourth::LinkedList::new(&arena);
+        exercise!(list, list.check_invariants(token), token);
+
+        let arena = Arena::new();
+        let mut list = fifth::LinkedList::new(&arena);
+        exercise!(list, list.check_invariants(token), token);
+
+        let list = sixth::LinkedList::new();
+        exercise!(list, list.check_invariants(token), token);
+
+        let bump = Bump::new();
+        let mut list = twelfth::LinkedList::new(&bump);
+        exercise!(list, list.check_invariants(token), token);
+    });
+}
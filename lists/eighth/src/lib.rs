@@ -0,0 +1,514 @@
+#![cfg_attr(not(test), no_std)]
+#![forbid(unsafe_code)]
+/// A circular doubly linked list, the tail links back to the head.
+///
+/// Nodes live in one `Vec` and are linked by `u32` indices like in `seventh`, but there is no
+/// separate tail: it is always `head.prev`. Rotating only moves the head, no node is relinked.
+///
+/// Allocation size per value:
+/// sizeof: Node<T> = Option<T> + u32 + u32
+/// => Overhead of list is 8 bytes per entry, plus the `Option` tag for types without a niche
+///
+/// Pros:
+/// - No Dependencies
+/// - No unsafe & fully stable rust
+/// - No tokens required
+/// - `rotate_left(1)`/`rotate_right(1)` are `O(1)`, good for round-robin scheduling
+/// - [`cycle`](LinkedList::cycle) walks around the ring forever
+///
+/// Cons:
+/// - At most `u32::MAX - 1` nodes
+/// - Growing the `Vec` copies every node
+/// - Needs `alloc`
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::mem;
+
+#[cfg(feature = "invariants")]
+pub use invariants::InvariantError;
+
+/// Marks an empty list or the end of the free list, never a valid index.
+const NIL: u32 = u32::MAX;
+
+pub struct LinkedList<T> {
+    len: usize,
+    nodes: Vec<Node<T>>,
+    head: u32,
+    /// Popped nodes, chained through `next`.
+    free: u32,
+}
+
+impl<T> LinkedList<T> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            len: 0,
+            nodes: Vec::with_capacity(capacity),
+            head: NIL,
+            free: NIL,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head == NIL
+    }
+
+    /// One lap around the ring, starting at the front.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            head: self.head,
+            tail: self.tail(),
+            len: self.len,
+        }
+    }
+
+    /// Walks around the ring forever, starting at the front. Empty if the list is.
+    pub fn cycle(&self) -> Cycle<'_, T> {
+        Cycle {
+            list: self,
+            current: self.head,
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.value(self.head)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.value(self.tail())
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.value_mut(self.head)
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.value_mut(self.tail())
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.head = self.link_before_head(value);
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.link_before_head(value);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head;
+        if head == NIL {
+            return None;
+        }
+
+        let next = self.node(head).next;
+        self.head = if next == head { NIL } else { next };
+
+        Some(self.unlink(head))
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail();
+        if tail == NIL {
+            return None;
+        }
+        if tail == self.head {
+            self.head = NIL;
+        }
+
+        Some(self.unlink(tail))
+    }
+
+    /// Moves the first `n` elements to the back by moving the head, `n` wraps around the length.
+    ///
+    /// Walks `min(n, len - n)` nodes, so rotating by one is `O(1)`.
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.len == 0 {
+            return;
+        }
+
+        let n = n % self.len;
+        if n <= self.len / 2 {
+            for _ in 0..n {
+                self.head = self.node(self.head).next;
+            }
+        } else {
+            for _ in n..self.len {
+                self.head = self.node(self.head).prev;
+            }
+        }
+    }
+
+    /// Moves the last `n` elements to the front by moving the head, `n` wraps around the length.
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.len == 0 {
+            return;
+        }
+
+        self.rotate_left(self.len - n % self.len);
+    }
+
+    /// Drops every element, the `Vec` keeps its capacity.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.head = NIL;
+        self.free = NIL;
+        self.len = 0;
+    }
+
+    fn tail(&self) -> u32 {
+        if self.head == NIL {
+            NIL
+        } else {
+            self.node(self.head).prev
+        }
+    }
+
+    /// Links a new node in between the tail and the head, which makes it the new tail.
+    fn link_before_head(&mut self, value: T) -> u32 {
+        let index = self.insert(value);
+        self.len += 1;
+
+        if self.head == NIL {
+            self.head = index;
+
+            let node = self.node_mut(index);
+            node.prev = index;
+            node.next = index;
+
+            return index;
+        }
+
+        let head = self.head;
+        let tail = mem::replace(&mut self.node_mut(head).prev, index);
+        self.node_mut(tail).next = index;
+
+        let node = self.node_mut(index);
+        node.prev = tail;
+        node.next = head;
+
+        index
+    }
+
+    /// Unlinks the node, takes its value and puts it on the free list.
+    fn unlink(&mut self, index: u32) -> T {
+        let Node { prev, next, .. } = *self.node(index);
+        self.node_mut(prev).next = next;
+        self.node_mut(next).prev = prev;
+        self.len -= 1;
+
+        let node = &mut self.nodes[index as usize];
+        let value = node.value.take().unwrap();
+        node.prev = NIL;
+        node.next = mem::replace(&mut self.free, index);
+
+        value
+    }
+
+    fn insert(&mut self, value: T) -> u32 {
+        if self.free != NIL {
+            let index = self.free;
+            let node = &mut self.nodes[index as usize];
+            self.free = mem::replace(&mut node.next, NIL);
+            node.value = Some(value);
+
+            return index;
+        }
+
+        let index = u32::try_from(self.nodes.len())
+            .ok()
+            .filter(|&index| index != NIL)
+            .expect("eighth::LinkedList can hold at most u32::MAX - 1 nodes");
+
+        self.nodes.push(Node {
+            value: Some(value),
+            prev: NIL,
+            next: NIL,
+        });
+
+        index
+    }
+
+    fn node(&self, index: u32) -> &Node<T> {
+        &self.nodes[index as usize]
+    }
+
+    fn node_mut(&mut self, index: u32) -> &mut Node<T> {
+        &mut self.nodes[index as usize]
+    }
+
+    fn value(&self, index: u32) -> Option<&T> {
+        self.nodes.get(index as usize)?.value.as_ref()
+    }
+
+    fn value_mut(&mut self, index: u32) -> Option<&mut T> {
+        self.nodes.get_mut(index as usize)?.value.as_mut()
+    }
+}
+
+#[cfg(feature = "invariants")]
+impl<T> LinkedList<T> {
+    /// Cuts the ring open between the tail and the head, walks it and checks that the links match
+    /// up, that `len` is right and that every node is either linked or free.
+    pub fn check_invariants(&self) -> Result<(), InvariantError> {
+        let head = self.head;
+        let head_tail = (head != NIL).then(|| (head, self.tail()));
+
+        invariants::check_links(
+            head_tail,
+            Some(self.len),
+            |&index| {
+                let node = self.nodes.get(index as usize)?;
+                node.value.as_ref()?;
+
+                let prev = (index != head).then_some(node.prev);
+                let next = (node.next != head).then_some(node.next);
+                Some((prev, next))
+            },
+            |a, b| a == b,
+        )?;
+
+        let mut free_len = 0;
+        let mut free = self.free;
+        while free != NIL && free_len < self.nodes.len() {
+            let Some(node) = self.nodes.get(free as usize) else {
+                return Err(InvariantError::Dangling { index: free_len });
+            };
+            if node.value.is_some() {
+                return Err(InvariantError::Other("a node on the free list has a value"));
+            }
+            free = node.next;
+            free_len += 1;
+        }
+
+        if self.len + free_len != self.nodes.len() {
+            Err(InvariantError::Other(
+                "some nodes are neither linked nor free",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Node<T> {
+    value: Option<T>,
+    prev: u32,
+    next: u32,
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+pub struct IntoIter<T>(LinkedList<T>);
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+pub struct Iter<'a, T> {
+    list: &'a LinkedList<T>,
+    head: u32,
+    tail: u32,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let current = self.head;
+        self.head = self.list.node(current).next;
+        self.len -= 1;
+
+        self.list.value(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let current = self.tail;
+        self.tail = self.list.node(current).prev;
+        self.len -= 1;
+
+        self.list.value(current)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+pub struct Cycle<'a, T> {
+    list: &'a LinkedList<T>,
+    current: u32,
+}
+
+impl<'a, T> Iterator for Cycle<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.list.value(self.current)?;
+        self.current = self.list.node(self.current).next;
+
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn push_pop() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+
+        list.push_back(2);
+        list.push_back(3);
+        list.push_front(1);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), None);
+        assert!(list.is_empty());
+
+        // popped nodes are reused
+        list.push_back(4);
+        list.push_front(5);
+        assert_eq!(list.nodes.len(), 3);
+        assert_eq!(list.to_vec(), vec![5, 4]);
+    }
+
+    #[test]
+    fn iter() {
+        let list: LinkedList<_> = (1..=4).collect();
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![4, 3, 2, 1]
+        );
+        assert_eq!(list.iter().len(), 4);
+        assert_eq!(
+            list.cycle().take(6).copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 1, 2]
+        );
+        assert_eq!(LinkedList::<i32>::new().cycle().next(), None);
+    }
+
+    #[test]
+    fn rotate() {
+        let mut list: LinkedList<_> = (0..5).collect();
+
+        list.rotate_left(1);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 0]);
+        list.rotate_left(4);
+        assert_eq!(list.to_vec(), vec![0, 1, 2, 3, 4]);
+        list.rotate_right(2);
+        assert_eq!(list.to_vec(), vec![3, 4, 0, 1, 2]);
+        list.rotate_right(7);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 0]);
+
+        // round robin: take the front, put it back at the end
+        *list.front_mut().unwrap() += 10;
+        list.rotate_left(1);
+        assert_eq!(list.back(), Some(&11));
+        assert_eq!(list.front(), Some(&2));
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![2, 3, 4, 0, 11]);
+    }
+}
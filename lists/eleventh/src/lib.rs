@@ -0,0 +1,438 @@
+#![cfg_attr(not(test), no_std)]
+#![forbid(unsafe_code)]
+/// A purely functional deque in the style of Okasaki, built from two immutable singly linked
+/// lists.
+///
+/// The front of the deque is one `Rc` cons list and the back is another one, stored reversed.
+/// Pushing and popping only ever touch the heads of those lists. Whenever popping empties one side
+/// the other one is split in half, which is `O(n)` but happens rarely enough that every operation
+/// is amortized `O(1)`.
+///
+/// Nodes are never mutated, so [`clone`](Clone::clone) is `O(1)` and the copies share all of
+/// their nodes. Pushing to or popping from one copy leaves the others untouched.
+///
+/// Allocation size per value:
+/// sizeof: RcBox<Cons<T>> = usize + usize + T + usize
+/// => Overhead of list is between 24 and 32 bytes per entry
+///
+/// Pros:
+/// - No Dependencies
+/// - No unsafe & fully stable rust
+/// - No tokens required
+/// - `clone()` is `O(1)` and every old version stays valid (it's persistent)
+/// - Push and pop at both ends are amortized `O(1)`
+///
+/// Cons:
+/// - Popping clones the value if the node is shared with another copy, so it needs `T: Clone`
+/// - Amortized bounds don't hold if an old version is popped from over and over, rebalancing isn't
+///   lazy like in Okasaki's banker's deque
+/// - Rebalancing rebuilds the whole deque
+/// - `Rc` is `!Send` and `!Sync`
+/// - Allocates every node on the heap individually
+/// - Needs `alloc`
+extern crate alloc;
+
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FusedIterator;
+
+#[cfg(feature = "invariants")]
+pub use invariants::InvariantError;
+
+type Stack<T> = Option<Rc<Cons<T>>>;
+
+struct Cons<T> {
+    value: T,
+    next: Stack<T>,
+}
+
+/// If one side is empty the other one holds at most one element, so both ends are always on top.
+pub struct LinkedList<T> {
+    front: Stack<T>,
+    front_len: usize,
+    /// The back half, last element on top.
+    back: Stack<T>,
+    back_len: usize,
+}
+
+impl<T> LinkedList<T> {
+    pub fn new() -> Self {
+        Self {
+            front: None,
+            front_len: 0,
+            back: None,
+            back_len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.front_len + self.back_len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut back = Vec::with_capacity(self.back_len);
+        let mut current = self.back.as_deref();
+
+        while let Some(cons) = current {
+            back.push(&cons.value);
+            current = cons.next.as_deref();
+        }
+
+        Iter {
+            front: self.front.as_deref(),
+            back,
+            len: self.len(),
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        let cons = self.front.as_deref().or(self.back.as_deref())?;
+        Some(&cons.value)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        let cons = self.back.as_deref().or(self.front.as_deref())?;
+        Some(&cons.value)
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        if self.back.is_none() {
+            // a single node reads the same from both ends, so it can simply change sides
+            self.back = self.front.take();
+            self.back_len = self.front_len;
+            self.front_len = 0;
+        }
+
+        push(&mut self.front, value);
+        self.front_len += 1;
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        if self.front.is_none() {
+            self.front = self.back.take();
+            self.front_len = self.back_len;
+            self.back_len = 0;
+        }
+
+        push(&mut self.back, value);
+        self.back_len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T>
+    where
+        T: Clone,
+    {
+        let value = if let Some(value) = pop(&mut self.front) {
+            self.front_len -= 1;
+            value
+        } else {
+            let value = pop(&mut self.back)?;
+            self.back_len -= 1;
+            value
+        };
+        self.rebalance();
+
+        Some(value)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T>
+    where
+        T: Clone,
+    {
+        let value = if let Some(value) = pop(&mut self.back) {
+            self.back_len -= 1;
+            value
+        } else {
+            let value = pop(&mut self.front)?;
+            self.front_len -= 1;
+            value
+        };
+        self.rebalance();
+
+        Some(value)
+    }
+
+    pub fn clear(&mut self) {
+        unwind(&mut self.front);
+        unwind(&mut self.back);
+        self.front_len = 0;
+        self.back_len = 0;
+    }
+
+    /// Splits the elements evenly between the two sides again once one of them is empty.
+    fn rebalance(&mut self)
+    where
+        T: Clone,
+    {
+        if self.front_len.min(self.back_len) > 0 || self.len() < 2 {
+            return;
+        }
+
+        let mut front = Vec::with_capacity(self.len());
+        drain(&mut self.front, &mut front);
+        let mut back = Vec::with_capacity(self.back_len);
+        drain(&mut self.back, &mut back);
+        front.extend(back.into_iter().rev());
+
+        let back = front.split_off(front.len() / 2);
+        self.front_len = front.len();
+        self.back_len = back.len();
+
+        for value in front.into_iter().rev() {
+            push(&mut self.front, value);
+        }
+        for value in back {
+            push(&mut self.back, value);
+        }
+    }
+}
+
+#[cfg(feature = "invariants")]
+impl<T> LinkedList<T> {
+    /// Checks the length of both halves and that neither end is buried, see [`LinkedList`].
+    pub fn check_invariants(&self) -> Result<(), InvariantError> {
+        for (stack, len) in [(&self.front, self.front_len), (&self.back, self.back_len)] {
+            let found =
+                core::iter::successors(stack.as_deref(), |cons| cons.next.as_deref()).count();
+            if found != len {
+                return Err(InvariantError::LenMismatch { len, found });
+            }
+        }
+
+        if self.front_len.min(self.back_len) == 0 && self.front_len.max(self.back_len) > 1 {
+            return Err(InvariantError::Other(
+                "one half is empty while the other holds more than one element",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn push<T>(stack: &mut Stack<T>, value: T) {
+    let next = stack.take();
+    *stack = Some(Rc::new(Cons { value, next }));
+}
+
+/// Moves the top value out if this is the only reference to it, otherwise clones it.
+fn pop<T: Clone>(stack: &mut Stack<T>) -> Option<T> {
+    let cons = stack.take()?;
+
+    match Rc::try_unwrap(cons) {
+        Ok(cons) => {
+            *stack = cons.next;
+            Some(cons.value)
+        }
+        Err(shared) => {
+            *stack = shared.next.clone();
+            Some(shared.value.clone())
+        }
+    }
+}
+
+fn drain<T: Clone>(stack: &mut Stack<T>, values: &mut Vec<T>) {
+    while let Some(value) = pop(stack) {
+        values.push(value);
+    }
+}
+
+/// Drops a stack node by node instead of recursively, stopping at the first shared node.
+fn unwind<T>(stack: &mut Stack<T>) {
+    while let Some(cons) = stack.take() {
+        match Rc::try_unwrap(cons) {
+            Ok(mut cons) => *stack = cons.next.take(),
+            Err(_) => break,
+        }
+    }
+}
+
+impl<T> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        Self {
+            front: self.front.clone(),
+            front_len: self.front_len,
+            back: self.back.clone(),
+            back_len: self.back_len,
+        }
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+pub struct IntoIter<T>(LinkedList<T>);
+
+impl<T: Clone> IntoIterator for LinkedList<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<T: Clone> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T: Clone> FusedIterator for IntoIter<T> {}
+
+/// Walks the front side directly, the back side has to be collected first since it is reversed.
+pub struct Iter<'a, T> {
+    front: Option<&'a Cons<T>>,
+    /// Last element first, so popping yields them in order.
+    back: Vec<&'a T>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = match self.front {
+            Some(cons) => {
+                self.front = cons.next.as_deref();
+                &cons.value
+            }
+            None => self.back.pop()?,
+        };
+        self.len -= 1;
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn push_pop() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+
+        list.push_back(2);
+        list.push_back(3);
+        list.push_front(1);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), None);
+        assert!(list.is_empty());
+
+        // everything pushed at one end comes out the other
+        list.extend(0..1000);
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!((list.front_len, list.back_len), (499, 500));
+        list.push_front(0);
+        assert_eq!(list.iter().len(), 1000);
+        assert!(list.iter().copied().eq(0..1000));
+        assert!(list.into_iter().rev().eq((0..1000).rev()));
+    }
+
+    #[test]
+    fn persistent() {
+        let mut list: LinkedList<_> = (1..=4).collect();
+        let snapshot = list.clone();
+
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(4));
+        list.push_front(0);
+        list.push_back(5);
+        let other = list.clone();
+        list.clear();
+
+        assert_eq!(snapshot.to_vec(), vec![1, 2, 3, 4]);
+        assert_eq!(other.to_vec(), vec![0, 2, 3, 5]);
+        assert!(list.is_empty());
+        assert_eq!(format!("{snapshot:?}"), "[1, 2, 3, 4]");
+    }
+
+    #[test]
+    fn long_drop() {
+        let mut list = LinkedList::new();
+
+        for i in 0..100_000 {
+            list.push_front(i);
+        }
+        let shared = list.clone();
+        drop(list);
+        assert_eq!(shared.len(), 100_000);
+    }
+}
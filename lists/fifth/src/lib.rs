@@ -1,43 +1,335 @@
 #![cfg_attr(not(test), no_std)]
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::mem;
 use ghost_cell::{GhostCell, GhostToken};
 use static_rc::StaticRcRef;
 use typed_arena::Arena;
 
+#[cfg(feature = "invariants")]
+pub use invariants::InvariantError;
+
 pub struct LinkedList<'arena, 'id, T> {
     arena: &'arena Arena<Node<'arena, 'id, T>>,
     head_tail: Option<(HalfNodePtr<'arena, 'id, T>, HalfNodePtr<'arena, 'id, T>)>,
+    len: usize,
+    /// Popped nodes, their `value` is `None` and they are handed out again before allocating new ones.
+    #[cfg(feature = "alloc")]
+    free: alloc::vec::Vec<&'arena mut GhostNode<'arena, 'id, T>>,
 }
 
 impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
     pub fn new(arena: &'arena Arena<Node<'arena, 'id, T>>) -> Self {
         Self {
             head_tail: None,
+            len: 0,
             arena,
+            #[cfg(feature = "alloc")]
+            free: alloc::vec::Vec::new(),
         }
     }
 
-    pub fn len(&self, token: &GhostToken<'id>) -> usize {
-        self.iter(token).count()
+    pub fn new_in(cx: &ListArena<'_, 'arena, 'id, T>) -> Self {
+        Self::new(cx.arena)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
     }
 
     pub fn is_empty(&self) -> bool {
         self.head_tail.is_none()
     }
 
+    /// Number of nodes allocated in the backing arena, including popped nodes (the arena never frees,
+    /// with `alloc` popped nodes are reused by later pushes on the same list).
+    ///
+    /// The arena may be shared with other lists, in which case their nodes are counted too.
+    pub fn arena_len(&self) -> usize {
+        self.arena.len()
+    }
+
+    /// Number of nodes the backing arena can hold before it has to allocate a new chunk.
+    pub fn arena_capacity(&self) -> usize {
+        self.arena.len() + self.arena.uninitialized_array().len()
+    }
+
+    /// `nodes` counts the whole arena, like [`arena_len`](Self::arena_len), `free_slots` only this
+    /// list's popped nodes.
+    pub fn memory_usage(&self) -> MemoryStats {
+        let bytes_per_node = mem::size_of::<Node<'arena, 'id, T>>();
+
+        MemoryStats {
+            nodes: self.arena_len(),
+            bytes_per_node,
+            total_bytes: self.arena_capacity() * bytes_per_node,
+            #[cfg(feature = "alloc")]
+            free_slots: self.free.len(),
+            #[cfg(not(feature = "alloc"))]
+            free_slots: 0,
+        }
+    }
+
     pub fn iter<'a>(&'a self, token: &'a GhostToken<'id>) -> Iter<'a, 'arena, 'id, T> {
         let head_tail = self.head_tail.as_ref().map(|head_tail| (&*head_tail.0, &*head_tail.1));
 
         Iter {
             token,
             head_tail,
+            len: self.len,
+        }
+    }
+
+    /// Pairs the list with `token`, so it can be iterated in a `for` loop.
+    pub fn view<'a>(&'a self, token: &'a GhostToken<'id>) -> ListView<'a, 'arena, 'id, T> {
+        ListView { list: self, token }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self, token: &GhostToken<'id>) -> alloc::vec::Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter(token).cloned().collect()
+    }
+
+    pub fn eq_with(&self, other: &Self, token: &GhostToken<'id>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter(token).eq(other.iter(token))
+    }
+
+    /// Compares the elements lexicographically, like slices.
+    pub fn cmp_with(&self, other: &Self, token: &GhostToken<'id>) -> Ordering
+    where
+        T: Ord,
+    {
+        self.iter(token).cmp(other.iter(token))
+    }
+
+    /// Feeds the length and then the elements in order to `state`, like `Hash` would.
+    pub fn hash_with<H: Hasher>(&self, token: &GhostToken<'id>, state: &mut H)
+    where
+        T: Hash,
+    {
+        self.len.hash(state);
+
+        for value in self.iter(token) {
+            value.hash(state);
+        }
+    }
+
+    /// Deep-copies the list into `arena`, which may be the one `self` lives in.
+    ///
+    /// Nodes can only be reached through borrows of the token, so the values are staged in a `Vec`
+    /// before they are linked into the copy.
+    #[cfg(feature = "alloc")]
+    pub fn clone_in(
+        &self,
+        arena: &'arena Arena<Node<'arena, 'id, T>>,
+        token: &mut GhostToken<'id>,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let mut clone = Self::new(arena);
+
+        for value in self.to_vec(token) {
+            clone.push_back(value, token);
         }
+
+        clone
+    }
+
+    pub fn front<'a>(&'a self, token: &'a GhostToken<'id>) -> Option<&'a T> {
+        self.end(End::Front)
+            .map(|node| node.borrow(token).value.as_ref().unwrap())
+    }
+
+    pub fn back<'a>(&'a self, token: &'a GhostToken<'id>) -> Option<&'a T> {
+        self.end(End::Back)
+            .map(|node| node.borrow(token).value.as_ref().unwrap())
+    }
+
+    pub fn front_mut<'a>(&'a mut self, token: &'a mut GhostToken<'id>) -> Option<&'a mut T> {
+        self.end(End::Front)
+            .map(|node| node.borrow_mut(token).value.as_mut().unwrap())
+    }
+
+    pub fn back_mut<'a>(&'a mut self, token: &'a mut GhostToken<'id>) -> Option<&'a mut T> {
+        self.end(End::Back)
+            .map(|node| node.borrow_mut(token).value.as_mut().unwrap())
     }
 
     pub fn push_front(&mut self, value: T, token: &mut GhostToken<'id>) {
         let (one, two) = self.new_halves(value);
 
+        self.link_front(one, two, token)
+    }
+
+    pub fn push_back(&mut self, value: T, token: &mut GhostToken<'id>) {
+        let (one, two) = self.new_halves(value);
+
+        self.link_back(one, two, token)
+    }
+
+    /// Pushes every element of `iter` to the back of the list.
+    ///
+    /// Room for the elements the free list can't take is reserved in the arena up front, going by
+    /// the lower bound of the size hint, so the arena grows at most once for them.
+    pub fn extend_from_iter<I>(&mut self, iter: I, token: &mut GhostToken<'id>)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        #[cfg(feature = "alloc")]
+        let additional = iter.size_hint().0.saturating_sub(self.free.len());
+        #[cfg(not(feature = "alloc"))]
+        let additional = iter.size_hint().0;
+        self.arena.reserve_extend(additional);
+
+        for value in iter {
+            self.push_back(value, token);
+        }
+    }
+
+    pub fn pop_front(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+        let (left, right) = self.unlink(End::Front, token)?;
+
+        Some(self.recycle(left, right, token))
+    }
+
+    pub fn pop_back(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+        let (left, right) = self.unlink(End::Back, token)?;
+
+        Some(self.recycle(left, right, token))
+    }
+
+    /// Pops the first element, but only if `pred` returns `true` for it.
+    pub fn pop_front_if<F>(&mut self, pred: F, token: &mut GhostToken<'id>) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        if pred(self.front_mut(token)?) {
+            self.pop_front(token)
+        } else {
+            None
+        }
+    }
+
+    /// Pops the last element, but only if `pred` returns `true` for it.
+    pub fn pop_back_if<F>(&mut self, pred: F, token: &mut GhostToken<'id>) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        if pred(self.back_mut(token)?) {
+            self.pop_back(token)
+        } else {
+            None
+        }
+    }
+
+    pub fn clear(&mut self, token: &mut GhostToken<'id>) {
+        while self.pop_back(token).is_some() {}
+    }
+
+    /// Reverses the list in place by relinking the nodes, no value is moved and nothing is allocated.
+    pub fn reverse(&mut self, token: &mut GhostToken<'id>) {
+        let mut reversed = Self::new(self.arena);
+
+        while let Some((left, right)) = self.unlink(End::Front, token) {
+            reversed.link_front(left, right, token);
+        }
+
+        self.head_tail = reversed.head_tail.take();
+        self.len = reversed.len;
+    }
+
+    pub fn sort(&mut self, token: &mut GhostToken<'id>)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp, token)
+    }
+
+    /// Stable bottom-up merge sort that relinks the nodes, no value is moved and nothing is allocated.
+    ///
+    /// If `cmp` panics, the list still holds every element, in no particular order.
+    pub fn sort_by<F>(&mut self, mut cmp: F, token: &mut GhostToken<'id>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut width = 1;
+
+        loop {
+            let mut pass = SortPass {
+                sorted: Self::new(self.arena),
+                left: Self::new(self.arena),
+                right: Self::new(self.arena),
+                list: &mut *self,
+                token: &mut *token,
+            };
+            let mut merges = 0;
+
+            while !pass.list.is_empty() {
+                pass.list.move_front_to(&mut pass.left, width, pass.token);
+                pass.list.move_front_to(&mut pass.right, width, pass.token);
+
+                loop {
+                    let from_left = match (&pass.left.head_tail, &pass.right.head_tail) {
+                        (Some((l, _)), Some((r, _))) => {
+                            let l = l.borrow(pass.token).value.as_ref().unwrap();
+                            let r = r.borrow(pass.token).value.as_ref().unwrap();
+                            // prefer the left run on ties to keep the sort stable
+                            cmp(l, r) != Ordering::Greater
+                        }
+                        (Some(_), None) => true,
+                        (None, Some(_)) => false,
+                        (None, None) => break,
+                    };
+
+                    let run = if from_left {
+                        &mut pass.left
+                    } else {
+                        &mut pass.right
+                    };
+                    let (one, two) = run.unlink(End::Front, pass.token).unwrap();
+                    pass.sorted.link_back(one, two, pass.token);
+                }
+
+                merges += 1;
+            }
+            drop(pass);
+
+            if merges <= 1 {
+                break;
+            }
+            width *= 2;
+        }
+    }
+
+    fn move_front_to(&mut self, other: &mut Self, n: usize, token: &mut GhostToken<'id>) {
+        for _ in 0..n {
+            match self.unlink(End::Front, token) {
+                Some((one, two)) => other.link_back(one, two, token),
+                None => break,
+            }
+        }
+    }
+
+    fn link_front(
+        &mut self,
+        one: HalfNodePtr<'arena, 'id, T>,
+        two: HalfNodePtr<'arena, 'id, T>,
+        token: &mut GhostToken<'id>,
+    ) {
         let head_tail = if let Some((head, tail)) = self.head_tail.take() {
             head.borrow_mut(token).prev = Some(one);
             two.borrow_mut(token).next = Some(head);
@@ -47,12 +339,16 @@ impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
             (one, two)
         };
 
-        self.head_tail = Some(head_tail)
+        self.head_tail = Some(head_tail);
+        self.len += 1;
     }
 
-    pub fn push_back(&mut self, value: T, token: &mut GhostToken<'id>) {
-        let (one, two) = self.new_halves(value);
-
+    fn link_back(
+        &mut self,
+        one: HalfNodePtr<'arena, 'id, T>,
+        two: HalfNodePtr<'arena, 'id, T>,
+        token: &mut GhostToken<'id>,
+    ) {
         let head_tail = if let Some((head, tail)) = self.head_tail.take() {
             tail.borrow_mut(token).next = Some(one);
             two.borrow_mut(token).prev = Some(tail);
@@ -62,54 +358,59 @@ impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
             (one, two)
         };
 
-        self.head_tail = Some(head_tail)
+        self.head_tail = Some(head_tail);
+        self.len += 1;
     }
 
-    pub fn pop_front(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
-        let (head, tail) = self.head_tail.take()?;
-
-        // when there is only one element in the list
-        if StaticRcRef::as_ptr(&head) == StaticRcRef::as_ptr(&tail) {
-            return Some(Self::into_inner(head, tail, token));
-        }
-
-        let next = head.borrow_mut(token).next.take().unwrap();
-        let other_head = next.borrow_mut(token).prev.take().unwrap();
-
-        self.head_tail = Some((next, tail));
-
-        Some(Self::into_inner(head, other_head, token))
+    fn end(&self, end: End) -> Option<&HalfNodePtr<'arena, 'id, T>> {
+        self.head_tail.as_ref().map(|(head, tail)| match end {
+            End::Front => head,
+            End::Back => tail,
+        })
     }
 
-    pub fn pop_back(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+    /// Detaches the node at `end` and returns both halves pointing to it.
+    fn unlink(
+        &mut self,
+        end: End,
+        token: &mut GhostToken<'id>,
+    ) -> Option<(HalfNodePtr<'arena, 'id, T>, HalfNodePtr<'arena, 'id, T>)> {
         let (head, tail) = self.head_tail.take()?;
 
+        self.len -= 1;
+
         // when there is only one element in the list
         if StaticRcRef::as_ptr(&head) == StaticRcRef::as_ptr(&tail) {
-            return Some(Self::into_inner(head, tail, token));
+            return Some((head, tail));
         }
 
-        let prev = tail
-            .borrow_mut(token)
-            .prev
-            .take()
-            .expect("Non-head should have a left node");
-        let other_tail = prev
-            .borrow_mut(token)
-            .next
-            .take()
-            .expect("Non-tail should have a right node");
+        let (node, other_end) = match end {
+            End::Front => (head, tail),
+            End::Back => (tail, head),
+        };
 
-        self.head_tail = Some((head, prev));
+        let neighbour = node.borrow_mut(token).link(end.opposite()).take().unwrap();
+        let other_half = neighbour.borrow_mut(token).link(end).take().unwrap();
 
-        Some(Self::into_inner(tail, other_tail, token))
-    }
+        self.head_tail = Some(match end {
+            End::Front => (neighbour, other_end),
+            End::Back => (other_end, neighbour),
+        });
 
-    pub fn clear(&mut self, token: &mut GhostToken<'id>) {
-        while self.pop_back(token).is_some() {}
+        Some((node, other_half))
     }
 
-    fn new_halves(&self, value: T) -> (HalfNodePtr<'arena, 'id, T>, HalfNodePtr<'arena, 'id, T>) {
+    fn new_halves(
+        &mut self,
+        value: T,
+    ) -> (HalfNodePtr<'arena, 'id, T>, HalfNodePtr<'arena, 'id, T>) {
+        #[cfg(feature = "alloc")]
+        if let Some(ghost_cell) = self.free.pop() {
+            ghost_cell.get_mut().value = Some(value);
+
+            return StaticRcRef::split::<1, 1>(FullNodePtr::new(ghost_cell));
+        }
+
         let node = self.arena.alloc(Node {
             value: Some(value),
             prev: None,
@@ -121,7 +422,9 @@ impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
         StaticRcRef::split::<1, 1>(full)
     }
 
-    fn into_inner(
+    /// Takes the value out of an unlinked node and keeps the node around for reuse.
+    fn recycle(
+        &mut self,
         left: HalfNodePtr<'arena, 'id, T>,
         right: HalfNodePtr<'arena, 'id, T>,
         token: &mut GhostToken<'id>,
@@ -134,24 +437,438 @@ impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
         debug_assert!(node.prev.is_none());
         debug_assert!(node.next.is_none());
 
-        node.value.take().unwrap()
+        let value = node.value.take().unwrap();
+
+        #[cfg(feature = "alloc")]
+        self.free.push(ghost_cell);
+
+        value
     }
 }
 
+#[cfg(feature = "invariants")]
+impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
+    /// Walks the list and checks that the links match up, that `len` is right and that exactly the
+    /// popped nodes have no value.
+    pub fn check_invariants(&self, token: &GhostToken<'id>) -> Result<(), InvariantError> {
+        invariants::check_links(
+            self.head_tail.as_ref().map(|(head, tail)| (head, tail)),
+            Some(self.len),
+            |&node| {
+                let node = node.borrow(token);
+                // a popped node still in the list counts as an empty slot
+                node.value.as_ref()?;
+                Some((node.prev.as_ref(), node.next.as_ref()))
+            },
+            |a, b| StaticRcRef::as_ptr(a) == StaticRcRef::as_ptr(b),
+        )?;
+
+        #[cfg(feature = "alloc")]
+        if self
+            .free
+            .iter()
+            .any(|node| node.borrow(token).value.is_some())
+        {
+            return Err(InvariantError::Other("a node on the free list has a value"));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "debug-viz")]
+impl<'arena, 'id, T: core::fmt::Debug> LinkedList<'arena, 'id, T> {
+    /// Writes the nodes and their `next` (solid) and `prev` (dashed) links as a GraphViz digraph,
+    /// e.g. for `dot -Tsvg`.
+    ///
+    /// Nodes are named by address and every link is drawn as stored, so a broken relink shows up
+    /// as a stray edge. There is no length to stop at, so a cycle makes this loop forever.
+    pub fn dot<W: core::fmt::Write>(
+        &self,
+        out: &mut W,
+        token: &GhostToken<'id>,
+    ) -> core::fmt::Result {
+        use core::fmt::Write;
+
+        writeln!(out, "digraph {{")?;
+        writeln!(out, "    rankdir=LR;")?;
+        writeln!(out, "    node [shape=box];")?;
+
+        if let Some((head, tail)) = &self.head_tail {
+            writeln!(out, "    head [shape=plaintext];")?;
+            writeln!(out, "    tail [shape=plaintext];")?;
+            writeln!(out, "    head -> \"{:p}\";", StaticRcRef::as_ptr(head))?;
+            writeln!(out, "    tail -> \"{:p}\";", StaticRcRef::as_ptr(tail))?;
+        }
+
+        let mut current = self.head_tail.as_ref().map(|(head, _)| head);
+        while let Some(node_ref) = current {
+            let node = node_ref.borrow(token);
+
+            write!(out, "    \"{:p}\" [label=\"", StaticRcRef::as_ptr(node_ref))?;
+            match &node.value {
+                Some(value) => write!(DotLabel(out), "{:?}", value)?,
+                // a node that was popped but is still linked from somewhere
+                None => out.write_str("(removed)")?,
+            }
+            writeln!(out, "\"];")?;
+
+            if let Some(next) = &node.next {
+                writeln!(
+                    out,
+                    "    \"{:p}\" -> \"{:p}\";",
+                    StaticRcRef::as_ptr(node_ref),
+                    StaticRcRef::as_ptr(next)
+                )?;
+            }
+            if let Some(prev) = &node.prev {
+                writeln!(
+                    out,
+                    "    \"{:p}\" -> \"{:p}\" [style=dashed];",
+                    StaticRcRef::as_ptr(node_ref),
+                    StaticRcRef::as_ptr(prev)
+                )?;
+            }
+
+            current = node.next.as_ref();
+        }
+
+        writeln!(out, "}}")
+    }
+
+    /// Renders the list on one line, like `head -> [1] <-> [2] <-> [3] <- tail`.
+    ///
+    /// A `->` between two elements means the second one's `prev` doesn't point back at the first.
+    /// Like [`dot`](Self::dot), a cycle makes it go on forever.
+    pub fn dump<'a>(&'a self, token: &'a GhostToken<'id>) -> Dump<'a, 'arena, 'id, T> {
+        Dump { list: self, token }
+    }
+
+    /// Writes the same graph as [`dot`](Self::dot) as a Mermaid `flowchart LR`, for docs and issues
+    /// that render Mermaid.
+    pub fn mermaid<W: core::fmt::Write>(
+        &self,
+        out: &mut W,
+        token: &GhostToken<'id>,
+    ) -> core::fmt::Result {
+        use core::fmt::Write;
+
+        writeln!(out, "flowchart LR")?;
+
+        if let Some((head, tail)) = &self.head_tail {
+            writeln!(out, "    head([head]) --> n{:p}", StaticRcRef::as_ptr(head))?;
+            writeln!(out, "    tail([tail]) --> n{:p}", StaticRcRef::as_ptr(tail))?;
+        }
+
+        let mut current = self.head_tail.as_ref().map(|(head, _)| head);
+        while let Some(node_ref) = current {
+            let node = node_ref.borrow(token);
+
+            write!(out, "    n{:p}[\"", StaticRcRef::as_ptr(node_ref))?;
+            match &node.value {
+                Some(value) => write!(MermaidLabel(out), "{:?}", value)?,
+                None => out.write_str("(removed)")?,
+            }
+            writeln!(out, "\"]")?;
+
+            if let Some(next) = node.next.as_ref() {
+                writeln!(
+                    out,
+                    "    n{:p} --> n{:p}",
+                    StaticRcRef::as_ptr(node_ref),
+                    StaticRcRef::as_ptr(next)
+                )?;
+            }
+            if let Some(prev) = node.prev.as_ref() {
+                writeln!(
+                    out,
+                    "    n{:p} -.-> n{:p}",
+                    StaticRcRef::as_ptr(node_ref),
+                    StaticRcRef::as_ptr(prev)
+                )?;
+            }
+
+            current = node.next.as_ref();
+        }
+
+        Ok(())
+    }
+}
+
+/// Escapes a GraphViz label as it is written.
+#[cfg(feature = "debug-viz")]
+struct DotLabel<'a, W>(&'a mut W);
+
+#[cfg(feature = "debug-viz")]
+impl<W: core::fmt::Write> core::fmt::Write for DotLabel<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for c in s.chars() {
+            if matches!(c, '"' | '\\') {
+                self.0.write_char('\\')?;
+            }
+            self.0.write_char(c)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Escapes a Mermaid label as it is written.
+#[cfg(feature = "debug-viz")]
+struct MermaidLabel<'a, W>(&'a mut W);
+
+#[cfg(feature = "debug-viz")]
+impl<W: core::fmt::Write> core::fmt::Write for MermaidLabel<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for part in s.split_inclusive('"') {
+            match part.strip_suffix('"') {
+                Some(part) => {
+                    self.0.write_str(part)?;
+                    self.0.write_str("#quot;")?;
+                }
+                None => self.0.write_str(part)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The one line rendering of a list, see [`LinkedList::dump`].
+#[cfg(feature = "debug-viz")]
+pub struct Dump<'a, 'arena, 'id, T> {
+    list: &'a LinkedList<'arena, 'id, T>,
+    token: &'a GhostToken<'id>,
+}
+
+#[cfg(feature = "debug-viz")]
+impl<'a, 'arena, 'id, T: core::fmt::Debug> core::fmt::Display for Dump<'a, 'arena, 'id, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let token = self.token;
+        let list = self.list;
+
+        f.write_str("head -> ")?;
+
+        let mut last = None;
+        let mut current = list.head_tail.as_ref().map(|(head, _)| head);
+        while let Some(node_ref) = current {
+            let node = node_ref.borrow(token);
+
+            if let Some(last) = last {
+                let linked_back = node
+                    .prev
+                    .as_ref()
+                    .is_some_and(|prev| StaticRcRef::as_ptr(prev) == StaticRcRef::as_ptr(last));
+                f.write_str(if linked_back { " <-> " } else { " -> " })?;
+            }
+            match &node.value {
+                Some(value) => write!(f, "[{:?}]", value)?,
+                None => f.write_str("[(removed)]")?,
+            }
+
+            last = Some(node_ref);
+            current = node.next.as_ref();
+        }
+
+        if last.is_none() {
+            f.write_str("(empty)")?;
+        }
+
+        f.write_str(" <- tail")
+    }
+}
+
+/// What a list costs in memory, see [`LinkedList::memory_usage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Nodes allocated so far, in use or not.
+    pub nodes: usize,
+    pub bytes_per_node: usize,
+    /// Everything reserved for nodes, including capacity that hasn't been handed out yet.
+    pub total_bytes: usize,
+    /// How many of `nodes` are free and will be reused before allocating again.
+    pub free_slots: usize,
+}
+
 pub struct Node<'arena, 'id, T> {
     value: Option<T>,
     prev: Option<HalfNodePtr<'arena, 'id, T>>,
     next: Option<HalfNodePtr<'arena, 'id, T>>,
 }
 
+impl<'arena, 'id, T> Node<'arena, 'id, T> {
+    /// The link pointing towards `end`.
+    fn link(&mut self, end: End) -> &mut Option<HalfNodePtr<'arena, 'id, T>> {
+        match end {
+            End::Front => &mut self.prev,
+            End::Back => &mut self.next,
+        }
+    }
+}
+
+/// One end of a list. Nodes are only reachable through their neighbours' halves, so the ends are
+/// the only nodes that can be unlinked without walking to them.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum End {
+    Front,
+    Back,
+}
+
+impl End {
+    fn opposite(self) -> Self {
+        match self {
+            End::Front => End::Back,
+            End::Back => End::Front,
+        }
+    }
+}
+
+/// One pass of [`LinkedList::sort_by`]. Dropping it puts the merged nodes back into the list,
+/// followed by the runs being merged and the nodes not reached yet, so a panicking comparison
+/// doesn't lose any of them.
+struct SortPass<'a, 'arena, 'id, T> {
+    list: &'a mut LinkedList<'arena, 'id, T>,
+    sorted: LinkedList<'arena, 'id, T>,
+    left: LinkedList<'arena, 'id, T>,
+    right: LinkedList<'arena, 'id, T>,
+    token: &'a mut GhostToken<'id>,
+}
+
+impl<'a, 'arena, 'id, T> Drop for SortPass<'a, 'arena, 'id, T> {
+    fn drop(&mut self) {
+        self.left
+            .move_front_to(&mut self.sorted, usize::MAX, self.token);
+        self.right
+            .move_front_to(&mut self.sorted, usize::MAX, self.token);
+        self.list
+            .move_front_to(&mut self.sorted, usize::MAX, self.token);
+
+        self.list.head_tail = self.sorted.head_tail.take();
+        self.list.len = self.sorted.len;
+    }
+}
+
 type GhostNode<'arena, 'id, T> = GhostCell<'id, Node<'arena, 'id, T>>;
 
 type HalfNodePtr<'arena, 'id, T> = StaticRcRef<'arena, GhostNode<'arena, 'id, T>, 1, 2>;
 type FullNodePtr<'arena, 'id, T> = StaticRcRef<'arena, GhostNode<'arena, 'id, T>, 2, 2>;
 
+/// An arena and the token for the lists in it, so they can be passed around as one.
+///
+/// The methods mirror the ones on [`LinkedList`] that need the token.
+pub struct ListArena<'a, 'arena, 'id, T> {
+    arena: &'arena Arena<Node<'arena, 'id, T>>,
+    token: &'a mut GhostToken<'id>,
+}
+
+impl<'a, 'arena, 'id, T> ListArena<'a, 'arena, 'id, T> {
+    pub fn new(arena: &'arena Arena<Node<'arena, 'id, T>>, token: &'a mut GhostToken<'id>) -> Self {
+        Self { arena, token }
+    }
+
+    pub fn new_list(&self) -> LinkedList<'arena, 'id, T> {
+        LinkedList::new(self.arena)
+    }
+
+    pub fn arena(&self) -> &'arena Arena<Node<'arena, 'id, T>> {
+        self.arena
+    }
+
+    /// For the parts of the list API that don't have a counterpart here.
+    pub fn token(&mut self) -> &mut GhostToken<'id> {
+        self.token
+    }
+
+    pub fn len(&self, list: &LinkedList<'arena, 'id, T>) -> usize {
+        list.len()
+    }
+
+    pub fn iter<'b>(&'b self, list: &'b LinkedList<'arena, 'id, T>) -> Iter<'b, 'arena, 'id, T> {
+        list.iter(self.token)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self, list: &LinkedList<'arena, 'id, T>) -> alloc::vec::Vec<T>
+    where
+        T: Clone,
+    {
+        list.to_vec(self.token)
+    }
+
+    pub fn front<'b>(&'b self, list: &'b LinkedList<'arena, 'id, T>) -> Option<&'b T> {
+        list.front(self.token)
+    }
+
+    pub fn back<'b>(&'b self, list: &'b LinkedList<'arena, 'id, T>) -> Option<&'b T> {
+        list.back(self.token)
+    }
+
+    pub fn front_mut<'b>(
+        &'b mut self,
+        list: &'b mut LinkedList<'arena, 'id, T>,
+    ) -> Option<&'b mut T> {
+        list.front_mut(self.token)
+    }
+
+    pub fn back_mut<'b>(
+        &'b mut self,
+        list: &'b mut LinkedList<'arena, 'id, T>,
+    ) -> Option<&'b mut T> {
+        list.back_mut(self.token)
+    }
+
+    pub fn push_front(&mut self, list: &mut LinkedList<'arena, 'id, T>, value: T) {
+        list.push_front(value, self.token)
+    }
+
+    pub fn push_back(&mut self, list: &mut LinkedList<'arena, 'id, T>, value: T) {
+        list.push_back(value, self.token)
+    }
+
+    pub fn pop_front(&mut self, list: &mut LinkedList<'arena, 'id, T>) -> Option<T> {
+        list.pop_front(self.token)
+    }
+
+    pub fn pop_back(&mut self, list: &mut LinkedList<'arena, 'id, T>) -> Option<T> {
+        list.pop_back(self.token)
+    }
+
+    pub fn clear(&mut self, list: &mut LinkedList<'arena, 'id, T>) {
+        list.clear(self.token)
+    }
+}
+
+/// A list and its token, see [`LinkedList::view`].
+pub struct ListView<'a, 'arena, 'id, T> {
+    list: &'a LinkedList<'arena, 'id, T>,
+    token: &'a GhostToken<'id>,
+}
+
+impl<'a, 'arena, 'id, T> Clone for ListView<'a, 'arena, 'id, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, 'arena, 'id, T> Copy for ListView<'a, 'arena, 'id, T> {}
+
+impl<'a, 'arena, 'id, T> IntoIterator for ListView<'a, 'arena, 'id, T>
+where
+    'arena: 'a,
+{
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, 'arena, 'id, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter(self.token)
+    }
+}
+
 pub struct Iter<'a, 'arena, 'id, T> {
     token: &'a GhostToken<'id>,
     head_tail: Option<(&'a GhostNode<'arena, 'id, T>, &'a GhostNode<'arena, 'id, T>)>,
+    len: usize,
 }
 
 impl<'a, 'arena, 'id, T> Iterator for Iter<'a, 'arena, 'id, T>
@@ -170,9 +887,18 @@ where
         } else {
             self.head_tail = None;
         }
+        self.len -= 1;
 
         Some(node.value.as_ref().unwrap())
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
 }
 
 impl<'a, 'arena, 'id, T> DoubleEndedIterator for Iter<'a, 'arena, 'id, T>
@@ -189,11 +915,16 @@ where
         } else {
             self.head_tail = None;
         }
+        self.len -= 1;
 
         Some(node.value.as_ref().unwrap())
     }
 }
 
+impl<'a, 'arena, 'id, T> ExactSizeIterator for Iter<'a, 'arena, 'id, T> where 'arena: 'a {}
+
+impl<'a, 'arena, 'id, T> FusedIterator for Iter<'a, 'arena, 'id, T> where 'arena: 'a {}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -249,6 +980,402 @@ mod test {
         });
     }
 
+    #[test]
+    fn pop_alternating() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in 1..=5 {
+                list.push_back(i, token);
+            }
+
+            assert_eq!(list.pop_back(token), Some(5));
+            assert_eq!(list.pop_front(token), Some(1));
+            assert_eq!(list.pop_back(token), Some(4));
+            assert_eq!(list.front(token), Some(&2));
+            assert_eq!(list.back(token), Some(&3));
+
+            list.push_front(0, token);
+            assert_eq!(list.pop_front(token), Some(0));
+            assert_eq!(list.pop_back(token), Some(3));
+            assert_eq!(list.front(token), list.back(token));
+            assert_eq!(list.pop_front(token), Some(2));
+            assert_eq!(list.pop_back(token), None);
+            assert_eq!(list.pop_front(token), None);
+
+            // the relinked ends still work after the list ran empty
+            list.push_back(6, token);
+            list.push_front(7, token);
+            assert_eq!(list.iter(token).copied().collect::<Vec<_>>(), vec![7, 6]);
+            assert_eq!(list.pop_back(token), Some(6));
+            assert_eq!(list.pop_back(token), Some(7));
+            assert!(list.is_empty());
+        });
+    }
+
+    #[test]
+    fn len() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            assert_eq!(list.len(), 0);
+
+            for i in [3, 1, 2] {
+                list.push_back(i, token);
+            }
+            list.push_front(0, token);
+            assert_eq!(list.len(), 4);
+
+            list.reverse(token);
+            assert_eq!(list.len(), 4);
+            list.sort(token);
+            assert_eq!(list.len(), 4);
+
+            list.pop_back(token);
+            list.pop_front(token);
+            assert_eq!(list.len(), 2);
+
+            list.clear(token);
+            assert_eq!(list.len(), 0);
+            assert!(list.is_empty());
+        });
+    }
+
+    #[test]
+    fn front_back() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            assert_eq!(list.front(token), None);
+            assert_eq!(list.back_mut(token), None);
+
+            list.push_back(1, token);
+            assert_eq!(list.front(token), Some(&1));
+            assert_eq!(list.back(token), Some(&1));
+
+            list.push_back(2, token);
+            *list.front_mut(token).unwrap() = 10;
+            *list.back_mut(token).unwrap() += 10;
+            assert_eq!(list.front(token), Some(&10));
+            assert_eq!(list.back(token), Some(&12));
+        });
+    }
+
+    #[test]
+    fn compare() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            let mut other = LinkedList::new(&arena);
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
+
+            assert!(!list.eq_with(&other, token));
+            assert_eq!(other.cmp_with(&list, token), Ordering::Less);
+
+            for i in [1, 2, 4] {
+                other.push_back(i, token);
+            }
+            assert_eq!(other.cmp_with(&list, token), Ordering::Greater);
+
+            other.pop_back(token);
+            other.push_back(3, token);
+            assert!(list.eq_with(&other, token));
+            assert_eq!(list.cmp_with(&other, token), Ordering::Equal);
+        });
+    }
+
+    #[test]
+    fn hash_with() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash<'id>(list: &LinkedList<'_, 'id, i32>, token: &GhostToken<'id>) -> u64 {
+            let mut state = DefaultHasher::new();
+            list.hash_with(token, &mut state);
+            state.finish()
+        }
+
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            let mut other = LinkedList::new(&arena);
+
+            for i in [1, 2] {
+                list.push_back(i, token);
+                other.push_front(i, token);
+            }
+            assert_ne!(hash(&list, token), hash(&other, token));
+
+            other.pop_back(token);
+            other.push_front(1, token);
+            assert_eq!(hash(&list, token), hash(&other, token));
+        });
+    }
+
+    #[test]
+    fn reverse() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            list.reverse(token);
+            assert!(list.is_empty());
+
+            list.push_back(1, token);
+            list.reverse(token);
+            assert_eq!(list.iter(token).copied().collect::<Vec<_>>(), vec![1]);
+
+            for i in 2..=4 {
+                list.push_back(i, token);
+            }
+            list.reverse(token);
+
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![4, 3, 2, 1]
+            );
+            assert_eq!(
+                list.iter(token).rev().copied().collect::<Vec<_>>(),
+                vec![1, 2, 3, 4]
+            );
+            assert_eq!(arena.len(), 4);
+
+            assert_eq!(list.pop_back(token), Some(1));
+            assert_eq!(list.pop_front(token), Some(4));
+        });
+    }
+
+    #[test]
+    fn sort() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            list.sort(token);
+            assert!(list.is_empty());
+
+            for v in [5, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5] {
+                list.push_back(v, token);
+            }
+            list.sort(token);
+
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![1, 1, 2, 3, 4, 5, 5, 5, 5, 6, 9]
+            );
+            assert_eq!(list.iter(token).next_back(), Some(&9));
+            assert_eq!(arena.len(), 11);
+        });
+
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for (i, key) in [3, 1, 3, 2, 1, 3].into_iter().enumerate() {
+                list.push_back((key, i), token);
+            }
+            list.sort_by(|a, b| a.0.cmp(&b.0), token);
+
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![(1, 1), (1, 4), (2, 3), (3, 0), (3, 2), (3, 5)]
+            );
+        });
+    }
+
+    #[test]
+    fn sort_panic() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for v in [5, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5] {
+                list.push_back(v, token);
+            }
+
+            let mut comparisons = 0;
+            let sorted = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                list.sort_by(
+                    |a, b| {
+                        comparisons += 1;
+                        assert!(comparisons < 12, "comparator panicked");
+                        a.cmp(b)
+                    },
+                    token,
+                )
+            }));
+            assert!(sorted.is_err());
+
+            assert_eq!(list.len(), 11);
+            assert_eq!(list.iter(token).rev().count(), 11);
+            let mut values = list.iter(token).copied().collect::<Vec<_>>();
+            values.sort();
+            assert_eq!(values, vec![1, 1, 2, 3, 4, 5, 5, 5, 5, 6, 9]);
+
+            list.sort(token);
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![1, 1, 2, 3, 4, 5, 5, 5, 5, 6, 9]
+            );
+        });
+    }
+
+    #[test]
+    fn arena_capacity() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::with_capacity(4);
+            let mut list = LinkedList::new(&arena);
+
+            assert_eq!(list.arena_len(), 0);
+            assert_eq!(list.arena_capacity(), 4);
+
+            for i in 0..4 {
+                list.push_back(i, token);
+            }
+
+            assert_eq!(list.arena_len(), 4);
+            assert_eq!(list.arena_capacity(), 4);
+
+            list.push_back(4, token);
+            list.pop_front(token);
+
+            assert_eq!(list.arena_len(), 5);
+            assert_eq!(list.arena_capacity(), 4 + 8);
+        });
+    }
+
+    #[test]
+    fn extend_from_iter() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::with_capacity(4);
+            let mut list = LinkedList::new(&arena);
+
+            list.extend_from_iter(0..1000, token);
+            assert_eq!(list.len(), 1000);
+            assert_eq!(list.arena_len(), 1000);
+            assert_eq!(list.iter(token).next_back(), Some(&999));
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn memory_usage() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::with_capacity(4);
+            let mut list = LinkedList::new(&arena);
+            let stats = list.memory_usage();
+            assert_eq!(stats.nodes, 0);
+            assert_eq!(stats.total_bytes, 4 * stats.bytes_per_node);
+
+            list.push_back(1, token);
+            list.push_back(2, token);
+            list.pop_front(token);
+
+            let stats = list.memory_usage();
+            assert_eq!(stats.nodes, 2);
+            assert_eq!(stats.free_slots, 1);
+
+            list.push_back(3, token);
+            assert_eq!(list.memory_usage().nodes, 2);
+            assert_eq!(list.memory_usage().free_slots, 0);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn freelist() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in 0..1000 {
+                list.push_back(i, token);
+                list.push_front(i, token);
+                assert_eq!(list.pop_back(token), Some(i));
+            }
+            list.clear(token);
+            assert_eq!(list.arena_len(), 1000 + 1);
+
+            for i in 0..1000 {
+                list.push_back(i, token);
+            }
+            assert_eq!(list.arena_len(), 1000 + 1);
+            assert!(list.iter(token).copied().eq(0..1000));
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn clone() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
+
+            let mut clone = list.clone_in(&arena, token);
+
+            *clone.front_mut(token).unwrap() = 10;
+            list.pop_back(token);
+
+            assert_eq!(list.to_vec(token), vec![1, 2]);
+            assert_eq!(clone.to_vec(token), vec![10, 2, 3]);
+            assert_eq!(
+                clone.iter(token).rev().copied().collect::<Vec<_>>(),
+                vec![3, 2, 10]
+            );
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            assert_eq!(list.to_vec(token), Vec::<i32>::new());
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
+            assert_eq!(list.to_vec(token), vec![1, 2, 3]);
+        });
+    }
+
+    #[test]
+    fn list_arena() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut cx = ListArena::new(&arena, token);
+            let mut one = LinkedList::new_in(&cx);
+            let mut two = cx.new_list();
+
+            cx.push_back(&mut one, 1);
+            cx.push_back(&mut one, 2);
+            cx.push_front(&mut two, 3);
+            *cx.front_mut(&mut two).unwrap() += 1;
+
+            assert_eq!(cx.len(&one), 2);
+            assert_eq!(cx.back(&one), Some(&2));
+            assert_eq!(cx.iter(&two).collect::<Vec<_>>(), vec![&4]);
+            assert_eq!(cx.pop_front(&mut one), Some(1));
+
+            one.reverse(cx.token());
+            cx.push_back(&mut one, 5);
+            assert_eq!(cx.pop_back(&mut one), Some(5));
+            cx.clear(&mut one);
+            cx.clear(&mut two);
+            assert!(one.is_empty());
+        });
+    }
+
     #[test]
     pub fn iter() {
         GhostToken::new(|ref mut token| {
@@ -263,7 +1390,83 @@ mod test {
             assert_eq!(
                 list.iter(token).copied().collect::<Vec<_>>(),
                 vec![1, 2, 3, 4]
-            )
+            );
+
+            let mut iter = list.iter(token);
+            assert_eq!(iter.len(), 4);
+            iter.next();
+            iter.next_back();
+            assert_eq!(iter.size_hint(), (2, Some(2)));
+            iter.next();
+            iter.next();
+            assert_eq!(iter.len(), 0);
+            assert_eq!(iter.next(), None);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "debug-viz")]
+    fn dot() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            list.push_back("a", token);
+            list.push_back("\"b\"", token);
+
+            let mut out = String::new();
+            list.dot(&mut out, token).unwrap();
+
+            assert!(out.starts_with("digraph {\n"));
+            assert!(out.ends_with("}\n"));
+            assert!(out.contains(r#"[label="\"a\""]"#));
+            assert!(out.contains(r#"[label="\"\\\"b\\\"\""]"#));
+            // head, tail, one next and one prev link
+            assert_eq!(out.matches(" -> ").count(), 4);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "debug-viz")]
+    fn dump() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            assert_eq!(list.dump(token).to_string(), "head -> (empty) <- tail");
+
+            list.push_back("a", token);
+            list.push_back("\"b\"", token);
+            assert_eq!(
+                list.dump(token).to_string(),
+                r#"head -> ["a"] <-> ["\"b\""] <- tail"#
+            );
+
+            let mut out = String::new();
+            list.mermaid(&mut out, token).unwrap();
+
+            assert!(out.starts_with("flowchart LR\n"));
+            assert!(out.contains(r##"["#quot;a#quot;"]"##));
+            assert_eq!(out.matches(" --> ").count(), 3);
+            assert_eq!(out.matches(" -.-> ").count(), 1);
+        });
+    }
+
+    #[test]
+    fn view() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
+
+            let view = list.view(token);
+            let mut sum = 0;
+            for value in view {
+                sum += value;
+            }
+            assert_eq!(sum, 6);
+            assert_eq!(view.into_iter().next_back(), Some(&3));
         });
     }
 }
@@ -13,7 +13,7 @@
 /// - No re-allocation required
 ///
 /// Cons:
-/// - Feature incomplete (`.iter()`, `.iter_mut()` and Cursors cant be implemented due to `Rc` and `RefCell`)
+/// - Feature incomplete (`.iter()`, `.iter_mut()` and Cursors cant be implemented due to `Rc` and `RefCell`, `for_each` and `for_each_mut` walk the list instead)
 /// - `Rc` and `RefCell` are slow (reference counting and locking)
 /// - `Rc` and `RefCell` are `!Send` and `!Sync` (but using `Arc` and `Mutex` would be even worse)
 /// - We have to manually drop() pointers when removing nodes. If we forget, our program will panic.
@@ -24,6 +24,10 @@ extern crate alloc;
 
 use alloc::rc::Rc;
 use core::cell::RefCell;
+use core::iter::FusedIterator;
+
+#[cfg(feature = "invariants")]
+pub use invariants::InvariantError;
 
 pub struct LinkedList<T> {
     len: usize,
@@ -79,6 +83,7 @@ impl<T> LinkedList<T> {
 
         if Rc::ptr_eq(&head, &tail) {
             drop(tail);
+            self.len -= 1;
             return Some(
                 Rc::try_unwrap(head)
                     .ok()
@@ -109,6 +114,7 @@ impl<T> LinkedList<T> {
         if Rc::ptr_eq(&head, &tail) {
             // they are pointing to the same thing, but let's keep the symmetry
             drop(head);
+            self.len -= 1;
             return Some(
                 Rc::try_unwrap(tail)
                     .ok()
@@ -136,6 +142,66 @@ impl<T> LinkedList<T> {
     pub fn clear(&mut self) {
         while self.pop_front().is_some() {}
     }
+
+    pub fn to_vec(&self) -> alloc::vec::Vec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = alloc::vec::Vec::with_capacity(self.len);
+        self.for_each(|value| vec.push(value.clone()));
+
+        vec
+    }
+
+    /// Calls `f` on every element front to back, what `.iter()` would do if the borrow of a node
+    /// could outlive the step that took it.
+    pub fn for_each(&self, mut f: impl FnMut(&T)) {
+        let mut current = self.head_tail.as_ref().map(|(head, _)| head.clone());
+
+        while let Some(node_ref) = current {
+            let node = node_ref.borrow();
+            f(&node.value);
+            current = node.next.clone();
+        }
+    }
+
+    pub fn for_each_rev(&self, mut f: impl FnMut(&T)) {
+        let mut current = self.head_tail.as_ref().map(|(_, tail)| tail.clone());
+
+        while let Some(node_ref) = current {
+            let node = node_ref.borrow();
+            f(&node.value);
+            current = node.prev.clone();
+        }
+    }
+
+    pub fn for_each_mut(&mut self, mut f: impl FnMut(&mut T)) {
+        let mut current = self.head_tail.as_ref().map(|(head, _)| head.clone());
+
+        while let Some(node_ref) = current {
+            let mut node = node_ref.borrow_mut();
+            f(&mut node.value);
+            current = node.next.clone();
+        }
+    }
+}
+
+#[cfg(feature = "invariants")]
+impl<T> LinkedList<T> {
+    /// Walks the list and checks that the links match up and that `len` is right.
+    pub fn check_invariants(&self) -> Result<(), InvariantError> {
+        invariants::check_links(
+            self.head_tail.clone(),
+            Some(self.len),
+            |node| {
+                let node = node.borrow();
+                Some((node.prev.clone(), node.next.clone()))
+            },
+            Rc::ptr_eq,
+        )?;
+
+        Ok(())
+    }
 }
 
 impl<T> Drop for LinkedList<T> {
@@ -164,6 +230,22 @@ type NodeRef<T> = Rc<RefCell<Node<T>>>;
 
 pub struct IntoIter<T>(LinkedList<T>);
 
+impl<T> From<alloc::collections::LinkedList<T>> for LinkedList<T> {
+    fn from(std: alloc::collections::LinkedList<T>) -> Self {
+        let mut list = Self::new();
+        for value in std {
+            list.push_back(value);
+        }
+        list
+    }
+}
+
+impl<T> From<LinkedList<T>> for alloc::collections::LinkedList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
 impl<T> IntoIterator for LinkedList<T> {
     type Item = T;
 
@@ -179,6 +261,40 @@ impl<T> Iterator for IntoIter<T> {
     fn next(&mut self) -> Option<Self::Item> {
         self.0.pop_front()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+/// [`IntoIter`] as a `Stream`, every element is ready right away.
+#[cfg(feature = "futures")]
+pub struct IntoStream<T>(IntoIter<T>);
+
+#[cfg(feature = "futures")]
+impl<T> LinkedList<T> {
+    pub fn into_stream(self) -> IntoStream<T> {
+        IntoStream(self.into_iter())
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T> futures_core::Stream for IntoStream<T> {
+    type Item = T;
+
+    fn poll_next(
+        mut self: core::pin::Pin<&mut Self>,
+        _: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Option<T>> {
+        core::task::Poll::Ready(self.0.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
 }
 
 #[cfg(test)]
@@ -224,9 +340,74 @@ mod test {
         assert_eq!(list.pop_back(), None);
     }
 
+    #[test]
+    #[cfg(feature = "futures")]
+    fn into_stream() {
+        use core::pin::Pin;
+        use core::task::{Context, Poll, Waker};
+        use futures_core::Stream;
+
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        let mut stream = list.into_stream();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        assert_eq!(stream.size_hint(), (2, Some(2)));
+        assert_eq!(
+            Pin::new(&mut stream).poll_next(&mut cx),
+            Poll::Ready(Some(1))
+        );
+        assert_eq!(
+            Pin::new(&mut stream).poll_next(&mut cx),
+            Poll::Ready(Some(2))
+        );
+        assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(None));
+    }
+
+    #[test]
+    fn for_each() {
+        let mut list = LinkedList::new();
+        list.for_each(|_: &i32| unreachable!());
+
+        for i in 1..=4 {
+            list.push_back(i);
+        }
+
+        list.for_each_mut(|value| *value *= 10);
+
+        let mut sum = 0;
+        list.for_each(|value| sum += value);
+        assert_eq!(sum, 100);
+        assert_eq!(list.to_vec(), vec![10, 20, 30, 40]);
+    }
+
     #[derive(Default)]
     struct Big([usize; 32]);
 
+    #[test]
+    fn std_conversions() {
+        let std: std::collections::LinkedList<_> = (1..=3).collect();
+
+        let list = LinkedList::from(std);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+
+        let std = std::collections::LinkedList::from(list);
+        assert_eq!(std.into_iter().rev().collect::<Vec<_>>(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn to_vec() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.to_vec(), Vec::<i32>::new());
+
+        for i in 1..=3 {
+            list.push_back(i);
+        }
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+    }
+
     #[test]
     fn push_back_first_big() {
         let mut list = LinkedList::new();
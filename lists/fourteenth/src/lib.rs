@@ -0,0 +1,413 @@
+#![cfg_attr(not(test), no_std)]
+#![forbid(unsafe_code)]
+/// A doubly linked list using a `generational_arena::Arena`.
+///
+/// Like `third`, every key carries a generation and stops working once its element is removed,
+/// even after the slot has been handed out again. Unlike `slotmap`, the arena keeps one generation
+/// counter for the whole arena instead of a version per slot, keys are a `usize` index plus a
+/// `u64` generation.
+///
+/// Allocation size per value:
+/// sizeof: Entry<Node<T>> = u64 + T + Option<Index> + Option<Index>
+/// => Overhead of list is between 56 and 63 bytes per entry
+///
+/// Pros:
+/// - No unsafe & fully stable rust
+/// - Implementation is `Send` and `Sync`
+/// - No tokens required
+/// - Iteration works
+/// - Stale keys are detected, they never refer to another element
+///
+/// Cons:
+/// - Big keys, so big nodes
+/// - lot's of `unwrap()` => no compile time guarantees, like `third`
+/// - Growing the arena copies every node
+/// - Needs `alloc`
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FusedIterator;
+use generational_arena::Arena;
+
+pub use generational_arena::Index;
+
+#[cfg(feature = "invariants")]
+pub use invariants::InvariantError;
+
+pub struct LinkedList<T> {
+    arena: Arena<Node<T>>,
+    head_tail: Option<(Index, Index)>,
+}
+
+impl<T> LinkedList<T> {
+    pub fn new() -> Self {
+        Self {
+            arena: Arena::new(),
+            head_tail: None,
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            arena: Arena::with_capacity(capacity),
+            head_tail: None,
+        }
+    }
+
+    /// Every occupied slot holds an element, so this is the arena's length.
+    pub fn len(&self) -> usize {
+        self.arena.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head_tail.is_none()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            head_tail: self.head_tail,
+            len: self.len(),
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    /// Iterates over the keys of the list front to back.
+    pub fn keys(&self) -> Keys<'_, T> {
+        Keys {
+            list: self,
+            head_tail: self.head_tail,
+        }
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        let (head, _) = self.head_tail?;
+        Some(&self.arena[head].value)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        let (_, tail) = self.head_tail?;
+        Some(&self.arena[tail].value)
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        let (head, _) = self.head_tail?;
+        Some(&mut self.arena[head].value)
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        let (_, tail) = self.head_tail?;
+        Some(&mut self.arena[tail].value)
+    }
+
+    /// The element stored under `key`, `None` once it has been removed.
+    pub fn get_key(&self, key: Index) -> Option<&T> {
+        Some(&self.arena.get(key)?.value)
+    }
+
+    pub fn get_key_mut(&mut self, key: Index) -> Option<&mut T> {
+        Some(&mut self.arena.get_mut(key)?.value)
+    }
+
+    pub fn push_front(&mut self, value: T) -> Index {
+        let new_head = self.arena.insert(Node {
+            value,
+            prev: None,
+            next: None,
+        });
+
+        let head_tail = match self.head_tail {
+            Some((head, tail)) => {
+                self.arena[head].prev = Some(new_head);
+                self.arena[new_head].next = Some(head);
+
+                (new_head, tail)
+            }
+            None => (new_head, new_head),
+        };
+        self.head_tail = Some(head_tail);
+
+        new_head
+    }
+
+    pub fn push_back(&mut self, value: T) -> Index {
+        let new_tail = self.arena.insert(Node {
+            value,
+            prev: None,
+            next: None,
+        });
+
+        let head_tail = match self.head_tail {
+            Some((head, tail)) => {
+                self.arena[tail].next = Some(new_tail);
+                self.arena[new_tail].prev = Some(tail);
+
+                (head, new_tail)
+            }
+            None => (new_tail, new_tail),
+        };
+        self.head_tail = Some(head_tail);
+
+        new_tail
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let (head, _) = self.head_tail?;
+
+        self.remove_key(head)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let (_, tail) = self.head_tail?;
+
+        self.remove_key(tail)
+    }
+
+    /// Unlinks and returns the element stored under `key`, `None` if it has already been removed.
+    pub fn remove_key(&mut self, key: Index) -> Option<T> {
+        let Node { value, prev, next } = self.arena.remove(key)?;
+
+        match prev {
+            Some(prev) => self.arena[prev].next = next,
+            None => self.head_tail = self.head_tail.map(|(_, tail)| (next.unwrap_or(tail), tail)),
+        }
+        match next {
+            Some(next) => self.arena[next].prev = prev,
+            None => self.head_tail = self.head_tail.map(|(head, _)| (head, prev.unwrap_or(head))),
+        }
+        if self.arena.is_empty() {
+            self.head_tail = None;
+        }
+
+        Some(value)
+    }
+
+    /// Drops every element, the arena keeps its capacity. Keys handed out before are invalidated.
+    pub fn clear(&mut self) {
+        self.arena.clear();
+        self.head_tail = None;
+    }
+}
+
+#[cfg(feature = "invariants")]
+impl<T> LinkedList<T> {
+    /// Walks the list and checks that every index is still live, that the links match up and that
+    /// every entry in the arena is linked.
+    pub fn check_invariants(&self) -> Result<(), InvariantError> {
+        invariants::check_links(
+            self.head_tail,
+            Some(self.arena.len()),
+            |&index| self.arena.get(index).map(|node| (node.prev, node.next)),
+            |a, b| a == b,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Node<T> {
+    value: T,
+    prev: Option<Index>,
+    next: Option<Index>,
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+pub struct IntoIter<T>(LinkedList<T>);
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+pub struct Iter<'a, T> {
+    list: &'a LinkedList<T>,
+    head_tail: Option<(Index, Index)>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        let node = &self.list.arena[head];
+
+        if head != tail {
+            self.head_tail = node.next.map(|n| (n, tail));
+        }
+        self.len -= 1;
+
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        let node = &self.list.arena[tail];
+
+        if head != tail {
+            self.head_tail = node.prev.map(|n| (head, n));
+        }
+        self.len -= 1;
+
+        Some(&node.value)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+pub struct Keys<'a, T> {
+    list: &'a LinkedList<T>,
+    head_tail: Option<(Index, Index)>,
+}
+
+impl<'a, T> Iterator for Keys<'a, T> {
+    type Item = Index;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        if head != tail {
+            self.head_tail = self.list.arena[head].next.map(|n| (n, tail));
+        }
+
+        Some(head)
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Keys<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        if head != tail {
+            self.head_tail = self.list.arena[tail].prev.map(|n| (head, n));
+        }
+
+        Some(tail)
+    }
+}
+
+impl<'a, T> FusedIterator for Keys<'a, T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let mut list: LinkedList<_> = (1..=4).collect();
+        *list.front_mut().unwrap() = 0;
+        *list.back_mut().unwrap() = 5;
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 2, 3, 5]);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![5, 3, 2, 0]
+        );
+        assert_eq!(list.iter().len(), 4);
+        assert_eq!(list.keys().count(), 4);
+        assert_eq!(list.into_iter().rev().collect::<Vec<_>>(), vec![5, 3, 2, 0]);
+    }
+
+    #[test]
+    fn stale_keys() {
+        let mut list = LinkedList::new();
+        let a = list.push_back('a');
+        let b = list.push_back('b');
+        let c = list.push_back('c');
+
+        *list.get_key_mut(b).unwrap() = 'B';
+        assert_eq!(list.remove_key(b), Some('B'));
+        assert_eq!(list.remove_key(b), None);
+        assert_eq!(list.remove_key(c), Some('c'));
+        assert_eq!(list.back(), Some(&'a'));
+
+        // the slot is reused, but the old key's generation no longer matches
+        let d = list.push_front('d');
+        assert_eq!(d.into_raw_parts().0, c.into_raw_parts().0);
+        assert_eq!(list.get_key(c), None);
+        assert_eq!(list.remove_key(c), None);
+        assert_eq!(list.to_vec(), vec!['d', 'a']);
+
+        list.clear();
+        assert_eq!(list.get_key(a), None);
+        assert_eq!(list.get_key(d), None);
+    }
+}
@@ -1,12 +1,31 @@
 #![cfg_attr(not(test), no_std)]
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::mem;
 use ghost_cell::{GhostCell, GhostToken};
 use typed_arena::Arena;
 
+mod queue;
+
+pub use queue::WorkQueue;
+
+#[cfg(feature = "invariants")]
+pub use invariants::InvariantError;
+
 pub struct LinkedList<'arena, 'id, T> {
     arena: &'arena Arena<Node<'arena, 'id, T>>,
+    len: usize,
     head_tail: Option<(NodeRef<'arena, 'id, T>, NodeRef<'arena, 'id, T>)>,
+    /// Popped nodes, chained through `next`, handed out again before allocating new ones.
+    free: Option<NodeRef<'arena, 'id, T>>,
+    free_len: usize,
 }
 
 impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
@@ -14,11 +33,53 @@ impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
         Self {
             head_tail: None,
             arena,
+            len: 0,
+            free: None,
+            free_len: 0,
         }
     }
 
-    pub fn len(&self, token: &GhostToken<'id>) -> usize {
-        self.iter(token).count()
+    pub fn new_in(cx: &ListArena<'_, 'arena, 'id, T>) -> Self {
+        Self::new(cx.arena)
+    }
+
+    /// Creates the token, an arena and a list in one go and hands the list to `f`, together with
+    /// a [`Scope`] owning the token and the arena.
+    pub fn scoped<R, F>(f: F) -> R
+    where
+        F: for<'new_arena, 'new_id> FnOnce(
+            &mut LinkedList<'new_arena, 'new_id, T>,
+            &mut Scope<'new_arena, 'new_id, T>,
+        ) -> R,
+    {
+        GhostToken::new(|token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            let mut scope = Scope {
+                arena: &arena,
+                token,
+            };
+
+            f(&mut list, &mut scope)
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `nodes` counts the whole arena, which may be shared with other lists, `free_slots` only this
+    /// list's popped nodes.
+    pub fn memory_usage(&self) -> MemoryStats {
+        let bytes_per_node = mem::size_of::<Node<'arena, 'id, T>>();
+        let capacity = self.arena.len() + self.arena.uninitialized_array().len();
+
+        MemoryStats {
+            nodes: self.arena.len(),
+            bytes_per_node,
+            total_bytes: capacity * bytes_per_node,
+            free_slots: self.free_len,
+        }
     }
 
     pub fn is_empty(&self) -> bool {
@@ -29,11 +90,284 @@ impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
         Iter {
             token,
             head_tail: self.head_tail,
+            len: self.len,
+        }
+    }
+
+    /// Every element together with the one after it.
+    pub fn iter_pairs<'a>(&'a self, token: &'a GhostToken<'id>) -> Pairs<'a, 'arena, 'id, T> {
+        let mut iter = self.iter(token);
+
+        Pairs {
+            prev: iter.next(),
+            iter,
+        }
+    }
+
+    /// All runs of `n` adjacent elements front to back, like `slice::windows`. The current window
+    /// is kept in a ring of references and copied out for every step.
+    ///
+    /// Panics if `n` is 0.
+    #[cfg(feature = "alloc")]
+    pub fn windows<'a>(
+        &'a self,
+        n: usize,
+        token: &'a GhostToken<'id>,
+    ) -> Windows<'a, 'arena, 'id, T> {
+        assert!(n > 0, "window size must be non-zero");
+
+        let mut iter = self.iter(token);
+        let ring = iter.by_ref().take(n - 1).collect();
+
+        Windows { iter, ring, n }
+    }
+
+    /// Pairs the list with `token`, so it can be iterated in a `for` loop.
+    pub fn view<'a>(&'a self, token: &'a GhostToken<'id>) -> ListView<'a, 'arena, 'id, T> {
+        ListView { list: self, token }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self, token: &GhostToken<'id>) -> alloc::vec::Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter(token).cloned().collect()
+    }
+
+    pub fn eq_with(&self, other: &Self, token: &GhostToken<'id>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter(token).eq(other.iter(token))
+    }
+
+    /// Compares the elements lexicographically, like slices.
+    pub fn cmp_with(&self, other: &Self, token: &GhostToken<'id>) -> Ordering
+    where
+        T: Ord,
+    {
+        self.iter(token).cmp(other.iter(token))
+    }
+
+    /// Feeds the length and then the elements in order to `state`, like `Hash` would.
+    pub fn hash_with<H: Hasher>(&self, token: &GhostToken<'id>, state: &mut H)
+    where
+        T: Hash,
+    {
+        self.len.hash(state);
+
+        for value in self.iter(token) {
+            value.hash(state);
+        }
+    }
+
+    /// Deep-copies the list into `arena`, which may be the one `self` lives in.
+    pub fn clone_in(
+        &self,
+        arena: &'arena Arena<Node<'arena, 'id, T>>,
+        token: &mut GhostToken<'id>,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let mut clone = Self::new(arena);
+        let mut current = self.head_tail.map(|(head, _)| head);
+
+        while let Some(node_ref) = current {
+            let node = node_ref.borrow(token);
+            let value = node.value.clone().unwrap();
+            current = node.next;
+
+            clone.push_back(value, token);
+        }
+
+        clone
+    }
+
+    pub fn front_handle(&self, token: &GhostToken<'id>) -> Option<NodeHandle<'arena, 'id, T>> {
+        self.head_tail.map(|(head, _)| NodeHandle::new(head, token))
+    }
+
+    pub fn back_handle(&self, token: &GhostToken<'id>) -> Option<NodeHandle<'arena, 'id, T>> {
+        self.head_tail.map(|(_, tail)| NodeHandle::new(tail, token))
+    }
+
+    /// Moves the node behind `handle` from `other` to the back of `self` without touching the
+    /// arena.
+    ///
+    /// Checking that the node belongs to `other` walks from it to the closer end of `other`, so
+    /// this is `O(1)` for nodes near either end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element has been removed, even if its node was reused since, or if the node
+    /// isn't in `other`.
+    pub fn take_node_from(
+        &mut self,
+        other: &mut Self,
+        handle: NodeHandle<'arena, 'id, T>,
+        token: &mut GhostToken<'id>,
+    ) {
+        other.detach(handle, token);
+
+        let tail = self.head_tail.map(|(_, tail)| tail);
+        self.link_after(tail, handle.node, handle.node, token);
+        self.len += 1;
+    }
+
+    pub fn cursor_front<'a>(&'a self, token: &'a GhostToken<'id>) -> Cursor<'a, 'arena, 'id, T> {
+        Cursor {
+            current: self.head_tail.map(|(head, _)| head),
+            index: 0,
+            list: self,
+            token,
+        }
+    }
+
+    pub fn cursor_back<'a>(&'a self, token: &'a GhostToken<'id>) -> Cursor<'a, 'arena, 'id, T> {
+        Cursor {
+            current: self.head_tail.map(|(_, tail)| tail),
+            index: self.len.saturating_sub(1),
+            list: self,
+            token,
+        }
+    }
+
+    /// Returns a cursor on the element at `index`, or on the ghost position if `index` is out of bounds.
+    ///
+    /// Walks from whichever end is closer.
+    pub fn cursor_at<'a>(
+        &'a self,
+        index: usize,
+        token: &'a GhostToken<'id>,
+    ) -> Cursor<'a, 'arena, 'id, T> {
+        Cursor {
+            current: self.node_at(index, token),
+            index: index.min(self.len),
+            list: self,
+            token,
+        }
+    }
+
+    pub fn cursor_front_mut<'a>(
+        &'a mut self,
+        token: &'a mut GhostToken<'id>,
+    ) -> CursorMut<'a, 'arena, 'id, T> {
+        CursorMut {
+            current: self.head_tail.map(|(head, _)| head),
+            index: 0,
+            list: self,
+            token,
+        }
+    }
+
+    pub fn cursor_back_mut<'a>(
+        &'a mut self,
+        token: &'a mut GhostToken<'id>,
+    ) -> CursorMut<'a, 'arena, 'id, T> {
+        CursorMut {
+            current: self.head_tail.map(|(_, tail)| tail),
+            index: self.len.saturating_sub(1),
+            list: self,
+            token,
+        }
+    }
+
+    /// Like [`LinkedList::cursor_at`], but the cursor can edit the list.
+    pub fn cursor_at_mut<'a>(
+        &'a mut self,
+        index: usize,
+        token: &'a mut GhostToken<'id>,
+    ) -> CursorMut<'a, 'arena, 'id, T> {
+        CursorMut {
+            current: self.node_at(index, token),
+            index: index.min(self.len),
+            list: self,
+            token,
         }
     }
 
+    pub fn contains(&self, x: &T, token: &GhostToken<'id>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter(token).any(|value| value == x)
+    }
+
+    pub fn position<P>(&self, pred: P, token: &GhostToken<'id>) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter(token).position(pred)
+    }
+
+    pub fn find<'a, P>(&'a self, mut pred: P, token: &'a GhostToken<'id>) -> Option<&'a T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter(token).find(|value| pred(value))
+    }
+
+    pub fn is_sorted(&self, token: &GhostToken<'id>) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b, token)
+    }
+
+    /// Whether `compare` holds for every pair of neighbours, like [`slice::is_sorted_by`].
+    pub fn is_sorted_by<F>(&self, mut compare: F, token: &GhostToken<'id>) -> bool
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut iter = self.iter(token);
+        let Some(mut prev) = iter.next() else {
+            return true;
+        };
+        iter.all(|value| compare(mem::replace(&mut prev, value), value))
+    }
+
+    pub fn insert_sorted(&mut self, value: T, token: &mut GhostToken<'id>)
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(value, T::cmp, token)
+    }
+
+    /// Inserts `value` before the first element that compares greater, so a list that
+    /// [`is_sorted_by`](Self::is_sorted_by) stays sorted and equal elements keep insertion order.
+    pub fn insert_sorted_by<F>(&mut self, value: T, mut cmp: F, token: &mut GhostToken<'id>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut cursor = self.cursor_front_mut(token);
+        cursor.seek_mut(|elem| cmp(elem, &value) == Ordering::Greater);
+        cursor.insert_before(value);
+    }
+
+    pub fn front<'a>(&'a self, token: &'a GhostToken<'id>) -> Option<&'a T> {
+        self.head_tail
+            .map(|(head, _)| head.borrow(token).value.as_ref().unwrap())
+    }
+
+    pub fn back<'a>(&'a self, token: &'a GhostToken<'id>) -> Option<&'a T> {
+        self.head_tail
+            .map(|(_, tail)| tail.borrow(token).value.as_ref().unwrap())
+    }
+
+    pub fn front_mut<'a>(&'a mut self, token: &'a mut GhostToken<'id>) -> Option<&'a mut T> {
+        self.head_tail
+            .map(|(head, _)| head.borrow_mut(token).value.as_mut().unwrap())
+    }
+
+    pub fn back_mut<'a>(&'a mut self, token: &'a mut GhostToken<'id>) -> Option<&'a mut T> {
+        self.head_tail
+            .map(|(_, tail)| tail.borrow_mut(token).value.as_mut().unwrap())
+    }
+
     pub fn push_front(&mut self, value: T, token: &mut GhostToken<'id>) {
-        let new_head = self.insert(value);
+        let new_head = self.insert(value, token);
 
         let head_tail = if let Some((head, tail)) = self.head_tail.take() {
             head.borrow_mut(token).prev = Some(new_head);
@@ -44,11 +378,12 @@ impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
             (new_head, new_head)
         };
 
-        self.head_tail = Some(head_tail)
+        self.head_tail = Some(head_tail);
+        self.len += 1;
     }
 
     pub fn push_back(&mut self, value: T, token: &mut GhostToken<'id>) {
-        let new_tail = self.insert(value);
+        let new_tail = self.insert(value, token);
 
         let head_tail = if let Some((head, tail)) = self.head_tail.take() {
             tail.borrow_mut(token).next = Some(new_tail);
@@ -59,15 +394,34 @@ impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
             (new_tail, new_tail)
         };
 
-        self.head_tail = Some(head_tail)
+        self.head_tail = Some(head_tail);
+        self.len += 1;
+    }
+
+    /// Pushes every element of `iter` to the back of the list.
+    ///
+    /// Room for the elements the free list can't take is reserved in the arena up front, going by
+    /// the lower bound of the size hint, so the arena grows at most once for them.
+    pub fn extend_from_iter<I>(&mut self, iter: I, token: &mut GhostToken<'id>)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        self.arena
+            .reserve_extend(iter.size_hint().0.saturating_sub(self.free_len));
+
+        for value in iter {
+            self.push_back(value, token);
+        }
     }
 
     pub fn pop_front(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
         let (head, tail) = self.head_tail.take()?;
+        self.len -= 1;
 
         // when there is only one element in the list
         if head.as_ptr() == tail.as_ptr() {
-            return Some(Self::into_inner(head, token));
+            return Some(self.recycle(head, token));
         }
 
         let next = head.borrow_mut(token).next.take().unwrap();
@@ -75,15 +429,16 @@ impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
 
         self.head_tail = Some((next, tail));
 
-        Some(Self::into_inner(head, token))
+        Some(self.recycle(head, token))
     }
 
     pub fn pop_back(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
         let (head, tail) = self.head_tail.take()?;
+        self.len -= 1;
 
         // when there is only one element in the list
         if head.as_ptr() == tail.as_ptr() {
-            return Some(Self::into_inner(head, token));
+            return Some(self.recycle(head, token));
         }
 
         let prev = tail
@@ -99,21 +454,375 @@ impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
 
         self.head_tail = Some((head, prev));
 
-        Some(Self::into_inner(tail, token))
+        Some(self.recycle(tail, token))
+    }
+
+    /// Pops the first element, but only if `pred` returns `true` for it.
+    pub fn pop_front_if<F>(&mut self, pred: F, token: &mut GhostToken<'id>) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        if pred(self.front_mut(token)?) {
+            self.pop_front(token)
+        } else {
+            None
+        }
+    }
+
+    /// Pops the last element, but only if `pred` returns `true` for it.
+    pub fn pop_back_if<F>(&mut self, pred: F, token: &mut GhostToken<'id>) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        if pred(self.back_mut(token)?) {
+            self.pop_back(token)
+        } else {
+            None
+        }
     }
 
     pub fn clear(&mut self, token: &mut GhostToken<'id>) {
         while self.pop_back(token).is_some() {}
     }
 
-    fn insert(&self, value: T) -> NodeRef<'arena, 'id, T> {
+    /// Drops everything after the first `len` elements, does nothing if the list is shorter.
+    ///
+    /// The cut is found from the closer end and the rest is unlinked in one go.
+    pub fn truncate(&mut self, len: usize, token: &mut GhostToken<'id>) {
+        if len >= self.len {
+            return;
+        }
+        let Some((head, _)) = self.head_tail else {
+            return;
+        };
+
+        let mut next = match len.checked_sub(1).and_then(|i| self.node_at(i, token)) {
+            Some(new_tail) => {
+                self.head_tail = Some((head, new_tail));
+                new_tail.borrow_mut(token).next.take()
+            }
+            None => {
+                self.head_tail = None;
+                Some(head)
+            }
+        };
+        self.len = len;
+
+        // the values are dropped right away, the nodes go on the free list
+        while let Some(node_ref) = next {
+            let node = node_ref.borrow_mut(token);
+            next = node.next.take();
+            node.next = self.free.replace(node_ref);
+            node.prev = None;
+            node.value = None;
+            node.generation = node.generation.wrapping_add(1);
+            self.free_len += 1;
+        }
+    }
+
+    /// Moves all elements of `other` in between the elements of `self`, alternating one from each
+    /// starting with `self`, and leaves `other` empty. Whatever is left of the longer list ends up
+    /// at the back.
+    ///
+    /// Nodes are relinked in `O(min(n, m))`, no value is moved.
+    pub fn interleave(&mut self, other: &mut Self, token: &mut GhostToken<'id>) {
+        let mut current = self.head_tail.map(|(head, _)| head);
+
+        while let (Some(node), Some((head, tail))) = (current, other.head_tail) {
+            let rest = head.borrow_mut(token).next.take();
+            if let Some(rest) = rest {
+                rest.borrow_mut(token).prev = None;
+            }
+            other.head_tail = rest.map(|rest| (rest, tail));
+            other.len -= 1;
+
+            current = node.borrow(token).next;
+            self.link_after(Some(node), head, head, token);
+            self.len += 1;
+        }
+
+        if let Some((head, tail)) = other.head_tail.take() {
+            let last = self.head_tail.map(|(_, tail)| tail);
+            self.link_after(last, head, tail, token);
+            self.len += mem::take(&mut other.len);
+        }
+    }
+
+    /// Detaches the first `n` elements (or all of them) as a list on the same arena.
+    ///
+    /// The cut is found from the closer end like [`Self::truncate`], no value is moved.
+    pub fn split_first_n(&mut self, n: usize, token: &mut GhostToken<'id>) -> Self {
+        let mut first = Self::new(self.arena);
+
+        if n >= self.len {
+            first.head_tail = self.head_tail.take();
+            first.len = mem::take(&mut self.len);
+            return first;
+        }
+        let (Some((head, tail)), Some(last)) = (self.head_tail, n.checked_sub(1)) else {
+            return first;
+        };
+        let last = self.node_at(last, token).unwrap();
+
+        let rest = last.borrow_mut(token).next.take().unwrap();
+        rest.borrow_mut(token).prev = None;
+        self.head_tail = Some((rest, tail));
+        self.len -= n;
+
+        first.head_tail = Some((head, last));
+        first.len = n;
+        first
+    }
+
+    /// Removes the first `n` elements (or all of them) and yields them front to back.
+    ///
+    /// Dropping the iterator early removes the rest of them anyway.
+    pub fn drain_n<'a>(
+        &'a mut self,
+        n: usize,
+        token: &'a mut GhostToken<'id>,
+    ) -> DrainN<'a, 'arena, 'id, T> {
+        DrainN {
+            remaining: n.min(self.len),
+            list: self,
+            token,
+        }
+    }
+
+    /// Chains all `lists` into one in `O(k)` relinks for `k` lists, the result lives on `arena`.
+    ///
+    /// The lists should come from `arena` too, nodes of other arenas work but stay allocated there.
+    /// Only the longest free list of popped nodes is kept, the others are left unused in the arena.
+    pub fn concat<I>(
+        arena: &'arena Arena<Node<'arena, 'id, T>>,
+        lists: I,
+        token: &mut GhostToken<'id>,
+    ) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let mut concat = Self::new(arena);
+
+        for mut list in lists {
+            if let Some((head, tail)) = list.head_tail.take() {
+                let last = concat.head_tail.map(|(_, tail)| tail);
+                concat.link_after(last, head, tail, token);
+                concat.len += list.len;
+            }
+
+            if list.free_len > concat.free_len {
+                concat.free = list.free;
+                concat.free_len = list.free_len;
+            }
+        }
+
+        concat
+    }
+
+    /// Splits the list into maximal runs of adjacent elements for which `eq` returns `true`, leaving
+    /// `self` empty.
+    ///
+    /// The runs are cut apart by relinking, no value is moved or cloned. Every run is its own list on
+    /// the same arena, popped nodes stay with `self`.
+    #[cfg(feature = "alloc")]
+    pub fn group_by<F>(&mut self, mut eq: F, token: &mut GhostToken<'id>) -> alloc::vec::Vec<Self>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut groups = alloc::vec::Vec::new();
+
+        while let Some((head, tail)) = self.head_tail {
+            let mut last = head;
+            let mut len = 1;
+
+            while let Some(next) = last.borrow(token).next {
+                let (a, b) = (last.borrow(token), next.borrow(token));
+                if !eq(a.value.as_ref().unwrap(), b.value.as_ref().unwrap()) {
+                    break;
+                }
+
+                last = next;
+                len += 1;
+            }
+
+            let rest = last.borrow_mut(token).next.take();
+            if let Some(rest) = rest {
+                rest.borrow_mut(token).prev = None;
+            }
+            self.head_tail = rest.map(|rest| (rest, tail));
+            self.len -= len;
+
+            let mut group = Self::new(self.arena);
+            group.head_tail = Some((head, last));
+            group.len = len;
+            groups.push(group);
+        }
+
+        groups
+    }
+
+    /// Returns an iterator that removes and yields every element for which `pred` returns `true`.
+    ///
+    /// Elements are only visited as the iterator is advanced, dropping it early leaves the rest in place.
+    pub fn extract_if<'a, F>(
+        &'a mut self,
+        pred: F,
+        token: &'a mut GhostToken<'id>,
+    ) -> ExtractIf<'a, 'arena, 'id, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let next = self.head_tail.map(|(head, _)| head);
+
+        ExtractIf {
+            list: self,
+            token,
+            next,
+            pred,
+        }
+    }
+
+    fn insert(&mut self, value: T, token: &mut GhostToken<'id>) -> NodeRef<'arena, 'id, T> {
+        if let Some(node_ref) = self.free {
+            let node = node_ref.borrow_mut(token);
+            self.free = node.next.take();
+            self.free_len -= 1;
+            node.value = Some(value);
+
+            return node_ref;
+        }
+
         GhostCell::from_mut(self.arena.alloc(Node {
             value: Some(value),
+            generation: 0,
             prev: None,
             next: None,
         }))
     }
 
+    /// Takes the value out of an unlinked node and puts the node on the free list.
+    fn recycle(&mut self, node_ref: NodeRef<'arena, 'id, T>, token: &mut GhostToken<'id>) -> T {
+        let value = Self::into_inner(node_ref, token);
+        node_ref.borrow_mut(token).next = self.free.replace(node_ref);
+        self.free_len += 1;
+
+        value
+    }
+
+    /// Links the chain `first..=last` in after `prev`, or at the front if `prev` is `None`.
+    fn link_after(
+        &mut self,
+        prev: Option<NodeRef<'arena, 'id, T>>,
+        first: NodeRef<'arena, 'id, T>,
+        last: NodeRef<'arena, 'id, T>,
+        token: &mut GhostToken<'id>,
+    ) {
+        let Some((head, tail)) = self.head_tail else {
+            self.head_tail = Some((first, last));
+            return;
+        };
+
+        let next = match prev {
+            Some(prev) => prev.borrow(token).next,
+            None => Some(head),
+        };
+
+        first.borrow_mut(token).prev = prev;
+        last.borrow_mut(token).next = next;
+
+        let head = match prev {
+            Some(prev) => {
+                prev.borrow_mut(token).next = Some(first);
+                head
+            }
+            None => first,
+        };
+        let tail = match next {
+            Some(next) => {
+                next.borrow_mut(token).prev = Some(last);
+                tail
+            }
+            None => last,
+        };
+
+        self.head_tail = Some((head, tail));
+    }
+
+    fn unlink(&mut self, node_ref: NodeRef<'arena, 'id, T>, token: &mut GhostToken<'id>) {
+        let node = node_ref.borrow_mut(token);
+        let prev = node.prev.take();
+        let next = node.next.take();
+
+        let (head, tail) = self.head_tail.take().unwrap();
+
+        let head = match prev {
+            Some(prev) => {
+                prev.borrow_mut(token).next = next;
+                Some(head)
+            }
+            None => next,
+        };
+        let tail = match next {
+            Some(next) => {
+                next.borrow_mut(token).prev = prev;
+                Some(tail)
+            }
+            None => prev,
+        };
+
+        self.head_tail = head.zip(tail);
+        self.len -= 1;
+    }
+
+    /// Unlinks the node behind a handle handed to us from outside, after checking it belongs here.
+    fn detach(&mut self, handle: NodeHandle<'arena, 'id, T>, token: &mut GhostToken<'id>) {
+        let node = handle.node.borrow(token);
+        assert!(
+            node.value.is_some() && node.generation == handle.generation,
+            "handle points to a removed node"
+        );
+        assert!(
+            self.owns(handle.node, token),
+            "handle does not belong to this list"
+        );
+
+        self.unlink(handle.node, token);
+    }
+
+    /// Whether a linked node is in this list, found by walking from it towards both ends at once
+    /// until one of them is reached.
+    fn owns(&self, node_ref: NodeRef<'arena, 'id, T>, token: &GhostToken<'id>) -> bool {
+        let Some((head, tail)) = self.head_tail else {
+            return false;
+        };
+        let (mut backward, mut forward) = (node_ref, node_ref);
+
+        loop {
+            let Some(prev) = backward.borrow(token).prev else {
+                return backward.as_ptr() == head.as_ptr();
+            };
+            let Some(next) = forward.borrow(token).next else {
+                return forward.as_ptr() == tail.as_ptr();
+            };
+            (backward, forward) = (prev, next);
+        }
+    }
+
+    /// Walks from whichever end is closer, `None` if `index` is out of bounds.
+    fn node_at(&self, index: usize, token: &GhostToken<'id>) -> Option<NodeRef<'arena, 'id, T>> {
+        if index >= self.len {
+            return None;
+        }
+
+        let (head, tail) = self.head_tail?;
+
+        if index < self.len / 2 {
+            (0..index).try_fold(head, |node, _| node.borrow(token).next)
+        } else {
+            (index..self.len - 1).try_fold(tail, |node, _| node.borrow(token).prev)
+        }
+    }
+
     fn into_inner(
         node_ref: NodeRef<'arena, 'id, T>,
         token: &mut GhostToken<'id>,
@@ -124,75 +833,1138 @@ impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
         debug_assert!(node.prev.is_none());
         debug_assert!(node.next.is_none());
 
+        // outdates the handles to this element, the node may be reused for another one
+        node.generation = node.generation.wrapping_add(1);
         node.value.take().unwrap()
     }
 }
 
-pub struct Node<'arena, 'id, T> {
-    value: Option<T>,
-    prev: Option<NodeRef<'arena, 'id, T>>,
-    next: Option<NodeRef<'arena, 'id, T>>,
-}
+#[cfg(feature = "invariants")]
+impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
+    /// Walks the list and its free list, checking that the links match up, that `len` and
+    /// `free_len` are right and that exactly the free nodes have no value.
+    pub fn check_invariants(&self, token: &GhostToken<'id>) -> Result<(), InvariantError> {
+        invariants::check_links(
+            self.head_tail,
+            Some(self.len),
+            |node| {
+                let node = node.borrow(token);
+                // a popped node still in the list counts as an empty slot
+                node.value.as_ref()?;
+                Some((node.prev, node.next))
+            },
+            |a, b| core::ptr::eq(*a, *b),
+        )?;
 
-type NodeRef<'arena, 'id, T> = &'arena GhostCell<'id, Node<'arena, 'id, T>>;
+        let mut free = self.free;
+        for _ in 0..self.free_len {
+            let Some(node) = free else {
+                return Err(InvariantError::Other(
+                    "free list is shorter than `free_len`",
+                ));
+            };
+            let node = node.borrow(token);
+            if node.value.is_some() {
+                return Err(InvariantError::Other("a node on the free list has a value"));
+            }
+            free = node.next;
+        }
 
-pub struct Iter<'a, 'arena, 'id, T> {
-    token: &'a GhostToken<'id>,
-    head_tail: Option<(NodeRef<'arena, 'id, T>, NodeRef<'arena, 'id, T>)>,
+        match free {
+            Some(_) => Err(InvariantError::Other("free list is longer than `free_len`")),
+            None => Ok(()),
+        }
+    }
 }
 
-impl<'a, 'arena, 'id, T> Iterator for Iter<'a, 'arena, 'id, T>
-where
-    'arena: 'a,
-{
-    type Item = &'a T;
+#[cfg(feature = "debug-viz")]
+impl<'arena, 'id, T: core::fmt::Debug> LinkedList<'arena, 'id, T> {
+    /// Writes the nodes and their `next` (solid) and `prev` (dashed) links as a GraphViz digraph,
+    /// e.g. for `dot -Tsvg`.
+    ///
+    /// Nodes are named by address and every link is drawn as stored, so a broken relink shows up
+    /// as a stray edge. At most `len + 1` nodes are followed.
+    pub fn dot<W: core::fmt::Write>(
+        &self,
+        out: &mut W,
+        token: &GhostToken<'id>,
+    ) -> core::fmt::Result {
+        use core::fmt::Write;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let (head, tail) = self.head_tail.take()?;
+        writeln!(out, "digraph {{")?;
+        writeln!(out, "    rankdir=LR;")?;
+        writeln!(out, "    node [shape=box];")?;
 
-        let node = head.borrow(self.token);
+        if let Some((head, tail)) = self.head_tail {
+            writeln!(out, "    head [shape=plaintext];")?;
+            writeln!(out, "    tail [shape=plaintext];")?;
+            writeln!(out, "    head -> \"{:p}\";", head)?;
+            writeln!(out, "    tail -> \"{:p}\";", tail)?;
+        }
 
-        if head.as_ptr() != tail.as_ptr() {
-            self.head_tail = node.next.map(|n| (n, tail));
-        } else {
-            self.head_tail = None;
+        // one node past `len` is enough to see a cycle or a length drift
+        for node_ref in self.iter_nodes(token).take(self.len + 1) {
+            let node = node_ref.borrow(token);
+
+            write!(out, "    \"{:p}\" [label=\"", node_ref)?;
+            match &node.value {
+                Some(value) => write!(DotLabel(out), "{:?}", value)?,
+                // a node that was popped but is still linked from somewhere
+                None => out.write_str("(removed)")?,
+            }
+            writeln!(out, "\"];")?;
+
+            if let Some(next) = node.next {
+                writeln!(out, "    \"{:p}\" -> \"{:p}\";", node_ref, next)?;
+            }
+            if let Some(prev) = node.prev {
+                writeln!(
+                    out,
+                    "    \"{:p}\" -> \"{:p}\" [style=dashed];",
+                    node_ref, prev
+                )?;
+            }
         }
 
-        Some(node.value.as_ref().unwrap())
+        writeln!(out, "}}")
     }
-}
 
-impl<'a, 'arena, 'id, T> DoubleEndedIterator for Iter<'a, 'arena, 'id, T>
-where
-    'arena: 'a,
-{
-    fn next_back(&mut self) -> Option<Self::Item> {
-        let (head, tail) = self.head_tail.take()?;
+    fn iter_nodes<'a>(
+        &self,
+        token: &'a GhostToken<'id>,
+    ) -> impl Iterator<Item = NodeRef<'arena, 'id, T>> + 'a
+    where
+        'arena: 'a,
+    {
+        let head = self.head_tail.map(|(head, _)| head);
 
-        let node = tail.borrow(self.token);
+        core::iter::successors(head, |node| node.borrow(token).next)
+    }
 
-        if head.as_ptr() != tail.as_ptr() {
-            self.head_tail = node.prev.map(|n| (head, n));
-        } else {
-            self.head_tail = None;
+    /// Renders the list on one line, like `head -> [1] <-> [2] <-> [3] <- tail`.
+    ///
+    /// A `->` between two elements means the second one's `prev` doesn't point back at the first.
+    /// At most `len + 1` nodes are shown.
+    pub fn dump<'a>(&'a self, token: &'a GhostToken<'id>) -> Dump<'a, 'arena, 'id, T> {
+        Dump { list: self, token }
+    }
+
+    /// Writes the same graph as [`dot`](Self::dot) as a Mermaid `flowchart LR`, for docs and issues
+    /// that render Mermaid.
+    pub fn mermaid<W: core::fmt::Write>(
+        &self,
+        out: &mut W,
+        token: &GhostToken<'id>,
+    ) -> core::fmt::Result {
+        use core::fmt::Write;
+
+        writeln!(out, "flowchart LR")?;
+
+        if let Some((head, tail)) = self.head_tail {
+            writeln!(out, "    head([head]) --> n{:p}", head)?;
+            writeln!(out, "    tail([tail]) --> n{:p}", tail)?;
         }
 
-        Some(node.value.as_ref().unwrap())
-    }
-}
+        let mut current = self.head_tail.map(|(head, _)| head);
+        // one node past `len` is enough to see a cycle or a length drift
+        for _ in 0..=self.len {
+            let Some(node_ref) = current else {
+                break;
+            };
+            let node = node_ref.borrow(token);
 
-#[cfg(test)]
-mod test {
-    use super::*;
+            write!(out, "    n{:p}[\"", node_ref)?;
+            match &node.value {
+                Some(value) => write!(MermaidLabel(out), "{:?}", value)?,
+                None => out.write_str("(removed)")?,
+            }
+            writeln!(out, "\"]")?;
 
-    #[test]
-    fn push_pop() {
-        GhostToken::new(|ref mut token| {
-            let arena = Arena::new();
-            let mut list = LinkedList::new(&arena);
+            if let Some(next) = node.next {
+                writeln!(out, "    n{:p} --> n{:p}", node_ref, next)?;
+            }
+            if let Some(prev) = node.prev {
+                writeln!(out, "    n{:p} -.-> n{:p}", node_ref, prev)?;
+            }
 
-            list.push_front(1, token);
-            list.push_front(2, token);
+            current = node.next;
+        }
+
+        Ok(())
+    }
+}
+
+/// Escapes a GraphViz label as it is written.
+#[cfg(feature = "debug-viz")]
+struct DotLabel<'a, W>(&'a mut W);
+
+#[cfg(feature = "debug-viz")]
+impl<W: core::fmt::Write> core::fmt::Write for DotLabel<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for c in s.chars() {
+            if matches!(c, '"' | '\\') {
+                self.0.write_char('\\')?;
+            }
+            self.0.write_char(c)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Escapes a Mermaid label as it is written.
+#[cfg(feature = "debug-viz")]
+struct MermaidLabel<'a, W>(&'a mut W);
+
+#[cfg(feature = "debug-viz")]
+impl<W: core::fmt::Write> core::fmt::Write for MermaidLabel<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for part in s.split_inclusive('"') {
+            match part.strip_suffix('"') {
+                Some(part) => {
+                    self.0.write_str(part)?;
+                    self.0.write_str("#quot;")?;
+                }
+                None => self.0.write_str(part)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The one line rendering of a list, see [`LinkedList::dump`].
+#[cfg(feature = "debug-viz")]
+pub struct Dump<'a, 'arena, 'id, T> {
+    list: &'a LinkedList<'arena, 'id, T>,
+    token: &'a GhostToken<'id>,
+}
+
+#[cfg(feature = "debug-viz")]
+impl<'a, 'arena, 'id, T: core::fmt::Debug> core::fmt::Display for Dump<'a, 'arena, 'id, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let token = self.token;
+        let list = self.list;
+
+        f.write_str("head -> ")?;
+
+        let mut last = None;
+        let mut current = list.head_tail.map(|(head, _)| head);
+        // one node past `len` is enough to see a cycle or a length drift
+        for _ in 0..=list.len {
+            let Some(node_ref) = current else {
+                break;
+            };
+            let node = node_ref.borrow(token);
+
+            if let Some(last) = last {
+                let linked_back = node.prev.is_some_and(|prev| core::ptr::eq(prev, last));
+                f.write_str(if linked_back { " <-> " } else { " -> " })?;
+            }
+            match &node.value {
+                Some(value) => write!(f, "[{:?}]", value)?,
+                None => f.write_str("[(removed)]")?,
+            }
+
+            last = Some(node_ref);
+            current = node.next;
+        }
+
+        if last.is_none() {
+            f.write_str("(empty)")?;
+        }
+
+        f.write_str(" <- tail")
+    }
+}
+
+/// What a list costs in memory, see [`LinkedList::memory_usage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Nodes allocated so far, in use or not.
+    pub nodes: usize,
+    pub bytes_per_node: usize,
+    /// Everything reserved for nodes, including capacity that hasn't been handed out yet.
+    pub total_bytes: usize,
+    /// How many of `nodes` are free and will be reused before allocating again.
+    pub free_slots: usize,
+}
+
+pub struct Node<'arena, 'id, T> {
+    value: Option<T>,
+    /// Bumped every time the value is taken out, see [`NodeHandle`].
+    generation: u32,
+    prev: Option<NodeRef<'arena, 'id, T>>,
+    next: Option<NodeRef<'arena, 'id, T>>,
+}
+
+type NodeRef<'arena, 'id, T> = &'arena GhostCell<'id, Node<'arena, 'id, T>>;
+
+/// Points at a node of a list, see [`LinkedList::take_node_from`] and [`CursorMut::splice_from`].
+///
+/// A handle stays usable while its node moves between lists that share the same token. It
+/// remembers the node's generation, so once the element is removed the handle is outdated even if
+/// the node gets reused for another element.
+pub struct NodeHandle<'arena, 'id, T> {
+    node: NodeRef<'arena, 'id, T>,
+    generation: u32,
+}
+
+impl<'arena, 'id, T> NodeHandle<'arena, 'id, T> {
+    fn new(node: NodeRef<'arena, 'id, T>, token: &GhostToken<'id>) -> Self {
+        Self {
+            node,
+            generation: node.borrow(token).generation,
+        }
+    }
+}
+
+impl<'arena, 'id, T> Clone for NodeHandle<'arena, 'id, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'arena, 'id, T> Copy for NodeHandle<'arena, 'id, T> {}
+
+impl<'arena, 'id, T> PartialEq for NodeHandle<'arena, 'id, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node.as_ptr() == other.node.as_ptr() && self.generation == other.generation
+    }
+}
+
+impl<'arena, 'id, T> Eq for NodeHandle<'arena, 'id, T> {}
+
+impl<'arena, 'id, T> fmt::Debug for NodeHandle<'arena, 'id, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodeHandle")
+            .field("node", &self.node.as_ptr())
+            .field("generation", &self.generation)
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
+    /// Serializes the elements as a sequence, front to back.
+    pub fn serialize_with<S>(
+        &self,
+        token: &GhostToken<'id>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: serde::Serialize,
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.len))?;
+        for value in self.iter(token) {
+            seq.serialize_element(value)?;
+        }
+        seq.end()
+    }
+
+    /// A [`DeserializeSeed`](serde::de::DeserializeSeed) that builds a list in `arena` with `token`.
+    pub fn deserialize_seed<'a>(
+        arena: &'arena Arena<Node<'arena, 'id, T>>,
+        token: &'a mut GhostToken<'id>,
+    ) -> ListSeed<'a, 'arena, 'id, T> {
+        ListSeed { arena, token }
+    }
+}
+
+#[cfg(feature = "serde")]
+pub struct ListSeed<'a, 'arena, 'id, T> {
+    arena: &'arena Arena<Node<'arena, 'id, T>>,
+    token: &'a mut GhostToken<'id>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, 'a, 'arena, 'id, T> serde::de::DeserializeSeed<'de> for ListSeed<'a, 'arena, 'id, T>
+where
+    T: serde::Deserialize<'de>,
+{
+    type Value = LinkedList<'arena, 'id, T>;
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, 'a, 'arena, 'id, T> serde::de::Visitor<'de> for ListSeed<'a, 'arena, 'id, T>
+where
+    T: serde::Deserialize<'de>,
+{
+    type Value = LinkedList<'arena, 'id, T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut list = LinkedList::new(self.arena);
+
+        while let Some(value) = seq.next_element()? {
+            list.push_back(value, self.token);
+        }
+
+        Ok(list)
+    }
+}
+
+/// The token and arena behind [`LinkedList::scoped`], lent out as a [`ListArena`] to work on the
+/// lists.
+pub struct Scope<'arena, 'id, T> {
+    arena: &'arena Arena<Node<'arena, 'id, T>>,
+    token: GhostToken<'id>,
+}
+
+impl<'arena, 'id, T> Scope<'arena, 'id, T> {
+    pub fn list_arena(&mut self) -> ListArena<'_, 'arena, 'id, T> {
+        ListArena::new(self.arena, &mut self.token)
+    }
+}
+
+/// An arena and the token for the lists in it, so they can be passed around as one.
+///
+/// The methods mirror the ones on [`LinkedList`] that need the token. Both are borrowed: the lists
+/// borrow the arena for as long as they live, so a `ListArena` owning it would stay borrowed too
+/// and couldn't hand out the token mutably any more.
+pub struct ListArena<'a, 'arena, 'id, T> {
+    arena: &'arena Arena<Node<'arena, 'id, T>>,
+    token: &'a mut GhostToken<'id>,
+}
+
+impl<'a, 'arena, 'id, T> ListArena<'a, 'arena, 'id, T> {
+    pub fn new(arena: &'arena Arena<Node<'arena, 'id, T>>, token: &'a mut GhostToken<'id>) -> Self {
+        Self { arena, token }
+    }
+
+    pub fn new_list(&self) -> LinkedList<'arena, 'id, T> {
+        LinkedList::new(self.arena)
+    }
+
+    pub fn arena(&self) -> &'arena Arena<Node<'arena, 'id, T>> {
+        self.arena
+    }
+
+    /// For the parts of the list API that don't have a counterpart here.
+    pub fn token(&mut self) -> &mut GhostToken<'id> {
+        self.token
+    }
+
+    pub fn iter<'b>(&'b self, list: &'b LinkedList<'arena, 'id, T>) -> Iter<'b, 'arena, 'id, T> {
+        list.iter(self.token)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self, list: &LinkedList<'arena, 'id, T>) -> alloc::vec::Vec<T>
+    where
+        T: Clone,
+    {
+        list.to_vec(self.token)
+    }
+
+    pub fn front<'b>(&'b self, list: &'b LinkedList<'arena, 'id, T>) -> Option<&'b T> {
+        list.front(self.token)
+    }
+
+    pub fn back<'b>(&'b self, list: &'b LinkedList<'arena, 'id, T>) -> Option<&'b T> {
+        list.back(self.token)
+    }
+
+    pub fn front_mut<'b>(
+        &'b mut self,
+        list: &'b mut LinkedList<'arena, 'id, T>,
+    ) -> Option<&'b mut T> {
+        list.front_mut(self.token)
+    }
+
+    pub fn back_mut<'b>(
+        &'b mut self,
+        list: &'b mut LinkedList<'arena, 'id, T>,
+    ) -> Option<&'b mut T> {
+        list.back_mut(self.token)
+    }
+
+    pub fn push_front(&mut self, list: &mut LinkedList<'arena, 'id, T>, value: T) {
+        list.push_front(value, self.token)
+    }
+
+    pub fn push_back(&mut self, list: &mut LinkedList<'arena, 'id, T>, value: T) {
+        list.push_back(value, self.token)
+    }
+
+    pub fn pop_front(&mut self, list: &mut LinkedList<'arena, 'id, T>) -> Option<T> {
+        list.pop_front(self.token)
+    }
+
+    pub fn pop_back(&mut self, list: &mut LinkedList<'arena, 'id, T>) -> Option<T> {
+        list.pop_back(self.token)
+    }
+
+    pub fn clear(&mut self, list: &mut LinkedList<'arena, 'id, T>) {
+        list.clear(self.token)
+    }
+}
+
+/// A list and its token, see [`LinkedList::view`].
+pub struct ListView<'a, 'arena, 'id, T> {
+    list: &'a LinkedList<'arena, 'id, T>,
+    token: &'a GhostToken<'id>,
+}
+
+impl<'a, 'arena, 'id, T> Clone for ListView<'a, 'arena, 'id, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, 'arena, 'id, T> Copy for ListView<'a, 'arena, 'id, T> {}
+
+impl<'a, 'arena, 'id, T> IntoIterator for ListView<'a, 'arena, 'id, T>
+where
+    'arena: 'a,
+{
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, 'arena, 'id, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter(self.token)
+    }
+}
+
+pub struct Iter<'a, 'arena, 'id, T> {
+    token: &'a GhostToken<'id>,
+    head_tail: Option<(NodeRef<'arena, 'id, T>, NodeRef<'arena, 'id, T>)>,
+    len: usize,
+}
+
+impl<'a, 'arena, 'id, T> Iterator for Iter<'a, 'arena, 'id, T>
+where
+    'arena: 'a,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        let node = head.borrow(self.token);
+
+        if head.as_ptr() != tail.as_ptr() {
+            self.head_tail = node.next.map(|n| (n, tail));
+        } else {
+            self.head_tail = None;
+        }
+        self.len -= 1;
+
+        Some(node.value.as_ref().unwrap())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, 'arena, 'id, T> DoubleEndedIterator for Iter<'a, 'arena, 'id, T>
+where
+    'arena: 'a,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        let node = tail.borrow(self.token);
+
+        if head.as_ptr() != tail.as_ptr() {
+            self.head_tail = node.prev.map(|n| (head, n));
+        } else {
+            self.head_tail = None;
+        }
+        self.len -= 1;
+
+        Some(node.value.as_ref().unwrap())
+    }
+}
+
+impl<'a, 'arena, 'id, T> FusedIterator for Iter<'a, 'arena, 'id, T> where 'arena: 'a {}
+
+pub struct Pairs<'a, 'arena, 'id, T> {
+    iter: Iter<'a, 'arena, 'id, T>,
+    prev: Option<&'a T>,
+}
+
+impl<'a, 'arena, 'id, T> Iterator for Pairs<'a, 'arena, 'id, T>
+where
+    'arena: 'a,
+{
+    type Item = (&'a T, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let prev = self.prev?;
+        let next = self.iter.next()?;
+        self.prev = Some(next);
+
+        Some((prev, next))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, 'arena, 'id, T> FusedIterator for Pairs<'a, 'arena, 'id, T> where 'arena: 'a {}
+
+#[cfg(feature = "alloc")]
+pub struct Windows<'a, 'arena, 'id, T> {
+    iter: Iter<'a, 'arena, 'id, T>,
+    ring: alloc::collections::VecDeque<&'a T>,
+    n: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, 'arena, 'id, T> Iterator for Windows<'a, 'arena, 'id, T>
+where
+    'arena: 'a,
+{
+    type Item = alloc::vec::Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.iter.next()?;
+
+        if self.ring.len() == self.n {
+            self.ring.pop_front();
+        }
+        self.ring.push_back(next);
+
+        Some(self.ring.iter().copied().collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, 'arena, 'id, T> FusedIterator for Windows<'a, 'arena, 'id, T> where 'arena: 'a {}
+
+/// Why a `try_move_*` call left the cursor where it was.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveError {
+    /// The cursor is on the head and can't move further left.
+    AtFront,
+    /// The cursor is on the tail and can't move further right.
+    AtBack,
+    /// The list has no elements to move onto.
+    Empty,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveError::AtFront => f.write_str("cursor is at the front of the list"),
+            MoveError::AtBack => f.write_str("cursor is at the back of the list"),
+            MoveError::Empty => f.write_str("list is empty"),
+        }
+    }
+}
+
+/// A cursor over the list, `None` is the "ghost" position between the tail and the head.
+pub struct Cursor<'a, 'arena, 'id, T> {
+    list: &'a LinkedList<'arena, 'id, T>,
+    token: &'a GhostToken<'id>,
+    current: Option<NodeRef<'arena, 'id, T>>,
+    // equals `list.len` on the ghost position
+    index: usize,
+}
+
+impl<'a, 'arena, 'id, T> Cursor<'a, 'arena, 'id, T> {
+    /// The handle of the current node, `None` on the ghost position.
+    pub fn handle(&self) -> Option<NodeHandle<'arena, 'id, T>> {
+        self.current.map(|node| NodeHandle::new(node, self.token))
+    }
+
+    pub fn current(&self) -> Option<&'a T> {
+        let token = self.token;
+
+        self.current
+            .map(|node| node.borrow(token).value.as_ref().unwrap())
+    }
+
+    /// The position of the current element, `None` on the ghost position.
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Moves to the next element, or from the tail onto the ghost position and from there onto the head.
+    pub fn move_right(&mut self) {
+        (self.current, self.index) = match self.current {
+            Some(node) => (node.borrow(self.token).next, self.index + 1),
+            None => (self.list.head_tail.map(|(head, _)| head), 0),
+        };
+    }
+
+    /// Moves to the previous element, or from the head onto the ghost position and from there onto the tail.
+    pub fn move_left(&mut self) {
+        (self.current, self.index) = match self.current {
+            Some(node) => match node.borrow(self.token).prev {
+                Some(prev) => (Some(prev), self.index - 1),
+                None => (None, self.list.len),
+            },
+            None => (
+                self.list.head_tail.map(|(_, tail)| tail),
+                self.list.len.saturating_sub(1),
+            ),
+        };
+    }
+
+    /// Like [`Self::move_right`], but stays put instead of stepping onto the ghost position.
+    pub fn try_move_right(&mut self) -> Result<(), MoveError> {
+        let (next, index) = match self.current {
+            Some(node) => (node.borrow(self.token).next, self.index + 1),
+            None => (self.list.head_tail.map(|(head, _)| head), 0),
+        };
+
+        match (next, self.current) {
+            (Some(_), _) => {
+                (self.current, self.index) = (next, index);
+                Ok(())
+            }
+            (None, Some(_)) => Err(MoveError::AtBack),
+            (None, None) => Err(MoveError::Empty),
+        }
+    }
+
+    /// Like [`Self::move_left`], but stays put instead of stepping onto the ghost position.
+    pub fn try_move_left(&mut self) -> Result<(), MoveError> {
+        let (prev, index) = match self.current {
+            Some(node) => (node.borrow(self.token).prev, self.index.wrapping_sub(1)),
+            None => (
+                self.list.head_tail.map(|(_, tail)| tail),
+                self.list.len.wrapping_sub(1),
+            ),
+        };
+
+        match (prev, self.current) {
+            (Some(_), _) => {
+                (self.current, self.index) = (prev, index);
+                Ok(())
+            }
+            (None, Some(_)) => Err(MoveError::AtFront),
+            (None, None) => Err(MoveError::Empty),
+        }
+    }
+
+    /// Moves right up to `n` times, stopping on the tail, and returns how far it got.
+    pub fn advance_by(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.try_move_right().is_ok()).count()
+    }
+
+    /// Moves right until `pred` matches, starting with the current element.
+    ///
+    /// Returns `false` and ends on the ghost position if nothing matched.
+    pub fn seek<F>(&mut self, mut pred: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        while let Some(value) = self.current() {
+            if pred(value) {
+                return true;
+            }
+            self.move_right();
+        }
+
+        false
+    }
+}
+
+/// A cursor that can edit the list, `None` is the "ghost" position between the tail and the head.
+pub struct CursorMut<'a, 'arena, 'id, T> {
+    list: &'a mut LinkedList<'arena, 'id, T>,
+    token: &'a mut GhostToken<'id>,
+    current: Option<NodeRef<'arena, 'id, T>>,
+    // equals `list.len` on the ghost position
+    index: usize,
+}
+
+impl<'a, 'arena, 'id, T> CursorMut<'a, 'arena, 'id, T> {
+    /// The handle of the current node, `None` on the ghost position.
+    pub fn handle(&self) -> Option<NodeHandle<'arena, 'id, T>> {
+        self.current.map(|node| NodeHandle::new(node, self.token))
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        let node = self.current?;
+
+        Some(node.borrow_mut(self.token).value.as_mut().unwrap())
+    }
+
+    /// The position of the current element, `None` on the ghost position.
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Moves to the next element, or from the tail onto the ghost position and from there onto the head.
+    pub fn move_right(&mut self) {
+        (self.current, self.index) = match self.current {
+            Some(node) => (node.borrow(self.token).next, self.index + 1),
+            None => (self.list.head_tail.map(|(head, _)| head), 0),
+        };
+    }
+
+    /// Moves to the previous element, or from the head onto the ghost position and from there onto the tail.
+    pub fn move_left(&mut self) {
+        (self.current, self.index) = match self.current {
+            Some(node) => match node.borrow(self.token).prev {
+                Some(prev) => (Some(prev), self.index - 1),
+                None => (None, self.list.len),
+            },
+            None => (
+                self.list.head_tail.map(|(_, tail)| tail),
+                self.list.len.saturating_sub(1),
+            ),
+        };
+    }
+
+    /// Like [`Self::move_right`], but stays put instead of stepping onto the ghost position.
+    pub fn try_move_right(&mut self) -> Result<(), MoveError> {
+        let (next, index) = match self.current {
+            Some(node) => (node.borrow(self.token).next, self.index + 1),
+            None => (self.list.head_tail.map(|(head, _)| head), 0),
+        };
+
+        match (next, self.current) {
+            (Some(_), _) => {
+                (self.current, self.index) = (next, index);
+                Ok(())
+            }
+            (None, Some(_)) => Err(MoveError::AtBack),
+            (None, None) => Err(MoveError::Empty),
+        }
+    }
+
+    /// Like [`Self::move_left`], but stays put instead of stepping onto the ghost position.
+    pub fn try_move_left(&mut self) -> Result<(), MoveError> {
+        let (prev, index) = match self.current {
+            Some(node) => (node.borrow(self.token).prev, self.index.wrapping_sub(1)),
+            None => (
+                self.list.head_tail.map(|(_, tail)| tail),
+                self.list.len.wrapping_sub(1),
+            ),
+        };
+
+        match (prev, self.current) {
+            (Some(_), _) => {
+                (self.current, self.index) = (prev, index);
+                Ok(())
+            }
+            (None, Some(_)) => Err(MoveError::AtFront),
+            (None, None) => Err(MoveError::Empty),
+        }
+    }
+
+    /// Moves right by `n` (or left if negative), stopping on the ends, and returns how far it got.
+    pub fn move_n(&mut self, n: isize) -> isize {
+        let steps = n.unsigned_abs();
+
+        if n < 0 {
+            -((0..steps)
+                .take_while(|_| self.try_move_left().is_ok())
+                .count() as isize)
+        } else {
+            (0..steps)
+                .take_while(|_| self.try_move_right().is_ok())
+                .count() as isize
+        }
+    }
+
+    /// Like [`Cursor::seek`], but `pred` may modify the elements it visits.
+    pub fn seek_mut<F>(&mut self, mut pred: F) -> bool
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        while let Some(value) = self.current() {
+            if pred(value) {
+                return true;
+            }
+            self.move_right();
+        }
+
+        false
+    }
+
+    /// Inserts after the current element, or at the front when on the ghost position.
+    pub fn insert_after(&mut self, value: T) {
+        let node = self.list.insert(value, self.token);
+
+        self.list.link_after(self.current, node, node, self.token);
+        self.list.len += 1;
+
+        if self.current.is_none() {
+            self.index += 1;
+        }
+    }
+
+    /// Inserts before the current element, or at the back when on the ghost position.
+    pub fn insert_before(&mut self, value: T) {
+        let node = self.list.insert(value, self.token);
+        let prev = self.prev();
+
+        self.list.link_after(prev, node, node, self.token);
+        self.list.len += 1;
+        self.index += 1;
+    }
+
+    /// Removes the current element and moves onto the next one.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current?;
+        self.current = node.borrow(self.token).next;
+
+        self.list.unlink(node, self.token);
+
+        Some(self.list.recycle(node, self.token))
+    }
+
+    /// Like [`Self::remove_current`], but hands the element back as a one-element list on the same
+    /// arena instead of dropping its node, ready to be spliced into another list.
+    pub fn remove_current_as_list(&mut self) -> Option<LinkedList<'arena, 'id, T>> {
+        let node = self.current?;
+        self.current = node.borrow(self.token).next;
+
+        self.list.unlink(node, self.token);
+
+        let mut list = LinkedList::new(self.list.arena);
+        list.head_tail = Some((node, node));
+        list.len = 1;
+
+        Some(list)
+    }
+
+    /// Moves the node behind `handle` from `other` in after the current element (or at the front),
+    /// checks and panics like [`LinkedList::take_node_from`].
+    pub fn splice_from(
+        &mut self,
+        other: &mut LinkedList<'arena, 'id, T>,
+        handle: NodeHandle<'arena, 'id, T>,
+    ) {
+        other.detach(handle, self.token);
+
+        self.list
+            .link_after(self.current, handle.node, handle.node, self.token);
+        self.list.len += 1;
+
+        if self.current.is_none() {
+            self.index += 1;
+        }
+    }
+
+    /// Moves all elements of `other` in after the current element (or at the front) in `O(1)`.
+    pub fn splice_after(&mut self, other: &mut LinkedList<'arena, 'id, T>) {
+        if let Some((head, tail)) = other.head_tail.take() {
+            self.list.link_after(self.current, head, tail, self.token);
+            self.list.len += other.len;
+
+            if self.current.is_none() {
+                self.index += other.len;
+            }
+            other.len = 0;
+        }
+    }
+
+    /// Moves all elements of `other` in before the current element (or at the back) in `O(1)`.
+    pub fn splice_before(&mut self, other: &mut LinkedList<'arena, 'id, T>) {
+        if let Some((head, tail)) = other.head_tail.take() {
+            let prev = self.prev();
+
+            self.list.link_after(prev, head, tail, self.token);
+            self.list.len += other.len;
+            self.index += other.len;
+            other.len = 0;
+        }
+    }
+
+    /// Seeks right from the current element to the first one matching `pred` like
+    /// [`Self::seek_mut`], for updating it or inserting next to it in the same pass.
+    ///
+    /// Returns a [`VacantEntry`] on the ghost position if nothing matched.
+    pub fn entry<F>(&mut self, mut pred: F) -> Entry<'_, 'a, 'arena, 'id, T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        if self.seek_mut(|value| pred(value)) {
+            Entry::Occupied(OccupiedEntry { cursor: self })
+        } else {
+            Entry::Vacant(VacantEntry { cursor: self })
+        }
+    }
+
+    fn prev(&self) -> Option<NodeRef<'arena, 'id, T>> {
+        match self.current {
+            Some(node) => node.borrow(self.token).prev,
+            None => self.list.head_tail.map(|(_, tail)| tail),
+        }
+    }
+}
+
+/// The element a [`CursorMut::entry`] stopped on, or the ghost position if there was none.
+pub enum Entry<'c, 'a, 'arena, 'id, T> {
+    Occupied(OccupiedEntry<'c, 'a, 'arena, 'id, T>),
+    Vacant(VacantEntry<'c, 'a, 'arena, 'id, T>),
+}
+
+impl<'c, 'a, 'arena, 'id, T> Entry<'c, 'a, 'arena, 'id, T> {
+    /// The matched element, or `default` inserted at the back.
+    pub fn or_insert(self, default: T) -> &'c mut T {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> T>(self, default: F) -> &'c mut T {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    pub fn and_modify<F: FnOnce(&mut T)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+/// The cursor stopped on a matching element.
+pub struct OccupiedEntry<'c, 'a, 'arena, 'id, T> {
+    cursor: &'c mut CursorMut<'a, 'arena, 'id, T>,
+}
+
+impl<'c, 'a, 'arena, 'id, T> OccupiedEntry<'c, 'a, 'arena, 'id, T> {
+    pub fn get(&self) -> &T {
+        let node = self.cursor.current.unwrap();
+
+        node.borrow(self.cursor.token).value.as_ref().unwrap()
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        self.cursor.current().unwrap()
+    }
+
+    pub fn into_mut(self) -> &'c mut T {
+        self.cursor.current().unwrap()
+    }
+
+    pub fn index(&self) -> usize {
+        self.cursor.index
+    }
+
+    /// Inserts `value` in front of the matched element, for when it went past where `value`
+    /// belongs.
+    pub fn insert_before(self, value: T) -> &'c mut T {
+        self.cursor.insert_before(value);
+        let node = self.cursor.prev().unwrap();
+
+        node.borrow_mut(self.cursor.token).value.as_mut().unwrap()
+    }
+
+    /// Removes the matched element, the cursor moves onto the next one.
+    pub fn remove(self) -> T {
+        self.cursor.remove_current().unwrap()
+    }
+}
+
+/// Nothing matched, the cursor is on the ghost position.
+pub struct VacantEntry<'c, 'a, 'arena, 'id, T> {
+    cursor: &'c mut CursorMut<'a, 'arena, 'id, T>,
+}
+
+impl<'c, 'a, 'arena, 'id, T> VacantEntry<'c, 'a, 'arena, 'id, T> {
+    /// Inserts `value` at the cursor, which is the back of the list.
+    pub fn insert(self, value: T) -> &'c mut T {
+        self.cursor.insert_before(value);
+        let node = self.cursor.prev().unwrap();
+
+        node.borrow_mut(self.cursor.token).value.as_mut().unwrap()
+    }
+}
+
+pub struct DrainN<'a, 'arena, 'id, T> {
+    list: &'a mut LinkedList<'arena, 'id, T>,
+    token: &'a mut GhostToken<'id>,
+    remaining: usize,
+}
+
+impl<'a, 'arena, 'id, T> Iterator for DrainN<'a, 'arena, 'id, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.remaining = self.remaining.checked_sub(1)?;
+
+        self.list.pop_front(self.token)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, 'arena, 'id, T> FusedIterator for DrainN<'a, 'arena, 'id, T> {}
+
+impl<'a, 'arena, 'id, T> Drop for DrainN<'a, 'arena, 'id, T> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+pub struct ExtractIf<'a, 'arena, 'id, T, F> {
+    list: &'a mut LinkedList<'arena, 'id, T>,
+    token: &'a mut GhostToken<'id>,
+    next: Option<NodeRef<'arena, 'id, T>>,
+    pred: F,
+}
+
+impl<'a, 'arena, 'id, T, F> Iterator for ExtractIf<'a, 'arena, 'id, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(current) = self.next {
+            let node = current.borrow_mut(self.token);
+            self.next = node.next;
+
+            if (self.pred)(node.value.as_mut().unwrap()) {
+                self.list.unlink(current, self.token);
+
+                return Some(self.list.recycle(current, self.token));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn push_pop() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            list.push_front(1, token);
+            list.push_front(2, token);
 
             assert_eq!(list.pop_front(token), Some(2));
             assert_eq!(list.pop_front(token), Some(1));
@@ -203,54 +1975,1139 @@ mod test {
             let arena = Arena::new();
             let mut list = LinkedList::new(&arena);
 
-            list.push_back(1, token);
-            list.push_back(2, token);
+            list.push_back(1, token);
+            list.push_back(2, token);
+
+            assert_eq!(list.pop_back(token), Some(2));
+            assert_eq!(list.pop_back(token), Some(1));
+            assert_eq!(list.pop_back(token), None);
+        });
+
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            list.push_back(1, token);
+            list.push_back(2, token);
+
+            assert_eq!(list.pop_front(token), Some(1));
+            assert_eq!(list.pop_front(token), Some(2));
+            assert_eq!(list.pop_front(token), None);
+        });
+
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            list.push_front(1, token);
+            list.push_front(2, token);
+
+            assert_eq!(list.pop_back(token), Some(1));
+            assert_eq!(list.pop_back(token), Some(2));
+            assert_eq!(list.pop_back(token), None);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn clone() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
+
+            let other_arena = Arena::new();
+            let mut clone = list.clone_in(&other_arena, token);
+
+            *clone.front_mut(token).unwrap() = 10;
+            list.pop_back(token);
+
+            assert_eq!(list.to_vec(token), vec![1, 2]);
+            assert_eq!(clone.to_vec(token), vec![10, 2, 3]);
+            assert_eq!(
+                clone.iter(token).rev().copied().collect::<Vec<_>>(),
+                vec![3, 2, 10]
+            );
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            assert_eq!(list.to_vec(token), Vec::<i32>::new());
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
+            assert_eq!(list.to_vec(token), vec![1, 2, 3]);
+        });
+    }
+
+    #[test]
+    fn scoped() {
+        let popped = LinkedList::scoped(|list, scope| {
+            let mut cx = scope.list_arena();
+            cx.push_back(list, 2);
+            cx.push_front(list, 1);
+
+            let mut other = cx.new_list();
+            cx.push_back(&mut other, 3);
+            list.cursor_back_mut(cx.token()).splice_after(&mut other);
+
+            *cx.back_mut(list).unwrap() += 1;
+            assert_eq!(cx.front(list), Some(&1));
+            assert_eq!(cx.iter(list).copied().collect::<Vec<_>>(), vec![1, 2, 4]);
+
+            let mut other = LinkedList::new_in(&cx);
+            cx.push_back(&mut other, 5);
+            assert_eq!(cx.pop_front(&mut other), Some(5));
+
+            cx.pop_back(list)
+        });
+
+        assert_eq!(popped, Some(4));
+    }
+
+    #[test]
+    pub fn iter() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            list.push_back(1, token);
+            list.push_back(2, token);
+            list.push_back(3, token);
+            list.push_back(4, token);
+
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![1, 2, 3, 4]
+            );
+            assert_eq!(list.iter(token).last(), Some(&4));
+        });
+    }
+
+    #[test]
+    fn size_hint() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in 0..4 {
+                list.push_back(i, token);
+            }
+
+            let mut iter = list.iter(token);
+            assert_eq!(iter.size_hint(), (4, Some(4)));
+            iter.next();
+            iter.next_back();
+            assert_eq!(iter.size_hint(), (2, Some(2)));
+            iter.by_ref().for_each(drop);
+            assert_eq!(iter.size_hint(), (0, Some(0)));
+            assert_eq!(iter.next(), None);
+        });
+    }
+
+    #[test]
+    fn front_back() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            assert_eq!(list.front(token), None);
+            assert_eq!(list.back_mut(token), None);
+
+            list.push_back(1, token);
+            assert_eq!(list.front(token), Some(&1));
+            assert_eq!(list.back(token), Some(&1));
+
+            list.push_back(2, token);
+            *list.front_mut(token).unwrap() = 10;
+            *list.back_mut(token).unwrap() += 10;
+            assert_eq!(list.front(token), Some(&10));
+            assert_eq!(list.back(token), Some(&12));
+        });
+    }
+
+    #[test]
+    fn truncate() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in 0..10 {
+                list.push_back(i, token);
+            }
+
+            list.truncate(12, token);
+            assert_eq!(list.len(), 10);
+
+            // cut found from the back
+            list.truncate(8, token);
+            // and from the front
+            list.truncate(3, token);
+            assert_eq!(list.len(), 3);
+            assert_eq!(list.iter(token).copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+            assert_eq!(
+                list.iter(token).rev().copied().collect::<Vec<_>>(),
+                vec![2, 1, 0]
+            );
+
+            list.push_back(3, token);
+            assert_eq!(list.back(token), Some(&3));
+
+            list.truncate(0, token);
+            assert!(list.is_empty());
+            assert_eq!(list.len(), 0);
+        });
+    }
+
+    #[test]
+    fn memory_usage() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::with_capacity(4);
+            let mut list = LinkedList::new(&arena);
+            let stats = list.memory_usage();
+            assert_eq!(stats.nodes, 0);
+            assert_eq!(stats.total_bytes, 4 * stats.bytes_per_node);
+
+            list.push_back(1, token);
+            list.push_back(2, token);
+            list.pop_front(token);
+
+            let stats = list.memory_usage();
+            assert_eq!(stats.nodes, 2);
+            assert_eq!(stats.free_slots, 1);
+
+            list.push_back(3, token);
+            assert_eq!(list.memory_usage().nodes, 2);
+            assert_eq!(list.memory_usage().free_slots, 0);
+        });
+    }
+
+    #[test]
+    fn freelist() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in 0..1000 {
+                list.push_back(i, token);
+                list.push_front(i, token);
+                assert_eq!(list.pop_front(token), Some(i));
+                if i % 10 == 9 {
+                    list.truncate(0, token);
+                }
+            }
+            // at most the ten elements alive between truncates, plus the one pushed on top
+            assert!(arena.len() <= 11);
+
+            let mut cursor = list.cursor_front_mut(token);
+            cursor.insert_after(1);
+            cursor.insert_after(2);
+            assert_eq!(cursor.remove_current(), None);
+            let mut cursor = list.cursor_front_mut(token);
+            assert_eq!(cursor.remove_current(), Some(2));
+            assert_eq!(
+                list.extract_if(|_| true, token).collect::<Vec<_>>(),
+                vec![1]
+            );
+
+            for i in 0..5 {
+                list.push_back(i, token);
+            }
+            assert!(arena.len() <= 11);
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![0, 1, 2, 3, 4]
+            );
+        });
+    }
+
+    #[test]
+    fn extend_from_iter() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::with_capacity(4);
+            let mut list = LinkedList::new(&arena);
+
+            list.extend_from_iter(0..1000, token);
+            assert_eq!(list.len(), 1000);
+            assert_eq!(arena.len(), 1000);
+
+            for _ in 0..10 {
+                list.pop_front(token);
+            }
+            list.extend_from_iter(0..10, token);
+            assert_eq!(arena.len(), 1000);
+            assert_eq!(list.iter(token).next_back(), Some(&9));
+        });
+    }
+
+    #[test]
+    fn compare() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            let mut other = LinkedList::new(&arena);
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
+
+            assert!(!list.eq_with(&other, token));
+            assert_eq!(other.cmp_with(&list, token), Ordering::Less);
+
+            for i in [1, 2, 4] {
+                other.push_back(i, token);
+            }
+            assert_eq!(other.cmp_with(&list, token), Ordering::Greater);
+
+            other.pop_back(token);
+            other.push_back(3, token);
+            assert!(list.eq_with(&other, token));
+            assert_eq!(list.cmp_with(&other, token), Ordering::Equal);
+        });
+    }
+
+    #[test]
+    fn hash_with() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash<'id>(list: &LinkedList<'_, 'id, i32>, token: &GhostToken<'id>) -> u64 {
+            let mut state = DefaultHasher::new();
+            list.hash_with(token, &mut state);
+            state.finish()
+        }
+
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            let mut other = LinkedList::new(&arena);
+
+            for i in [1, 2] {
+                list.push_back(i, token);
+                other.push_front(i, token);
+            }
+            assert_ne!(hash(&list, token), hash(&other, token));
+
+            other.pop_back(token);
+            other.push_front(1, token);
+            assert_eq!(hash(&list, token), hash(&other, token));
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        use postcard::ser_flavors::{AllocVec, Flavor};
+        use serde::de::DeserializeSeed;
+
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            for v in ["a", "b", "c"] {
+                list.push_back(v, token);
+            }
+
+            let mut serializer = postcard::Serializer {
+                output: AllocVec::new(),
+            };
+            list.serialize_with(token, &mut serializer).unwrap();
+            let bytes = serializer.output.finalize().unwrap();
+            assert_eq!(bytes, postcard::to_allocvec(&["a", "b", "c"][..]).unwrap());
+
+            let arena = Arena::new();
+            let mut deserializer = postcard::Deserializer::from_bytes(&bytes);
+            let restored: LinkedList<String> = LinkedList::deserialize_seed(&arena, token)
+                .deserialize(&mut deserializer)
+                .unwrap();
+            assert_eq!(restored.len(), 3);
+            assert_eq!(restored.back(token).map(String::as_str), Some("c"));
+        });
+    }
+
+    #[test]
+    fn queries() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            assert!(!list.contains(&1, token));
+
+            for i in 1..=4 {
+                list.push_back(i * 10, token);
+            }
+
+            assert!(list.contains(&30, token));
+            assert!(!list.contains(&35, token));
+            assert_eq!(list.position(|v| *v > 15, token), Some(1));
+            assert_eq!(list.position(|v| *v > 40, token), None);
+            assert_eq!(list.find(|v| *v % 20 == 0, token), Some(&20));
+            assert_eq!(list.find(|v| *v < 0, token), None);
+        });
+    }
+
+    #[test]
+    fn is_sorted() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            assert!(list.is_sorted(token));
+
+            list.push_back(2, token);
+            assert!(list.is_sorted(token));
+
+            list.push_back(2, token);
+            list.push_back(3, token);
+            assert!(list.is_sorted(token));
+            assert!(!list.is_sorted_by(|a, b| a < b, token));
+
+            list.push_front(5, token);
+            assert!(!list.is_sorted(token));
+            assert!(!list.is_sorted_by(|a, b| a >= b, token));
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn insert_sorted() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in [5, 1, 4, 1, 5, 9, 2, 6] {
+                list.insert_sorted(i, token);
+            }
+            assert_eq!(list.to_vec(token), vec![1, 1, 2, 4, 5, 5, 6, 9]);
+            assert_eq!(list.len(), 8);
+
+            // equal keys stay in insertion order
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            for pair in [(2, 'a'), (1, 'b'), (2, 'c'), (0, 'd'), (1, 'e')] {
+                list.insert_sorted_by(pair, |a, b| a.0.cmp(&b.0), token);
+            }
+            assert_eq!(
+                list.iter(token).map(|(_, c)| *c).collect::<String>(),
+                "dbeac"
+            );
+            assert_eq!(list.back(token), Some(&(2, 'c')));
+        });
+    }
+
+    #[test]
+    fn cursor() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
 
-            assert_eq!(list.pop_back(token), Some(2));
-            assert_eq!(list.pop_back(token), Some(1));
-            assert_eq!(list.pop_back(token), None);
+            let mut cursor = list.cursor_front(token);
+            assert_eq!(cursor.current(), Some(&1));
+            cursor.move_left();
+            assert_eq!(cursor.current(), None);
+            cursor.move_left();
+            assert_eq!(cursor.current(), Some(&3));
+            cursor.move_right();
+            cursor.move_right();
+            assert_eq!(cursor.current(), Some(&1));
+
+            let mut cursor = list.cursor_front_mut(token);
+            cursor.move_right();
+            *cursor.current().unwrap() = 20;
+            cursor.insert_after(25);
+            cursor.insert_before(15);
+            assert_eq!(cursor.remove_current(), Some(20));
+            assert_eq!(cursor.current(), Some(&mut 25));
+
+            // on the ghost position inserts go to the ends
+            cursor.move_right();
+            cursor.move_right();
+            assert_eq!(cursor.current(), None);
+            cursor.insert_after(0);
+            cursor.insert_before(4);
+
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![0, 1, 15, 25, 3, 4]
+            );
+            assert_eq!(
+                list.iter(token).rev().copied().collect::<Vec<_>>(),
+                vec![4, 3, 25, 15, 1, 0]
+            );
         });
+    }
 
+    #[test]
+    fn splice() {
         GhostToken::new(|ref mut token| {
             let arena = Arena::new();
             let mut list = LinkedList::new(&arena);
+            let mut other = LinkedList::new(&arena);
 
             list.push_back(1, token);
-            list.push_back(2, token);
+            list.push_back(4, token);
+            other.push_back(2, token);
+            other.push_back(3, token);
 
-            assert_eq!(list.pop_front(token), Some(1));
-            assert_eq!(list.pop_front(token), Some(2));
-            assert_eq!(list.pop_front(token), None);
+            let mut cursor = list.cursor_front_mut(token);
+            cursor.splice_after(&mut other);
+            assert!(other.is_empty());
+
+            other.push_back(5, token);
+            let mut cursor = list.cursor_back_mut(token);
+            cursor.move_right();
+            cursor.splice_before(&mut other);
+
+            other.push_back(0, token);
+            let mut cursor = list.cursor_front_mut(token);
+            cursor.splice_before(&mut other);
+
+            // splicing an empty list is a no-op
+            list.cursor_back_mut(token).splice_after(&mut other);
+
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![0, 1, 2, 3, 4, 5]
+            );
+            assert_eq!(
+                list.iter(token).rev().copied().collect::<Vec<_>>(),
+                vec![5, 4, 3, 2, 1, 0]
+            );
+
+            let mut empty = LinkedList::new(&arena);
+            empty.cursor_front_mut(token).splice_after(&mut list);
+            assert_eq!(empty.len(), 6);
+            assert!(list.is_empty());
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn remove_current_as_list() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            let mut other = LinkedList::new(&arena);
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
+            other.push_back(10, token);
+
+            let mut cursor = list.cursor_front_mut(token);
+            cursor.move_right();
+            let mut two = cursor.remove_current_as_list().unwrap();
+            assert_eq!(cursor.current(), Some(&mut 3));
+            assert_eq!(two.len(), 1);
+            assert_eq!(two.front(token), Some(&2));
+
+            let nodes = arena.len();
+            other.cursor_front_mut(token).splice_before(&mut two);
+            assert_eq!(arena.len(), nodes);
+            assert_eq!(other.to_vec(token), vec![2, 10]);
+            assert_eq!(list.to_vec(token), vec![1, 3]);
+
+            let mut cursor = list.cursor_back_mut(token);
+            cursor.move_right();
+            assert!(cursor.remove_current_as_list().is_none());
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn take_node_from() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut busy = LinkedList::new(&arena);
+            let mut idle = LinkedList::new(&arena);
+
+            for task in ["a", "b", "c"] {
+                idle.push_back(task, token);
+            }
+            let mut cursor = idle.cursor_front(token);
+            cursor.move_right();
+            let b = cursor.handle().unwrap();
+            let c = idle.back_handle(token).unwrap();
+
+            let nodes = arena.len();
+            busy.take_node_from(&mut idle, b, token);
+            busy.take_node_from(&mut idle, c, token);
+            assert_eq!(busy.to_vec(token), vec!["b", "c"]);
+            assert_eq!(idle.to_vec(token), vec!["a"]);
+            assert_eq!(busy.back_handle(token), Some(c));
+
+            // and back again, in front of `a`
+            let mut cursor = idle.cursor_front_mut(token);
+            cursor.move_left();
+            cursor.splice_from(&mut busy, c);
+            assert_eq!(cursor.index(), None);
+            assert_eq!(idle.to_vec(token), vec!["c", "a"]);
+            assert_eq!(busy.len(), 1);
+            assert_eq!(busy.front_handle(token), Some(b));
+            assert_eq!(arena.len(), nodes);
         });
+    }
 
+    #[test]
+    #[should_panic = "removed node"]
+    fn take_removed_node() {
         GhostToken::new(|ref mut token| {
             let arena = Arena::new();
             let mut list = LinkedList::new(&arena);
+            let mut other = LinkedList::new(&arena);
 
-            list.push_front(1, token);
-            list.push_front(2, token);
+            other.push_back(1, token);
+            let handle = other.front_handle(token).unwrap();
+            other.pop_front(token);
 
-            assert_eq!(list.pop_back(token), Some(1));
-            assert_eq!(list.pop_back(token), Some(2));
-            assert_eq!(list.pop_back(token), None);
+            list.take_node_from(&mut other, handle, token);
         });
     }
 
     #[test]
-    pub fn iter() {
+    #[should_panic = "removed node"]
+    fn take_reused_node() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            let mut other = LinkedList::new(&arena);
+
+            other.push_back("x", token);
+            let handle = other.front_handle(token).unwrap();
+            other.pop_front(token);
+            other.push_back("y", token);
+            assert_eq!(arena.len(), 1);
+
+            list.take_node_from(&mut other, handle, token);
+        });
+    }
+
+    #[test]
+    #[should_panic = "does not belong"]
+    fn take_foreign_node() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            let mut other = LinkedList::new(&arena);
+            let mut third = LinkedList::new(&arena);
+
+            other.push_back(1, token);
+            third.extend_from_iter([2, 3, 4], token);
+            let mut cursor = third.cursor_front(token);
+            cursor.move_right();
+            let handle = cursor.handle().unwrap();
+
+            list.take_node_from(&mut other, handle, token);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn group_by() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in [1, 1, 2, 3, 3, 3, 1] {
+                list.push_back(i, token);
+            }
+
+            let groups = list.group_by(|a, b| a == b, token);
+            assert!(list.is_empty());
+            assert_eq!(
+                groups
+                    .iter()
+                    .map(|group| (group.len(), group.to_vec(token)))
+                    .collect::<Vec<_>>(),
+                vec![
+                    (2, vec![1, 1]),
+                    (1, vec![2]),
+                    (3, vec![3, 3, 3]),
+                    (1, vec![1])
+                ]
+            );
+            assert_eq!(groups[2].iter(token).next_back(), Some(&3));
+
+            // runs of ascending elements
+            for i in [1, 2, 5, 3, 4] {
+                list.push_back(i, token);
+            }
+            let runs = list.group_by(|a, b| a < b, token);
+            assert_eq!(runs.len(), 2);
+            assert_eq!(runs[1].to_vec(token), vec![3, 4]);
+
+            assert!(list.group_by(|_, _| true, token).is_empty());
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn concat() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in [1, 1, 2, 3, 3] {
+                list.push_back(i, token);
+            }
+            let mut groups = list.group_by(|a, b| a == b, token);
+            groups.insert(1, LinkedList::new(&arena));
+            groups[2].pop_front(token);
+
+            let list = LinkedList::concat(&arena, groups, token);
+            assert_eq!(list.len(), 4);
+            assert_eq!(list.to_vec(token), vec![1, 1, 3, 3]);
+            assert_eq!(
+                list.iter(token).rev().copied().collect::<Vec<_>>(),
+                vec![3, 3, 1, 1]
+            );
+            assert_eq!(list.memory_usage().free_slots, 1);
+
+            let empty = LinkedList::<i32>::concat(&arena, [], token);
+            assert!(empty.is_empty());
+        });
+    }
+
+    #[test]
+    fn try_move() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            let mut cursor = list.cursor_front_mut(token);
+            assert_eq!(cursor.try_move_right(), Err(MoveError::Empty));
+            assert_eq!(cursor.try_move_left(), Err(MoveError::Empty));
+
+            for i in 0..3 {
+                list.push_back(i, token);
+            }
+
+            let mut cursor = list.cursor_back_mut(token);
+            assert_eq!(cursor.try_move_right(), Err(MoveError::AtBack));
+            assert_eq!(cursor.current(), Some(&mut 2));
+            assert_eq!(cursor.index(), Some(2));
+
+            assert_eq!(cursor.try_move_left(), Ok(()));
+            assert_eq!(cursor.try_move_left(), Ok(()));
+            assert_eq!(cursor.try_move_left(), Err(MoveError::AtFront));
+            assert_eq!(cursor.current(), Some(&mut 0));
+            assert_eq!(cursor.index(), Some(0));
+
+            // from the ghost position moves land on the ends
+            cursor.move_left();
+            assert_eq!(cursor.try_move_left(), Ok(()));
+            assert_eq!(cursor.index(), Some(2));
+
+            let mut cursor = list.cursor_at(3, token);
+            assert_eq!(cursor.try_move_right(), Ok(()));
+            assert_eq!(cursor.current(), Some(&0));
+        });
+    }
+
+    #[test]
+    fn move_n() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in 0..5 {
+                list.push_back(i, token);
+            }
+
+            let mut cursor = list.cursor_front(token);
+            assert_eq!(cursor.advance_by(0), 0);
+            assert_eq!(cursor.advance_by(3), 3);
+            assert_eq!(cursor.current(), Some(&3));
+            assert_eq!(cursor.advance_by(10), 1);
+            assert_eq!(cursor.current(), Some(&4));
+
+            let mut cursor = list.cursor_front_mut(token);
+            assert_eq!(cursor.move_n(2), 2);
+            assert_eq!(cursor.move_n(-5), -2);
+            assert_eq!(cursor.index(), Some(0));
+            assert_eq!(cursor.move_n(isize::MAX), 4);
+            assert_eq!(cursor.current(), Some(&mut 4));
+            assert_eq!(cursor.move_n(isize::MIN), -4);
+            assert_eq!(cursor.index(), Some(0));
+        });
+    }
+
+    #[test]
+    fn seek() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in 0..6 {
+                list.push_back(i, token);
+            }
+
+            let mut cursor = list.cursor_front(token);
+            assert!(cursor.seek(|v| *v % 2 == 1));
+            assert_eq!(cursor.current(), Some(&1));
+            // the current element counts as a match
+            assert!(cursor.seek(|v| *v % 2 == 1));
+            assert_eq!(cursor.index(), Some(1));
+            assert!(!cursor.seek(|v| *v > 10));
+            assert_eq!(cursor.current(), None);
+
+            let mut cursor = list.cursor_front_mut(token);
+            assert!(cursor.seek_mut(|v| {
+                *v *= 10;
+                *v >= 30
+            }));
+            assert_eq!(cursor.index(), Some(3));
+
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![0, 10, 20, 30, 4, 5]
+            );
+        });
+    }
+
+    #[test]
+    fn entry() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            // a sorted map of counts, one pass per key
+            for key in [3, 1, 3, 2, 5, 1, 3] {
+                let mut cursor = list.cursor_front_mut(token);
+                match cursor.entry(|&(k, _)| k >= key) {
+                    Entry::Occupied(mut entry) if entry.get().0 == key => entry.get_mut().1 += 1,
+                    Entry::Occupied(entry) => {
+                        entry.insert_before((key, 1));
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert((key, 1));
+                    }
+                }
+            }
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![(1, 2), (2, 1), (3, 3), (5, 1)]
+            );
+
+            let mut cursor = list.cursor_front_mut(token);
+            *cursor.entry(|&(k, _)| k == 6).or_insert((6, 0)) = (6, 10);
+            list.cursor_front_mut(token)
+                .entry(|&(k, _)| k == 6)
+                .and_modify(|(_, count)| *count += 1)
+                .or_insert((6, 0));
+
+            let mut cursor = list.cursor_front_mut(token);
+            match cursor.entry(|&(k, _)| k == 2) {
+                Entry::Occupied(entry) => {
+                    assert_eq!(entry.index(), 1);
+                    assert_eq!(entry.remove(), (2, 1));
+                }
+                Entry::Vacant(_) => unreachable!(),
+            }
+            assert_eq!(cursor.current(), Some(&mut (3, 3)));
+
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![(1, 2), (3, 3), (5, 1), (6, 11)]
+            );
+        });
+    }
+
+    #[test]
+    fn pairs() {
         GhostToken::new(|ref mut token| {
             let arena = Arena::new();
             let mut list = LinkedList::new(&arena);
 
             list.push_back(1, token);
-            list.push_back(2, token);
-            list.push_back(3, token);
-            list.push_back(4, token);
+            assert_eq!(list.iter_pairs(token).next(), None);
+
+            for i in [3, 6, 10] {
+                list.push_back(i, token);
+            }
+            let pairs = list.iter_pairs(token);
+            assert_eq!(pairs.size_hint(), (3, Some(3)));
+            assert_eq!(pairs.map(|(a, b)| b - a).collect::<Vec<_>>(), vec![2, 3, 4]);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn windows() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in 1..=5 {
+                list.push_back(i, token);
+            }
+
+            let sums: Vec<i32> = list
+                .windows(3, token)
+                .map(|window| window.into_iter().sum())
+                .collect();
+            assert_eq!(sums, vec![6, 9, 12]);
+            assert_eq!(list.windows(1, token).count(), 5);
+            assert_eq!(list.windows(5, token).count(), 1);
+            assert_eq!(list.windows(6, token).next(), None);
+        });
+    }
+
+    #[test]
+    fn interleave() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            let mut other = LinkedList::new(&arena);
+
+            for i in 0..3 {
+                list.push_back(i, token);
+                other.push_back(10 + i, token);
+            }
+            other.push_back(13, token);
+            other.push_back(14, token);
 
+            list.interleave(&mut other, token);
+            assert!(other.is_empty());
+            assert_eq!(list.len(), 8);
             assert_eq!(
                 list.iter(token).copied().collect::<Vec<_>>(),
-                vec![1, 2, 3, 4]
-            )
+                vec![0, 10, 1, 11, 2, 12, 13, 14]
+            );
+            assert_eq!(
+                list.iter(token).rev().copied().collect::<Vec<_>>(),
+                vec![14, 13, 12, 2, 11, 1, 10, 0]
+            );
+
+            // the longer side is `self`, and the empty cases
+            other.push_back(-1, token);
+            list.interleave(&mut other, token);
+            assert_eq!(
+                list.iter(token).take(3).copied().collect::<Vec<_>>(),
+                vec![0, -1, 10]
+            );
+            list.interleave(&mut other, token);
+            other.interleave(&mut list, token);
+            assert!(list.is_empty());
+            assert_eq!(other.len(), 9);
+            assert_eq!(other.back(token), Some(&14));
+        });
+    }
+
+    #[test]
+    fn split_first_n() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in 0..10 {
+                list.push_back(i, token);
+            }
+
+            let mut first = list.split_first_n(3, token);
+            assert_eq!(
+                first.iter(token).copied().collect::<Vec<_>>(),
+                vec![0, 1, 2]
+            );
+            assert_eq!(list.len(), 7);
+
+            // closer to the back
+            let second = list.split_first_n(6, token);
+            assert_eq!(second.len(), 6);
+            assert_eq!(list.iter(token).copied().collect::<Vec<_>>(), vec![9]);
+            assert_eq!(list.front(token), list.back(token));
+
+            assert!(list.split_first_n(0, token).is_empty());
+            let rest = list.split_first_n(5, token);
+            assert!(list.is_empty());
+            assert_eq!(rest.len(), 1);
+
+            first.push_front(-1, token);
+            assert_eq!(
+                first.iter(token).rev().copied().collect::<Vec<_>>(),
+                vec![2, 1, 0, -1]
+            );
+        });
+    }
+
+    #[test]
+    fn drain_n() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in 0..10 {
+                list.push_back(i, token);
+            }
+
+            let mut drain = list.drain_n(4, token);
+            assert_eq!(drain.size_hint(), (4, Some(4)));
+            assert_eq!(drain.next(), Some(0));
+            assert_eq!(drain.next(), Some(1));
+            drop(drain);
+            assert_eq!(list.front(token), Some(&4));
+            assert_eq!(list.len(), 6);
+
+            let batches: Vec<i32> = (0..3).map(|_| list.drain_n(4, token).sum()).collect();
+            assert_eq!(batches, vec![4 + 5 + 6 + 7, 8 + 9, 0]);
+        });
+    }
+
+    #[test]
+    fn cursor_index() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            assert_eq!(list.cursor_at(0, token).index(), None);
+
+            for i in 0..5 {
+                list.push_back(i, token);
+            }
+
+            for i in 0..5 {
+                let cursor = list.cursor_at(i, token);
+                assert_eq!(cursor.current(), Some(&i));
+                assert_eq!(cursor.index(), Some(i));
+            }
+            assert_eq!(list.cursor_at(5, token).current(), None);
+
+            let mut cursor = list.cursor_back(token);
+            assert_eq!(cursor.index(), Some(4));
+            cursor.move_right();
+            assert_eq!(cursor.index(), None);
+            cursor.move_right();
+            assert_eq!(cursor.index(), Some(0));
+            cursor.move_left();
+            cursor.move_left();
+            assert_eq!(cursor.index(), Some(4));
+
+            let mut cursor = list.cursor_at_mut(2, token);
+            cursor.insert_before(10);
+            cursor.insert_after(20);
+            assert_eq!(cursor.index(), Some(3));
+            assert_eq!(cursor.remove_current(), Some(2));
+            assert_eq!(cursor.index(), Some(3));
+            assert_eq!(cursor.current(), Some(&mut 20));
+            cursor.move_left();
+            cursor.move_left();
+            cursor.move_left();
+            cursor.move_left();
+            assert_eq!(cursor.index(), None);
+            cursor.insert_after(99);
+            cursor.move_left();
+            assert_eq!(cursor.index(), Some(6));
+            assert_eq!(cursor.current(), Some(&mut 4));
+
+            assert_eq!(list.len(), 7);
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![99, 0, 1, 10, 20, 3, 4]
+            );
+        });
+    }
+
+    #[test]
+    fn extract_if() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in 1..=6 {
+                list.push_back(i, token);
+            }
+
+            let evens: Vec<_> = list.extract_if(|v| *v % 2 == 0, token).collect();
+            assert_eq!(evens, vec![2, 4, 6]);
+            assert_eq!(list.iter(token).copied().collect::<Vec<_>>(), vec![1, 3, 5]);
+            assert_eq!(
+                list.iter(token).rev().copied().collect::<Vec<_>>(),
+                vec![5, 3, 1]
+            );
+
+            // stopping early leaves the remaining elements untouched
+            assert_eq!(list.extract_if(|_| true, token).next(), Some(1));
+            assert_eq!(list.iter(token).copied().collect::<Vec<_>>(), vec![3, 5]);
+
+            assert_eq!(list.extract_if(|_| true, token).count(), 2);
+            assert!(list.is_empty());
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "debug-viz")]
+    fn dot() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            list.push_back("a", token);
+            list.push_back("\"b\"", token);
+
+            let mut out = String::new();
+            list.dot(&mut out, token).unwrap();
+
+            assert!(out.starts_with("digraph {\n"));
+            assert!(out.ends_with("}\n"));
+            assert!(out.contains(r#"[label="\"a\""]"#));
+            assert!(out.contains(r#"[label="\"\\\"b\\\"\""]"#));
+            // head, tail, one next and one prev link
+            assert_eq!(out.matches(" -> ").count(), 4);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "debug-viz")]
+    fn dump() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+            assert_eq!(list.dump(token).to_string(), "head -> (empty) <- tail");
+
+            list.push_back("a", token);
+            list.push_back("\"b\"", token);
+            assert_eq!(
+                list.dump(token).to_string(),
+                r#"head -> ["a"] <-> ["\"b\""] <- tail"#
+            );
+
+            let mut out = String::new();
+            list.mermaid(&mut out, token).unwrap();
+
+            assert!(out.starts_with("flowchart LR\n"));
+            assert!(out.contains(r##"["#quot;a#quot;"]"##));
+            assert_eq!(out.matches(" --> ").count(), 3);
+            assert_eq!(out.matches(" -.-> ").count(), 1);
+        });
+    }
+
+    #[test]
+    fn view() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut list = LinkedList::new(&arena);
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
+
+            let view = list.view(token);
+            let mut sum = 0;
+            for value in view {
+                sum += value;
+            }
+            assert_eq!(sum, 6);
+            assert_eq!(view.into_iter().next_back(), Some(&3));
         });
     }
 }
@@ -0,0 +1,108 @@
+use ghost_cell::GhostToken;
+use typed_arena::Arena;
+
+use crate::{LinkedList, Node};
+
+/// A FIFO queue that hands out all pending work at once.
+///
+/// [`take_all`](Self::take_all) and [`requeue_front`](Self::requeue_front) move whole lists in
+/// `O(1)`, no matter how long they are, so a worker can grab a batch, process part of it and give
+/// the rest back.
+pub struct WorkQueue<'arena, 'id, T> {
+    list: LinkedList<'arena, 'id, T>,
+}
+
+impl<'arena, 'id, T> WorkQueue<'arena, 'id, T> {
+    pub fn new(arena: &'arena Arena<Node<'arena, 'id, T>>) -> Self {
+        Self {
+            list: LinkedList::new(arena),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn as_list(&self) -> &LinkedList<'arena, 'id, T> {
+        &self.list
+    }
+
+    pub fn push(&mut self, value: T, token: &mut GhostToken<'id>) {
+        self.list.push_back(value, token)
+    }
+
+    pub fn pop(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+        self.list.pop_front(token)
+    }
+
+    /// Takes all queued elements in `O(1)`, leaving the queue empty.
+    ///
+    /// Popped nodes stay with the queue, so pushing again reuses them.
+    pub fn take_all(&mut self) -> LinkedList<'arena, 'id, T> {
+        let mut batch = LinkedList::new(self.list.arena);
+        batch.head_tail = self.list.head_tail.take();
+        batch.len = core::mem::take(&mut self.list.len);
+
+        batch
+    }
+
+    /// Puts `batch` back in front of everything queued in the meantime, in `O(1)`.
+    ///
+    /// `batch` should live on the queue's arena. The longer of the two free lists is kept.
+    pub fn requeue_front(
+        &mut self,
+        mut batch: LinkedList<'arena, 'id, T>,
+        token: &mut GhostToken<'id>,
+    ) {
+        if let Some((head, tail)) = batch.head_tail.take() {
+            self.list.link_after(None, head, tail, token);
+            self.list.len += batch.len;
+        }
+
+        if batch.free_len > self.list.free_len {
+            self.list.free = batch.free;
+            self.list.free_len = batch.free_len;
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn take_all() {
+        GhostToken::new(|ref mut token| {
+            let arena = Arena::new();
+            let mut queue = WorkQueue::new(&arena);
+
+            for i in 1..=5 {
+                queue.push(i, token);
+            }
+            assert_eq!(queue.pop(token), Some(1));
+
+            let mut batch = queue.take_all();
+            assert!(queue.is_empty());
+            assert_eq!(batch.len(), 4);
+
+            queue.push(6, token);
+            assert_eq!(batch.pop_front(token), Some(2));
+            assert_eq!(batch.pop_front(token), Some(3));
+
+            queue.requeue_front(batch, token);
+            assert_eq!(queue.len(), 3);
+            assert_eq!(queue.as_list().to_vec(token), vec![4, 5, 6]);
+
+            // both popped nodes came back with the batch
+            assert_eq!(queue.as_list().memory_usage().free_slots, 2);
+
+            queue.requeue_front(LinkedList::new(&arena), token);
+            assert_eq!(queue.as_list().to_vec(token), vec![4, 5, 6]);
+            assert_eq!(queue.as_list().memory_usage().free_slots, 2);
+        });
+    }
+}
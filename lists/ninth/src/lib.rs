@@ -0,0 +1,631 @@
+#![cfg_attr(not(test), no_std)]
+#![forbid(unsafe_code)]
+/// A list with `O(log n)` indexing, backed by an implicit treap.
+///
+/// Every node is the root of a subtree and stores that subtree's size, the position of a node is
+/// the number of nodes before it in order. Nodes also get a random priority and the tree is kept
+/// a heap on those, which keeps it balanced in expectation. Everything positional is built from
+/// two primitives: splitting a tree after the first `n` elements and merging two trees.
+///
+/// Allocation size per value:
+/// sizeof: Node<T> = T + u32 + usize + usize + usize
+/// => Overhead of list is between 28 and 32 bytes per entry
+///
+/// Pros:
+/// - No Dependencies
+/// - No unsafe & fully stable rust
+/// - No tokens required
+/// - `get`, `insert`, `remove`, `split_off`, `append` and `binary_search` are `O(log n)`
+///
+/// Cons:
+/// - Pushing and popping at the ends is `O(log n)` too instead of `O(1)`
+/// - Allocates every node on the heap individually
+/// - Needs `alloc`
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FusedIterator;
+
+#[cfg(feature = "invariants")]
+pub use invariants::InvariantError;
+
+type Link<T> = Option<Box<Node<T>>>;
+
+pub struct LinkedList<T> {
+    root: Link<T>,
+    /// xorshift32 state the node priorities are drawn from.
+    seed: u32,
+}
+
+impl<T> LinkedList<T> {
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            seed: 0x9e37_79b9,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter {
+            front: Vec::new(),
+            back: Vec::new(),
+            len: self.len(),
+        };
+        iter.push_left(self.root.as_deref());
+        iter.push_right(self.root.as_deref());
+        iter
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let mut index = index;
+        let mut current = self.root.as_deref()?;
+
+        loop {
+            let left = size(&current.left);
+
+            current = match index.cmp(&left) {
+                Ordering::Less => current.left.as_deref()?,
+                Ordering::Equal => return Some(&current.value),
+                Ordering::Greater => {
+                    index -= left + 1;
+                    current.right.as_deref()?
+                }
+            };
+        }
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let mut index = index;
+        let mut current = self.root.as_deref_mut()?;
+
+        loop {
+            let left = size(&current.left);
+
+            current = match index.cmp(&left) {
+                Ordering::Less => current.left.as_deref_mut()?,
+                Ordering::Equal => return Some(&mut current.value),
+                Ordering::Greater => {
+                    index -= left + 1;
+                    current.right.as_deref_mut()?
+                }
+            };
+        }
+    }
+
+    /// Returns a cursor on the element at `index`, or on the ghost position if `index` is out of
+    /// bounds.
+    pub fn cursor_at(&self, index: usize) -> Cursor<'_, T> {
+        Cursor {
+            list: self,
+            index: index.min(self.len()),
+        }
+    }
+
+    /// Searches a sorted list for `x` in `O(log n)` by walking down the tree, like
+    /// `slice::binary_search`.
+    ///
+    /// Returns a cursor on a match, or on the element `x` would have to be inserted before to keep
+    /// the list sorted, which is the ghost position if that's past the back.
+    pub fn binary_search(&self, x: &T) -> Result<Cursor<'_, T>, Cursor<'_, T>>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|value| value.cmp(x))
+    }
+
+    pub fn binary_search_by_key<B: Ord, F>(
+        &self,
+        key: &B,
+        mut f: F,
+    ) -> Result<Cursor<'_, T>, Cursor<'_, T>>
+    where
+        F: FnMut(&T) -> B,
+    {
+        self.binary_search_by(|value| f(value).cmp(key))
+    }
+
+    /// `f` tells whether a value is less than, equal to or greater than the target.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<Cursor<'_, T>, Cursor<'_, T>>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut before = 0;
+        let mut current = self.root.as_deref();
+
+        while let Some(node) = current {
+            let left = size(&node.left);
+
+            current = match f(&node.value) {
+                Ordering::Less => {
+                    before += left + 1;
+                    node.right.as_deref()
+                }
+                Ordering::Equal => return Ok(self.cursor_at(before + left)),
+                Ordering::Greater => node.left.as_deref(),
+            };
+        }
+
+        Err(self.cursor_at(before))
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len().checked_sub(1)?)
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.insert(0, value)
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.insert(self.len(), value)
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.remove(0)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.remove(self.len().checked_sub(1)?)
+    }
+
+    /// Inserts `value` so it ends up at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len(), "index out of bounds");
+
+        let node = Box::new(Node {
+            value,
+            priority: self.priority(),
+            size: 1,
+            left: None,
+            right: None,
+        });
+
+        let (front, back) = split(self.root.take(), index);
+        self.root = merge(merge(front, Some(node)), back);
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let (front, back) = split(self.root.take(), index);
+        let (removed, back) = split(back, 1);
+        self.root = merge(front, back);
+
+        removed.map(|node| node.value)
+    }
+
+    /// Splits the list in two after the first `at` elements, returning the second half.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len(), "index out of bounds");
+
+        let (front, back) = split(self.root.take(), at);
+        self.root = front;
+
+        Self {
+            root: back,
+            seed: self.priority(),
+        }
+    }
+
+    /// Moves every element of `other` to the back of `self`, leaving `other` empty.
+    pub fn append(&mut self, other: &mut Self) {
+        self.root = merge(self.root.take(), other.root.take());
+    }
+
+    pub fn clear(&mut self) {
+        self.root = None;
+    }
+
+    fn priority(&mut self) -> u32 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 17;
+        self.seed ^= self.seed << 5;
+        self.seed
+    }
+}
+
+#[cfg(feature = "invariants")]
+impl<T> LinkedList<T> {
+    /// Walks the tree and checks that every subtree size is right and that no node has a higher
+    /// priority than its parent. There are no links between neighbours to check.
+    pub fn check_invariants(&self) -> Result<(), InvariantError> {
+        check_tree(&self.root, u32::MAX)?;
+
+        Ok(())
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Node<T> {
+    value: T,
+    priority: u32,
+    /// Number of nodes in the subtree rooted here, including this one.
+    size: usize,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+fn size<T>(link: &Link<T>) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+fn update<T>(node: &mut Node<T>) {
+    node.size = 1 + size(&node.left) + size(&node.right);
+}
+
+/// Checks the sizes and priorities of a subtree and returns how many nodes it has.
+#[cfg(feature = "invariants")]
+fn check_tree<T>(link: &Link<T>, max_priority: u32) -> Result<usize, InvariantError> {
+    let Some(node) = link else {
+        return Ok(0);
+    };
+
+    if node.priority > max_priority {
+        return Err(InvariantError::Other(
+            "a node has a higher priority than its parent",
+        ));
+    }
+
+    let found =
+        1 + check_tree(&node.left, node.priority)? + check_tree(&node.right, node.priority)?;
+    if node.size != found {
+        return Err(InvariantError::LenMismatch {
+            len: node.size,
+            found,
+        });
+    }
+
+    Ok(found)
+}
+
+/// Splits `link` into the first `at` nodes and the rest.
+fn split<T>(link: Link<T>, at: usize) -> (Link<T>, Link<T>) {
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+
+    let left = size(&node.left);
+    if at <= left {
+        let (front, back) = split(node.left.take(), at);
+        node.left = back;
+        update(&mut node);
+
+        (front, Some(node))
+    } else {
+        let (front, back) = split(node.right.take(), at - left - 1);
+        node.right = front;
+        update(&mut node);
+
+        (Some(node), back)
+    }
+}
+
+/// Concatenates two trees, keeping the higher priority on top.
+fn merge<T>(front: Link<T>, back: Link<T>) -> Link<T> {
+    match (front, back) {
+        (None, link) | (link, None) => link,
+        (Some(mut front), Some(mut back)) => {
+            if front.priority > back.priority {
+                front.right = merge(front.right.take(), Some(back));
+                update(&mut front);
+
+                Some(front)
+            } else {
+                back.left = merge(Some(front), back.left.take());
+                update(&mut back);
+
+                Some(back)
+            }
+        }
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+pub struct IntoIter<T>(LinkedList<T>);
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+/// In-order walk with one stack per end, `len` keeps the two ends from crossing.
+pub struct Iter<'a, T> {
+    front: Vec<&'a Node<T>>,
+    back: Vec<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn push_left(&mut self, mut node: Option<&'a Node<T>>) {
+        while let Some(current) = node {
+            self.front.push(current);
+            node = current.left.as_deref();
+        }
+    }
+
+    fn push_right(&mut self, mut node: Option<&'a Node<T>>) {
+        while let Some(current) = node {
+            self.back.push(current);
+            node = current.right.as_deref();
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let node = self.front.pop()?;
+        self.push_left(node.right.as_deref());
+        self.len -= 1;
+
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let node = self.back.pop()?;
+        self.push_right(node.left.as_deref());
+        self.len -= 1;
+
+        Some(&node.value)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+/// A position in the list, see [`LinkedList::cursor_at`] and [`LinkedList::binary_search`].
+///
+/// Like the cursors of `std::collections::LinkedList` it can also point at a "ghost" position past
+/// the back and before the front, where [`current`](Cursor::current) is `None`. The cursor only
+/// remembers its position, so reading the current element walks down the tree in `O(log n)`.
+pub struct Cursor<'a, T> {
+    list: &'a LinkedList<T>,
+    // equals `list.len()` on the ghost position
+    index: usize,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// The element the cursor points at, `None` at the ghost position.
+    pub fn current(&self) -> Option<&'a T> {
+        self.list.get(self.index)
+    }
+
+    /// The position of the current element, `None` at the ghost position.
+    pub fn index(&self) -> Option<usize> {
+        (self.index < self.list.len()).then_some(self.index)
+    }
+
+    /// Moves to the next element, from the back to the ghost and from the ghost to the front.
+    pub fn move_next(&mut self) {
+        self.index = if self.index == self.list.len() {
+            0
+        } else {
+            self.index + 1
+        };
+    }
+
+    /// Moves to the previous element, from the front to the ghost and from the ghost to the back.
+    pub fn move_prev(&mut self) {
+        self.index = match self.index {
+            0 => self.list.len(),
+            index => index - 1,
+        };
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Cursor<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cursor")
+            .field("index", &self.index())
+            .field("current", &self.current())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn depth<T>(link: &Link<T>) -> usize {
+        link.as_ref()
+            .map_or(0, |node| 1 + depth(&node.left).max(depth(&node.right)))
+    }
+
+    #[test]
+    fn positional() {
+        let mut list: LinkedList<_> = (0..10).collect();
+
+        assert_eq!(list.get(4), Some(&4));
+        assert_eq!(list.get(10), None);
+        *list.get_mut(9).unwrap() = 90;
+
+        list.insert(3, 30);
+        list.insert(11, 110);
+        assert_eq!(list.remove(0), Some(0));
+        assert_eq!(list.remove(11), None);
+        assert_eq!(list.to_vec(), vec![1, 2, 30, 3, 4, 5, 6, 7, 8, 90, 110]);
+
+        let mut back = list.split_off(4);
+        assert_eq!(list.to_vec(), vec![1, 2, 30, 3]);
+        assert_eq!(back.to_vec(), vec![4, 5, 6, 7, 8, 90, 110]);
+
+        back.append(&mut list);
+        assert!(list.is_empty());
+        assert_eq!(back.len(), 11);
+        assert_eq!(back.get(7), Some(&1));
+        assert_eq!(
+            back.iter().rev().copied().collect::<Vec<_>>(),
+            vec![3, 30, 2, 1, 110, 90, 8, 7, 6, 5, 4]
+        );
+    }
+
+    #[test]
+    fn binary_search() {
+        let mut list: LinkedList<_> = (0..10_000).map(|i| i * 2).collect();
+
+        let index = |found: Result<Cursor<'_, i32>, Cursor<'_, i32>>| {
+            found
+                .map(|cursor| cursor.index())
+                .map_err(|cursor| cursor.index())
+        };
+        assert_eq!(index(list.binary_search(&0)), Ok(Some(0)));
+        assert_eq!(index(list.binary_search(&1234)), Ok(Some(617)));
+        assert_eq!(index(list.binary_search(&19_998)), Ok(Some(9_999)));
+        assert_eq!(index(list.binary_search(&-1)), Err(Some(0)));
+        let missing = list.binary_search(&1235).unwrap_err();
+        assert_eq!(missing.current(), Some(&1236));
+        assert_eq!(list.binary_search(&20_000).unwrap_err().current(), None);
+
+        let mut cursor = list.binary_search(&1234).unwrap();
+        assert_eq!(cursor.current(), Some(&1234));
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&1236));
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&1232));
+
+        // keep it sorted while inserting
+        for x in [7, 7, -3, 30_001] {
+            let index = match list.binary_search(&x) {
+                Ok(cursor) | Err(cursor) => cursor.index().unwrap_or(list.len()),
+            };
+            list.insert(index, x);
+        }
+        assert_eq!(list.front(), Some(&-3));
+        assert_eq!(list.get(5), Some(&7));
+        assert_eq!(list.get(6), Some(&7));
+        assert_eq!(list.back(), Some(&30_001));
+        assert!(list.iter().zip(list.iter().skip(1)).all(|(a, b)| a <= b));
+
+        let pairs: LinkedList<_> = [(1, 'a'), (3, 'b'), (5, 'c')].into_iter().collect();
+        let found = pairs.binary_search_by_key(&3, |&(n, _)| n).unwrap();
+        assert_eq!(found.current(), Some(&(3, 'b')));
+        let missing = pairs.binary_search_by_key(&4, |&(n, _)| n).unwrap_err();
+        assert_eq!(missing.index(), Some(2));
+
+        let empty = LinkedList::<i32>::new();
+        let mut ghost = empty.binary_search(&1).unwrap_err();
+        assert_eq!(ghost.current(), None);
+        ghost.move_next();
+        ghost.move_prev();
+        assert_eq!(ghost.index(), None);
+    }
+
+    #[test]
+    fn balanced() {
+        let list: LinkedList<_> = (0..10_000).collect();
+
+        // a degenerate tree would be 10_000 deep
+        assert!(depth(&list.root) < 64);
+        assert!(list.iter().copied().eq(0..10_000));
+        assert_eq!(list.get(5_000), Some(&5_000));
+    }
+}
@@ -0,0 +1,226 @@
+use ghost_cell::{GhostCell, GhostToken};
+use static_rc::StaticRc;
+
+/// A min-heap, implemented as a pairing heap on `StaticRc` and `GhostCell` nodes like the list.
+///
+/// Every node is owned by exactly one parent or older sibling, so the node pointers are full
+/// `StaticRc`s and never need to be split. Pushing and merging take `O(1)`, popping takes
+/// `O(log n)` amortized.
+///
+/// Dropping the heap drops its nodes recursively, use [`clear`](Self::clear) for large heaps.
+pub struct PriorityQueue<'id, T> {
+    len: usize,
+    root: Option<HeapNodePtr<'id, T>>,
+}
+
+impl<'id, T> PriorityQueue<'id, T> {
+    pub fn new() -> Self {
+        Self { len: 0, root: None }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    pub fn peek_min<'a>(&'a self, token: &'a GhostToken<'id>) -> Option<&'a T> {
+        self.root.as_ref().map(|root| &root.borrow(token).value)
+    }
+
+    /// Drops all nodes without recursing, by rotating children up into the sibling chain.
+    pub fn clear(&mut self, token: &mut GhostToken<'id>) {
+        let mut current = self.root.take();
+
+        while let Some(node) = current {
+            current = match node.borrow_mut(token).child.take() {
+                Some(child) => {
+                    let grandchild = child.borrow_mut(token).sibling.take();
+                    node.borrow_mut(token).child = grandchild;
+                    child.borrow_mut(token).sibling = Some(node);
+                    Some(child)
+                }
+                None => Self::into_node(node).sibling,
+            };
+        }
+
+        self.len = 0;
+    }
+
+    fn into_node(ptr: HeapNodePtr<'id, T>) -> HeapNode<'id, T> {
+        GhostCell::into_inner(HeapNodePtr::into_inner(ptr))
+    }
+}
+
+impl<'id, T: Ord> PriorityQueue<'id, T> {
+    pub fn push(&mut self, value: T, token: &mut GhostToken<'id>) {
+        let node = HeapNodePtr::new(GhostCell::new(HeapNode {
+            value,
+            child: None,
+            sibling: None,
+        }));
+
+        self.root = Some(match self.root.take() {
+            Some(root) => Self::meld(root, node, token),
+            None => node,
+        });
+        self.len += 1;
+    }
+
+    pub fn pop_min(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+        let root = Self::into_node(self.root.take()?);
+
+        self.root = root.child.map(|child| Self::merge_pairs(child, token));
+        self.len -= 1;
+
+        Some(root.value)
+    }
+
+    /// Moves all elements of `other` into `self`, leaving `other` empty.
+    pub fn merge(&mut self, other: &mut Self, token: &mut GhostToken<'id>) {
+        self.root = match (self.root.take(), other.root.take()) {
+            (Some(root), Some(other)) => Some(Self::meld(root, other, token)),
+            (root, other) => root.or(other),
+        };
+        self.len += other.len;
+        other.len = 0;
+    }
+
+    /// Links two roots, the greater one becomes the first child of the other.
+    fn meld(
+        left: HeapNodePtr<'id, T>,
+        right: HeapNodePtr<'id, T>,
+        token: &mut GhostToken<'id>,
+    ) -> HeapNodePtr<'id, T> {
+        let (parent, child) = if right.borrow(token).value < left.borrow(token).value {
+            (right, left)
+        } else {
+            (left, right)
+        };
+
+        let first_child = parent.borrow_mut(token).child.take();
+        child.borrow_mut(token).sibling = first_child;
+        parent.borrow_mut(token).child = Some(child);
+
+        parent
+    }
+
+    /// The two-pass merge of a sibling chain: meld pairs front to back, then fold the pairs back
+    /// to front. The first pass stacks the pairs through their own sibling links.
+    fn merge_pairs(first: HeapNodePtr<'id, T>, token: &mut GhostToken<'id>) -> HeapNodePtr<'id, T> {
+        let mut pairs: Option<HeapNodePtr<'id, T>> = None;
+        let mut rest = Some(first);
+
+        while let Some(left) = rest {
+            let pair = match left.borrow_mut(token).sibling.take() {
+                Some(right) => {
+                    rest = right.borrow_mut(token).sibling.take();
+                    Self::meld(left, right, token)
+                }
+                None => {
+                    rest = None;
+                    left
+                }
+            };
+
+            pair.borrow_mut(token).sibling = pairs;
+            pairs = Some(pair);
+        }
+
+        let mut root = pairs.unwrap();
+        let mut rest = root.borrow_mut(token).sibling.take();
+        while let Some(pair) = rest {
+            rest = pair.borrow_mut(token).sibling.take();
+            root = Self::meld(pair, root, token);
+        }
+
+        root
+    }
+}
+
+impl<'id, T> Default for PriorityQueue<'id, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct HeapNode<'id, T> {
+    value: T,
+    child: Option<HeapNodePtr<'id, T>>,
+    sibling: Option<HeapNodePtr<'id, T>>,
+}
+
+type HeapNodePtr<'id, T> = StaticRc<GhostCell<'id, HeapNode<'id, T>>, 1, 1>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn push_pop() {
+        GhostToken::new(|ref mut token| {
+            let mut heap = PriorityQueue::new();
+            assert_eq!(heap.pop_min(token), None);
+
+            for i in [5, 1, 4, 1, 5, 9, 2, 6, 5, 3] {
+                heap.push(i, token);
+            }
+            assert_eq!(heap.len(), 10);
+            assert_eq!(heap.peek_min(token), Some(&1));
+
+            let mut sorted = Vec::new();
+            while let Some(i) = heap.pop_min(token) {
+                sorted.push(i);
+            }
+            assert_eq!(sorted, vec![1, 1, 2, 3, 4, 5, 5, 5, 6, 9]);
+            assert!(heap.is_empty());
+            assert_eq!(heap.len(), 0);
+        });
+    }
+
+    #[test]
+    fn merge() {
+        GhostToken::new(|ref mut token| {
+            let mut heap = PriorityQueue::new();
+            let mut other = PriorityQueue::new();
+
+            for i in (0..10).step_by(2) {
+                heap.push(i, token);
+                other.push(i + 1, token);
+            }
+
+            heap.merge(&mut other, token);
+            assert!(other.is_empty());
+            assert_eq!(heap.len(), 10);
+
+            other.merge(&mut heap, token);
+            assert!(heap.is_empty());
+            assert_eq!(
+                (0..10)
+                    .map(|_| other.pop_min(token).unwrap())
+                    .collect::<Vec<_>>(),
+                (0..10).collect::<Vec<_>>()
+            );
+        });
+    }
+
+    #[test]
+    fn clear() {
+        GhostToken::new(|ref mut token| {
+            let mut heap = PriorityQueue::new();
+
+            // long child and sibling chains alike
+            for i in 0..100_000 {
+                heap.push(i, token);
+                heap.push(-i, token);
+            }
+            assert_eq!(heap.pop_min(token), Some(-99_999));
+
+            heap.clear(token);
+            assert!(heap.is_empty());
+            assert_eq!(heap.len(), 0);
+        });
+    }
+}
@@ -19,12 +19,28 @@
 /// - requires tokens to be passed around
 /// - mutable cursors and iterators are unsafe
 /// - Allocates every node on the heap individually
-/// - requires nightly rust
-/// - list must be cleared before drop (will panic otherwise)
+/// - the `StaticRc` ratios are only checked at compile time with the `nightly` feature, on stable
+///   `split` and `join` check them when they are called
+/// - list must be cleared before drop (will panic otherwise), use [`LinkedList::guard`] or
+///   [`LinkedList::close`] to make that hard to forget
 ///
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::ops::{Deref, DerefMut};
 use ghost_cell::{GhostCell, GhostToken};
 use static_rc::StaticRc;
 
+pub mod heap;
+
+pub use heap::PriorityQueue;
+
+#[cfg(feature = "invariants")]
+pub use invariants::InvariantError;
+
 pub struct LinkedList<'id, T> {
     len: usize,
     head_tail: Option<(HalfNodePtr<'id, T>, HalfNodePtr<'id, T>)>,
@@ -46,6 +62,88 @@ impl<'id, T> LinkedList<'id, T> {
         Iter::new(token, self)
     }
 
+    /// Pairs the list with `token`, so it can be iterated in a `for` loop.
+    pub fn view<'a>(&'a self, token: &'a GhostToken<'id>) -> ListView<'a, 'id, T> {
+        ListView { list: self, token }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self, token: &GhostToken<'id>) -> alloc::vec::Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter(token).cloned().collect()
+    }
+
+    pub fn eq_with(&self, other: &Self, token: &GhostToken<'id>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter(token).eq(other.iter(token))
+    }
+
+    /// Compares the elements lexicographically, like slices.
+    pub fn cmp_with(&self, other: &Self, token: &GhostToken<'id>) -> Ordering
+    where
+        T: Ord,
+    {
+        self.iter(token).cmp(other.iter(token))
+    }
+
+    /// Feeds the length and then the elements in order to `state`, like `Hash` would.
+    pub fn hash_with<H: Hasher>(&self, token: &GhostToken<'id>, state: &mut H)
+    where
+        T: Hash,
+    {
+        self.len.hash(state);
+
+        for value in self.iter(token) {
+            value.hash(state);
+        }
+    }
+
+    /// Deep-copies the list.
+    ///
+    /// Nodes can only be reached through borrows of the token, so the values are staged in a `Vec`
+    /// before they are linked into the copy.
+    #[cfg(feature = "alloc")]
+    pub fn clone(&self, token: &mut GhostToken<'id>) -> Self
+    where
+        T: Clone,
+    {
+        let mut clone = Self::new();
+
+        for value in self.to_vec(token) {
+            clone.push_back(value, token);
+        }
+
+        clone
+    }
+
+    pub fn front<'a>(&'a self, token: &'a GhostToken<'id>) -> Option<&'a T> {
+        self.head_tail
+            .as_ref()
+            .map(|(head, _)| &head.borrow(token).value)
+    }
+
+    pub fn back<'a>(&'a self, token: &'a GhostToken<'id>) -> Option<&'a T> {
+        self.head_tail
+            .as_ref()
+            .map(|(_, tail)| &tail.borrow(token).value)
+    }
+
+    pub fn front_mut<'a>(&'a mut self, token: &'a mut GhostToken<'id>) -> Option<&'a mut T> {
+        self.head_tail
+            .as_ref()
+            .map(|(head, _)| &mut head.borrow_mut(token).value)
+    }
+
+    pub fn back_mut<'a>(&'a mut self, token: &'a mut GhostToken<'id>) -> Option<&'a mut T> {
+        self.head_tail
+            .as_ref()
+            .map(|(_, tail)| &mut tail.borrow_mut(token).value)
+    }
+
     pub fn push_front(&mut self, value: T, token: &mut GhostToken<'id>) {
         let (one, two) = Self::new_halves(value);
 
@@ -65,39 +163,22 @@ impl<'id, T> LinkedList<'id, T> {
     pub fn push_back(&mut self, value: T, token: &mut GhostToken<'id>) {
         let (one, two) = Self::new_halves(value);
 
-        let head_tail = if let Some((head, tail)) = self.head_tail.take() {
-            tail.borrow_mut(token).next = Some(one);
-            two.borrow_mut(token).prev = Some(tail);
-
-            (head, two)
-        } else {
-            (one, two)
-        };
-
-        self.head_tail = Some(head_tail);
+        self.link_back(one, two, token);
         self.len += 1;
     }
 
     pub fn pop_front(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
-        let (head, tail) = self.head_tail.take()?;
-
-        if StaticRc::ptr_eq(&head, &tail) {
-            return Some(Self::into_inner(head, tail));
-        }
-
-        let new_head = head.borrow_mut(token).next.take().unwrap();
-        let other_head = new_head.borrow_mut(token).prev.take().unwrap();
-
-        self.head_tail = Some((new_head, tail));
+        let (left, right) = self.unlink_front(token)?;
         self.len -= 1;
 
-        Some(Self::into_inner(head, other_head))
+        Some(Self::into_inner(left, right))
     }
 
     pub fn pop_back(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
         let (head, tail) = self.head_tail.take()?;
 
         if StaticRc::ptr_eq(&head, &tail) {
+            self.len -= 1;
             return Some(Self::into_inner(head, tail));
         }
 
@@ -110,10 +191,165 @@ impl<'id, T> LinkedList<'id, T> {
         Some(Self::into_inner(tail, other_tail))
     }
 
+    /// Pops the first element, but only if `pred` returns `true` for it.
+    pub fn pop_front_if<F>(&mut self, pred: F, token: &mut GhostToken<'id>) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        if pred(self.front_mut(token)?) {
+            self.pop_front(token)
+        } else {
+            None
+        }
+    }
+
+    /// Pops the last element, but only if `pred` returns `true` for it.
+    pub fn pop_back_if<F>(&mut self, pred: F, token: &mut GhostToken<'id>) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        if pred(self.back_mut(token)?) {
+            self.pop_back(token)
+        } else {
+            None
+        }
+    }
+
     pub fn clear(&mut self, token: &mut GhostToken<'id>) {
         while self.pop_front(token).is_some() {}
     }
 
+    /// Clears the list and drops it, the list can't be used by accident afterwards.
+    pub fn close(mut self, token: &mut GhostToken<'id>) {
+        self.clear(token)
+    }
+
+    /// Borrows the list together with its token, clearing the list when the guard is dropped.
+    pub fn guard<'a>(&'a mut self, token: &'a mut GhostToken<'id>) -> ListGuard<'a, 'id, T> {
+        ListGuard { list: self, token }
+    }
+
+    pub fn dedup(&mut self, token: &mut GhostToken<'id>)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b, token)
+    }
+
+    /// Removes consecutive elements for which `same(current, previous)` returns `true`, keeping the first.
+    ///
+    /// If `same` panics, the elements that weren't looked at yet are kept.
+    pub fn dedup_by<F>(&mut self, mut same: F, token: &mut GhostToken<'id>)
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        // `same` only ever sees linked nodes, the guard moves whatever is left back behind the
+        // deduplicated ones
+        let mut guard = ScopeGuard::new((Self::new(), self, token), |(deduped, list, token)| {
+            while let Some((left, right)) = list.unlink_front(token) {
+                deduped.link_back(left, right, token);
+                deduped.len += 1;
+            }
+
+            list.head_tail = deduped.head_tail.take();
+            list.len = deduped.len;
+        });
+        let (deduped, list, token) = &mut *guard;
+
+        while let Some((head, _)) = &list.head_tail {
+            let duplicate = match &deduped.head_tail {
+                Some((_, tail)) => same(&head.borrow(token).value, &tail.borrow(token).value),
+                None => false,
+            };
+
+            let (left, right) = list.unlink_front(token).unwrap();
+            if duplicate {
+                drop(Self::into_inner(left, right));
+            } else {
+                deduped.link_back(left, right, token);
+                deduped.len += 1;
+            }
+        }
+    }
+
+    /// Merges `other` into `self` by relinking nodes, leaving `other` empty.
+    ///
+    /// Both lists must already be sorted by `cmp`. On ties elements of `self` come first. If `cmp`
+    /// panics, all elements still end up in `self`: the merged ones, then the rest of `self`, then
+    /// the rest of `other`.
+    pub fn merge<F>(&mut self, other: &mut Self, mut cmp: F, token: &mut GhostToken<'id>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut guard = ScopeGuard::new(
+            (Self::new(), self, other, token),
+            |(merged, list, other, token)| {
+                for source in [&mut **list, &mut **other] {
+                    while let Some((left, right)) = source.unlink_front(token) {
+                        merged.link_back(left, right, token);
+                    }
+                }
+
+                list.head_tail = merged.head_tail.take();
+                list.len += other.len;
+                other.len = 0;
+            },
+        );
+        let (merged, list, other, token) = &mut *guard;
+
+        loop {
+            let from_self = match (&list.head_tail, &other.head_tail) {
+                (Some((a, _)), Some((b, _))) => {
+                    cmp(&a.borrow(token).value, &b.borrow(token).value) != Ordering::Greater
+                }
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+
+            let source = if from_self { &mut **list } else { &mut **other };
+            let (left, right) = source.unlink_front(token).unwrap();
+            merged.link_back(left, right, token);
+        }
+    }
+
+    fn link_back(
+        &mut self,
+        one: HalfNodePtr<'id, T>,
+        two: HalfNodePtr<'id, T>,
+        token: &mut GhostToken<'id>,
+    ) {
+        let head_tail = if let Some((head, tail)) = self.head_tail.take() {
+            tail.borrow_mut(token).next = Some(one);
+            two.borrow_mut(token).prev = Some(tail);
+
+            (head, two)
+        } else {
+            (one, two)
+        };
+
+        self.head_tail = Some(head_tail);
+    }
+
+    /// Detaches the head node and returns both halves pointing to it.
+    fn unlink_front(
+        &mut self,
+        token: &mut GhostToken<'id>,
+    ) -> Option<(HalfNodePtr<'id, T>, HalfNodePtr<'id, T>)> {
+        let (head, tail) = self.head_tail.take()?;
+
+        if StaticRc::ptr_eq(&head, &tail) {
+            return Some((head, tail));
+        }
+
+        let new_head = head.borrow_mut(token).next.take().unwrap();
+        let other_head = new_head.borrow_mut(token).prev.take().unwrap();
+
+        self.head_tail = Some((new_head, tail));
+
+        Some((head, other_head))
+    }
+
     fn new_halves(value: T) -> (HalfNodePtr<'id, T>, HalfNodePtr<'id, T>) {
         let node = GhostCell::new(Node {
             value,
@@ -139,9 +375,440 @@ impl<'id, T> LinkedList<'id, T> {
     }
 }
 
+#[cfg(feature = "invariants")]
+impl<'id, T> LinkedList<'id, T> {
+    /// Walks the list and checks that the links match up and that `len` is right.
+    pub fn check_invariants(&self, token: &GhostToken<'id>) -> Result<(), InvariantError> {
+        invariants::check_links(
+            self.head_tail.as_ref().map(|(head, tail)| (head, tail)),
+            Some(self.len),
+            |&node| {
+                let node = node.borrow(token);
+                Some((node.prev.as_ref(), node.next.as_ref()))
+            },
+            |a, b| StaticRc::as_ptr(a) == StaticRc::as_ptr(b),
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "debug-viz")]
+impl<'id, T: core::fmt::Debug> LinkedList<'id, T> {
+    /// Writes the nodes and their `next` (solid) and `prev` (dashed) links as a GraphViz digraph,
+    /// e.g. for `dot -Tsvg`.
+    ///
+    /// Nodes are named by address and every link is drawn as stored, so a broken relink shows up
+    /// as a stray edge. At most `len + 1` nodes are followed.
+    pub fn dot<W: core::fmt::Write>(
+        &self,
+        out: &mut W,
+        token: &GhostToken<'id>,
+    ) -> core::fmt::Result {
+        use core::fmt::Write;
+
+        writeln!(out, "digraph {{")?;
+        writeln!(out, "    rankdir=LR;")?;
+        writeln!(out, "    node [shape=box];")?;
+
+        if let Some((head, tail)) = &self.head_tail {
+            writeln!(out, "    head [shape=plaintext];")?;
+            writeln!(out, "    tail [shape=plaintext];")?;
+            writeln!(out, "    head -> \"{:p}\";", StaticRc::as_ptr(head))?;
+            writeln!(out, "    tail -> \"{:p}\";", StaticRc::as_ptr(tail))?;
+        }
+
+        // one node past `len` is enough to see a cycle or a length drift
+        let mut current = self.head_tail.as_ref().map(|(head, _)| head);
+        for _ in 0..=self.len {
+            let Some(node_ref) = current else {
+                break;
+            };
+            let node = node_ref.borrow(token);
+
+            write!(out, "    \"{:p}\" [label=\"", StaticRc::as_ptr(node_ref))?;
+            write!(DotLabel(out), "{:?}", node.value)?;
+            writeln!(out, "\"];")?;
+
+            if let Some(next) = &node.next {
+                writeln!(
+                    out,
+                    "    \"{:p}\" -> \"{:p}\";",
+                    StaticRc::as_ptr(node_ref),
+                    StaticRc::as_ptr(next)
+                )?;
+            }
+            if let Some(prev) = &node.prev {
+                writeln!(
+                    out,
+                    "    \"{:p}\" -> \"{:p}\" [style=dashed];",
+                    StaticRc::as_ptr(node_ref),
+                    StaticRc::as_ptr(prev)
+                )?;
+            }
+
+            current = node.next.as_ref();
+        }
+
+        writeln!(out, "}}")
+    }
+
+    /// Renders the list on one line, like `head -> [1] <-> [2] <-> [3] <- tail`.
+    ///
+    /// A `->` between two elements means the second one's `prev` doesn't point back at the first.
+    /// At most `len + 1` nodes are shown.
+    pub fn dump<'a>(&'a self, token: &'a GhostToken<'id>) -> Dump<'a, 'id, T> {
+        Dump { list: self, token }
+    }
+
+    /// Writes the same graph as [`dot`](Self::dot) as a Mermaid `flowchart LR`, for docs and issues
+    /// that render Mermaid.
+    pub fn mermaid<W: core::fmt::Write>(
+        &self,
+        out: &mut W,
+        token: &GhostToken<'id>,
+    ) -> core::fmt::Result {
+        use core::fmt::Write;
+
+        writeln!(out, "flowchart LR")?;
+
+        if let Some((head, tail)) = &self.head_tail {
+            writeln!(out, "    head([head]) --> n{:p}", StaticRc::as_ptr(head))?;
+            writeln!(out, "    tail([tail]) --> n{:p}", StaticRc::as_ptr(tail))?;
+        }
+
+        let mut current = self.head_tail.as_ref().map(|(head, _)| head);
+        // one node past `len` is enough to see a cycle or a length drift
+        for _ in 0..=self.len {
+            let Some(node_ref) = current else {
+                break;
+            };
+            let node = node_ref.borrow(token);
+
+            write!(out, "    n{:p}[\"", StaticRc::as_ptr(node_ref))?;
+            write!(MermaidLabel(out), "{:?}", node.value)?;
+            writeln!(out, "\"]")?;
+
+            if let Some(next) = node.next.as_ref() {
+                writeln!(
+                    out,
+                    "    n{:p} --> n{:p}",
+                    StaticRc::as_ptr(node_ref),
+                    StaticRc::as_ptr(next)
+                )?;
+            }
+            if let Some(prev) = node.prev.as_ref() {
+                writeln!(
+                    out,
+                    "    n{:p} -.-> n{:p}",
+                    StaticRc::as_ptr(node_ref),
+                    StaticRc::as_ptr(prev)
+                )?;
+            }
+
+            current = node.next.as_ref();
+        }
+
+        Ok(())
+    }
+}
+
+/// Escapes a GraphViz label as it is written.
+#[cfg(feature = "debug-viz")]
+struct DotLabel<'a, W>(&'a mut W);
+
+#[cfg(feature = "debug-viz")]
+impl<W: core::fmt::Write> core::fmt::Write for DotLabel<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for c in s.chars() {
+            if matches!(c, '"' | '\\') {
+                self.0.write_char('\\')?;
+            }
+            self.0.write_char(c)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Escapes a Mermaid label as it is written.
+#[cfg(feature = "debug-viz")]
+struct MermaidLabel<'a, W>(&'a mut W);
+
+#[cfg(feature = "debug-viz")]
+impl<W: core::fmt::Write> core::fmt::Write for MermaidLabel<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for part in s.split_inclusive('"') {
+            match part.strip_suffix('"') {
+                Some(part) => {
+                    self.0.write_str(part)?;
+                    self.0.write_str("#quot;")?;
+                }
+                None => self.0.write_str(part)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The one line rendering of a list, see [`LinkedList::dump`].
+#[cfg(feature = "debug-viz")]
+pub struct Dump<'a, 'id, T> {
+    list: &'a LinkedList<'id, T>,
+    token: &'a GhostToken<'id>,
+}
+
+#[cfg(feature = "debug-viz")]
+impl<'a, 'id, T: core::fmt::Debug> core::fmt::Display for Dump<'a, 'id, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let token = self.token;
+        let list = self.list;
+
+        f.write_str("head -> ")?;
+
+        let mut last = None;
+        let mut current = list.head_tail.as_ref().map(|(head, _)| head);
+        // one node past `len` is enough to see a cycle or a length drift
+        for _ in 0..=list.len {
+            let Some(node_ref) = current else {
+                break;
+            };
+            let node = node_ref.borrow(token);
+
+            if let Some(last) = last {
+                let linked_back = node
+                    .prev
+                    .as_ref()
+                    .is_some_and(|prev| StaticRc::as_ptr(prev) == StaticRc::as_ptr(last));
+                f.write_str(if linked_back { " <-> " } else { " -> " })?;
+            }
+            write!(f, "[{:?}]", node.value)?;
+
+            last = Some(node_ref);
+            current = node.next.as_ref();
+        }
+
+        if last.is_none() {
+            f.write_str("(empty)")?;
+        }
+
+        f.write_str(" <- tail")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'id, T> LinkedList<'id, T> {
+    /// Serializes the elements as a sequence, front to back.
+    pub fn serialize_with<S>(
+        &self,
+        token: &GhostToken<'id>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: serde::Serialize,
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.len))?;
+        for value in self.iter(token) {
+            seq.serialize_element(value)?;
+        }
+        seq.end()
+    }
+
+    /// A [`DeserializeSeed`](serde::de::DeserializeSeed) that builds a list with `token`.
+    pub fn deserialize_seed<'a>(token: &'a mut GhostToken<'id>) -> ListSeed<'a, 'id, T> {
+        ListSeed {
+            token,
+            marker: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+pub struct ListSeed<'a, 'id, T> {
+    token: &'a mut GhostToken<'id>,
+    marker: core::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, 'a, 'id, T> serde::de::DeserializeSeed<'de> for ListSeed<'a, 'id, T>
+where
+    T: serde::Deserialize<'de>,
+{
+    type Value = LinkedList<'id, T>;
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, 'a, 'id, T> serde::de::Visitor<'de> for ListSeed<'a, 'id, T>
+where
+    T: serde::Deserialize<'de>,
+{
+    type Value = LinkedList<'id, T>;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("a sequence")
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut list = LinkedList::new();
+
+        loop {
+            match seq.next_element() {
+                Ok(Some(value)) => list.push_back(value, self.token),
+                Ok(None) => return Ok(list),
+                Err(err) => {
+                    // the list must not be dropped while it still holds nodes
+                    list.clear(self.token);
+                    return Err(err);
+                }
+            }
+        }
+    }
+}
+
+/// Runs `on_drop` on the value when dropped, after a normal return as well as while unwinding.
+///
+/// Relinking operations that call user code keep their bookkeeping in here, so a panic can't leave
+/// nodes detached from every list.
+struct ScopeGuard<V, F: FnMut(&mut V)> {
+    value: V,
+    on_drop: F,
+}
+
+impl<V, F: FnMut(&mut V)> ScopeGuard<V, F> {
+    fn new(value: V, on_drop: F) -> Self {
+        Self { value, on_drop }
+    }
+}
+
+impl<V, F: FnMut(&mut V)> Deref for ScopeGuard<V, F> {
+    type Target = V;
+
+    fn deref(&self) -> &V {
+        &self.value
+    }
+}
+
+impl<V, F: FnMut(&mut V)> DerefMut for ScopeGuard<V, F> {
+    fn deref_mut(&mut self) -> &mut V {
+        &mut self.value
+    }
+}
+
+impl<V, F: FnMut(&mut V)> Drop for ScopeGuard<V, F> {
+    fn drop(&mut self) {
+        (self.on_drop)(&mut self.value)
+    }
+}
+
+/// A list and its token that clears the list on drop, see [`LinkedList::guard`].
+///
+/// The methods mirror the ones on [`LinkedList`] that need the token. The list is also cleared
+/// when unwinding, so a panic can't leak its nodes either.
+pub struct ListGuard<'a, 'id, T> {
+    list: &'a mut LinkedList<'id, T>,
+    token: &'a mut GhostToken<'id>,
+}
+
+impl<'a, 'id, T> ListGuard<'a, 'id, T> {
+    /// For the parts of the list API that don't have a counterpart here.
+    pub fn parts(&mut self) -> (&mut LinkedList<'id, T>, &mut GhostToken<'id>) {
+        (self.list, self.token)
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.head_tail.is_none()
+    }
+
+    pub fn iter(&self) -> Iter<'_, 'id, T> {
+        self.list.iter(self.token)
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.list.front(self.token)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.list.back(self.token)
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.list.front_mut(self.token)
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.list.back_mut(self.token)
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.list.push_front(value, self.token)
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.list.push_back(value, self.token)
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.pop_front(self.token)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.pop_back(self.token)
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear(self.token)
+    }
+}
+
+impl<'a, 'id, T> Drop for ListGuard<'a, 'id, T> {
+    fn drop(&mut self) {
+        self.list.clear(self.token)
+    }
+}
+
+/// A list and its token, see [`LinkedList::view`].
+pub struct ListView<'a, 'id, T> {
+    list: &'a LinkedList<'id, T>,
+    token: &'a GhostToken<'id>,
+}
+
+impl<'a, 'id, T> Clone for ListView<'a, 'id, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, 'id, T> Copy for ListView<'a, 'id, T> {}
+
+impl<'a, 'id, T> IntoIterator for ListView<'a, 'id, T> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, 'id, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter(self.token)
+    }
+}
+
 pub struct Iter<'a, 'id, T> {
     token: &'a GhostToken<'id>,
     head_tail: Option<(&'a GhostNode<'id, T>, &'a GhostNode<'id, T>)>,
+    len: usize,
 }
 
 impl<'a, 'id, T> Iter<'a, 'id, T> {
@@ -151,7 +818,11 @@ impl<'a, 'id, T> Iter<'a, 'id, T> {
             .as_ref()
             .map(|head_tail| (&*head_tail.0, &*head_tail.1));
 
-        Self { token, head_tail }
+        Self {
+            token,
+            head_tail,
+            len: list.len,
+        }
     }
 }
 
@@ -172,8 +843,22 @@ impl<'a, 'id, T> Iterator for Iter<'a, 'id, T> {
             self.head_tail = None;
         }
 
+        self.len -= 1;
+
         Some(&current.value)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
 }
 
 impl<'a, 'id, T> DoubleEndedIterator for Iter<'a, 'id, T> {
@@ -191,10 +876,14 @@ impl<'a, 'id, T> DoubleEndedIterator for Iter<'a, 'id, T> {
             self.head_tail = None;
         }
 
+        self.len -= 1;
+
         Some(&node.value)
     }
 }
 
+impl<'a, 'id, T> FusedIterator for Iter<'a, 'id, T> {}
+
 pub struct Node<'id, T> {
     value: T,
     prev: Option<HalfNodePtr<'id, T>>,
@@ -284,9 +973,411 @@ mod test {
         })
     }
 
+    #[test]
+    fn iter_last_count() {
+        GhostToken::new(|ref mut token| {
+            let mut list = LinkedList::new();
+
+            for i in 0..4 {
+                list.push_back(i, token);
+            }
+            assert_eq!(list.pop_front(token), Some(0));
+
+            assert_eq!(list.iter(token).count(), 3);
+            assert_eq!(list.iter(token).last(), Some(&3));
+
+            let mut iter = list.iter(token);
+            iter.next();
+            iter.next_back();
+            assert_eq!(iter.count(), 1);
+
+            while list.pop_back(token).is_some() {}
+            assert_eq!(list.len(), 0);
+            assert_eq!(list.iter(token).count(), 0);
+            assert_eq!(list.iter(token).last(), None);
+        })
+    }
+
+    #[test]
+    fn front_back() {
+        GhostToken::new(|ref mut token| {
+            let mut list = LinkedList::new();
+
+            assert_eq!(list.front(token), None);
+            assert_eq!(list.back_mut(token), None);
+
+            list.push_back(1, token);
+            assert_eq!(list.front(token), Some(&1));
+            assert_eq!(list.back(token), Some(&1));
+
+            list.push_back(2, token);
+            *list.front_mut(token).unwrap() = 10;
+            *list.back_mut(token).unwrap() += 10;
+            assert_eq!(list.front(token), Some(&10));
+            assert_eq!(list.back(token), Some(&12));
+
+            list.clear(token)
+        })
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn clone() {
+        GhostToken::new(|ref mut token| {
+            let mut list = LinkedList::new();
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
+
+            let mut clone = list.clone(token);
+
+            *clone.front_mut(token).unwrap() = 10;
+            list.pop_back(token);
+
+            assert_eq!(list.to_vec(token), vec![1, 2]);
+            assert_eq!(clone.to_vec(token), vec![10, 2, 3]);
+            assert_eq!(
+                clone.iter(token).rev().copied().collect::<Vec<_>>(),
+                vec![3, 2, 10]
+            );
+
+            list.clear(token);
+            clone.clear(token)
+        })
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec() {
+        GhostToken::new(|ref mut token| {
+            let mut list = LinkedList::new();
+
+            assert_eq!(list.to_vec(token), Vec::<i32>::new());
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
+            assert_eq!(list.to_vec(token), vec![1, 2, 3]);
+
+            list.clear(token)
+        })
+    }
+
+    #[test]
+    fn compare() {
+        GhostToken::new(|ref mut token| {
+            let mut list = LinkedList::new();
+            let mut other = LinkedList::new();
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
+
+            assert!(!list.eq_with(&other, token));
+            assert_eq!(other.cmp_with(&list, token), Ordering::Less);
+
+            for i in [1, 2, 4] {
+                other.push_back(i, token);
+            }
+            assert_eq!(other.cmp_with(&list, token), Ordering::Greater);
+
+            other.pop_back(token);
+            other.push_back(3, token);
+            assert!(list.eq_with(&other, token));
+            assert_eq!(list.cmp_with(&other, token), Ordering::Equal);
+
+            list.clear(token);
+            other.clear(token)
+        })
+    }
+
+    #[test]
+    fn hash_with() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash<'id>(list: &LinkedList<'id, i32>, token: &GhostToken<'id>) -> u64 {
+            let mut state = DefaultHasher::new();
+            list.hash_with(token, &mut state);
+            state.finish()
+        }
+
+        GhostToken::new(|ref mut token| {
+            let mut list = LinkedList::new();
+            let mut other = LinkedList::new();
+
+            for i in [1, 2] {
+                list.push_back(i, token);
+                other.push_front(i, token);
+            }
+            assert_ne!(hash(&list, token), hash(&other, token));
+
+            other.pop_back(token);
+            other.push_front(1, token);
+            assert_eq!(hash(&list, token), hash(&other, token));
+
+            list.clear(token);
+            other.clear(token)
+        })
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        use postcard::ser_flavors::{AllocVec, Flavor};
+        use serde::de::DeserializeSeed;
+
+        GhostToken::new(|ref mut token| {
+            let mut list = LinkedList::new();
+            for v in ["a", "b", "c"] {
+                list.push_back(v, token);
+            }
+
+            let mut serializer = postcard::Serializer {
+                output: AllocVec::new(),
+            };
+            list.serialize_with(token, &mut serializer).unwrap();
+            let bytes = serializer.output.finalize().unwrap();
+            assert_eq!(bytes, postcard::to_allocvec(&["a", "b", "c"][..]).unwrap());
+
+            let mut deserializer = postcard::Deserializer::from_bytes(&bytes);
+            let mut restored: LinkedList<String> = LinkedList::deserialize_seed(token)
+                .deserialize(&mut deserializer)
+                .unwrap();
+            assert_eq!(restored.len(), 3);
+            assert_eq!(restored.back(token).map(String::as_str), Some("c"));
+
+            // a truncated input fails without dropping a non-empty list
+            let mut deserializer = postcard::Deserializer::from_bytes(&bytes[..bytes.len() - 1]);
+            assert!(LinkedList::<String>::deserialize_seed(token)
+                .deserialize(&mut deserializer)
+                .is_err());
+
+            list.clear(token);
+            restored.clear(token)
+        })
+    }
+
+    #[test]
+    fn dedup() {
+        GhostToken::new(|ref mut token| {
+            let mut list = LinkedList::new();
+
+            for v in [1, 1, 2, 3, 3, 3, 1, 4, 4] {
+                list.push_back(v, token);
+            }
+            list.dedup(token);
+
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![1, 2, 3, 1, 4]
+            );
+            assert_eq!(
+                list.iter(token).rev().copied().collect::<Vec<_>>(),
+                vec![4, 1, 3, 2, 1]
+            );
+            assert_eq!(list.len(), 5);
+
+            list.dedup_by(|a, b| a > b, token);
+            assert_eq!(list.iter(token).copied().collect::<Vec<_>>(), vec![1, 1]);
+            assert_eq!(list.len(), 2);
+
+            list.clear(token)
+        })
+    }
+
+    #[test]
+    fn merge() {
+        GhostToken::new(|ref mut token| {
+            let mut list = LinkedList::new();
+            let mut other = LinkedList::new();
+
+            for v in [(1, 'a'), (3, 'a'), (3, 'b'), (8, 'a')] {
+                list.push_back(v, token);
+            }
+            for v in [(0, 'c'), (3, 'c'), (9, 'c')] {
+                other.push_back(v, token);
+            }
+
+            list.merge(&mut other, |a, b| a.0.cmp(&b.0), token);
+
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![
+                    (0, 'c'),
+                    (1, 'a'),
+                    (3, 'a'),
+                    (3, 'b'),
+                    (3, 'c'),
+                    (8, 'a'),
+                    (9, 'c')
+                ]
+            );
+            assert_eq!(list.iter(token).next_back(), Some(&(9, 'c')));
+            assert_eq!(list.len(), 7);
+            assert_eq!(other.len(), 0);
+            assert_eq!(other.iter(token).next(), None);
+
+            list.clear(token)
+        })
+    }
+
+    #[test]
+    #[cfg(feature = "debug-viz")]
+    fn dot() {
+        GhostToken::new(|ref mut token| {
+            let mut list = LinkedList::new();
+            list.push_back("a", token);
+            list.push_back("\"b\"", token);
+
+            let mut out = String::new();
+            list.dot(&mut out, token).unwrap();
+
+            assert!(out.starts_with("digraph {\n"));
+            assert!(out.ends_with("}\n"));
+            assert!(out.contains(r#"[label="\"a\""]"#));
+            assert!(out.contains(r#"[label="\"\\\"b\\\"\""]"#));
+            // head, tail, one next and one prev link
+            assert_eq!(out.matches(" -> ").count(), 4);
+
+            list.clear(token);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "debug-viz")]
+    fn dump() {
+        GhostToken::new(|ref mut token| {
+            let mut list = LinkedList::new();
+            assert_eq!(list.dump(token).to_string(), "head -> (empty) <- tail");
+
+            list.push_back("a", token);
+            list.push_back("\"b\"", token);
+            assert_eq!(
+                list.dump(token).to_string(),
+                r#"head -> ["a"] <-> ["\"b\""] <- tail"#
+            );
+
+            let mut out = String::new();
+            list.mermaid(&mut out, token).unwrap();
+
+            assert!(out.starts_with("flowchart LR\n"));
+            assert!(out.contains(r##"["#quot;a#quot;"]"##));
+            assert_eq!(out.matches(" --> ").count(), 3);
+            assert_eq!(out.matches(" -.-> ").count(), 1);
+
+            list.clear(token);
+        });
+    }
+
+    #[test]
+    fn view() {
+        GhostToken::new(|ref mut token| {
+            let mut list = LinkedList::new();
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
+
+            let view = list.view(token);
+            let mut sum = 0;
+            for value in view {
+                sum += value;
+            }
+            assert_eq!(sum, 6);
+            assert_eq!(view.into_iter().next_back(), Some(&3));
+        });
+    }
+
     #[derive(Default)]
     struct Big([usize; 32]);
 
+    #[test]
+    fn guard() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+
+        GhostToken::new(|ref mut token| {
+            let mut list = LinkedList::new();
+            {
+                let mut guard = list.guard(token);
+                guard.push_back(Rc::clone(&value));
+                guard.push_front(Rc::clone(&value));
+                assert_eq!(guard.len(), 2);
+
+                let (list, token) = guard.parts();
+                list.push_back(Rc::clone(&value), token);
+                assert_eq!(Rc::strong_count(&value), 4);
+            }
+            assert_eq!(list.len(), 0);
+            assert_eq!(Rc::strong_count(&value), 1);
+
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                let mut guard = list.guard(token);
+                guard.push_back(Rc::clone(&value));
+                panic!("user code panicked");
+            }));
+            assert!(result.is_err());
+            assert_eq!(list.len(), 0);
+            assert_eq!(Rc::strong_count(&value), 1);
+
+            list.push_back(Rc::clone(&value), token);
+            list.close(token);
+            assert_eq!(Rc::strong_count(&value), 1);
+        });
+    }
+
+    #[test]
+    fn panic_safety() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        GhostToken::new(|ref mut token| {
+            let mut list = LinkedList::new();
+            let mut other = LinkedList::new();
+            for i in 0..5 {
+                list.push_back(i * 2, token);
+                other.push_back(i * 2 + 1, token);
+            }
+
+            let mut calls = 0;
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                let cmp = |a: &i32, b: &i32| {
+                    calls += 1;
+                    assert!(calls < 4, "comparator panicked");
+                    a.cmp(b)
+                };
+                list.merge(&mut other, cmp, token)
+            }));
+            assert!(result.is_err());
+            assert_eq!(list.len(), 10);
+            assert_eq!(other.len(), 0);
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![0, 1, 2, 4, 6, 8, 3, 5, 7, 9]
+            );
+
+            let mut calls = 0;
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                let same = |_: &i32, _: &i32| {
+                    calls += 1;
+                    assert!(calls < 3, "same panicked");
+                    true
+                };
+                list.dedup_by(same, token)
+            }));
+            assert!(result.is_err());
+            assert_eq!(list.len(), 8);
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![0, 4, 6, 8, 3, 5, 7, 9]
+            );
+
+            list.clear(token);
+        });
+    }
+
     #[test]
     fn push_back_first_big() {
         GhostToken::new(|ref mut token| {
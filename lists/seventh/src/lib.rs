@@ -0,0 +1,461 @@
+#![cfg_attr(not(test), no_std)]
+#![forbid(unsafe_code)]
+/// A doubly linked list with all nodes in one `Vec`, linked by `u32` indices.
+///
+/// Allocation size per value:
+/// sizeof: Node<T> = Option<T> + u32 + u32
+/// => Overhead of list is 8 bytes per entry, plus the `Option` tag for types without a niche
+///
+/// Pros:
+/// - No Dependencies
+/// - No unsafe & fully stable rust
+/// - Implementation is `Send` and `Sync`
+/// - No tokens required
+/// - Iteration works
+/// - Popped nodes go on a free list and are reused, the `Vec` only grows to the longest the list has been
+///
+/// Cons:
+/// - At most `u32::MAX - 1` nodes
+/// - Growing the `Vec` copies every node
+/// - Needs `alloc`
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::mem;
+
+#[cfg(feature = "invariants")]
+pub use invariants::InvariantError;
+
+/// Marks the end of a chain, never a valid index.
+const NIL: u32 = u32::MAX;
+
+pub struct LinkedList<T> {
+    len: usize,
+    nodes: Vec<Node<T>>,
+    head_tail: Option<(u32, u32)>,
+    /// Popped nodes, chained through `next`.
+    free: u32,
+    free_len: usize,
+}
+
+impl<T> LinkedList<T> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            len: 0,
+            nodes: Vec::with_capacity(capacity),
+            head_tail: None,
+            free: NIL,
+            free_len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head_tail.is_none()
+    }
+
+    /// Every node in the `Vec` counts, the ones on the free list are free.
+    pub fn memory_usage(&self) -> MemoryStats {
+        let bytes_per_node = mem::size_of::<Node<T>>();
+
+        MemoryStats {
+            nodes: self.nodes.len(),
+            bytes_per_node,
+            total_bytes: self.nodes.capacity() * bytes_per_node,
+            free_slots: self.free_len,
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            head_tail: self.head_tail,
+            len: self.len,
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head_tail.map(|(head, _)| self.value(head))
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.head_tail.map(|(_, tail)| self.value(tail))
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        let (head, _) = self.head_tail?;
+        self.node_mut(head).value.as_mut()
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        let (_, tail) = self.head_tail?;
+        self.node_mut(tail).value.as_mut()
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let new_head = self.insert(value);
+
+        let head_tail = match self.head_tail {
+            Some((head, tail)) => {
+                self.node_mut(head).prev = new_head;
+                self.node_mut(new_head).next = head;
+
+                (new_head, tail)
+            }
+            None => (new_head, new_head),
+        };
+
+        self.head_tail = Some(head_tail);
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let new_tail = self.insert(value);
+
+        let head_tail = match self.head_tail {
+            Some((head, tail)) => {
+                self.node_mut(tail).next = new_tail;
+                self.node_mut(new_tail).prev = tail;
+
+                (head, new_tail)
+            }
+            None => (new_tail, new_tail),
+        };
+
+        self.head_tail = Some(head_tail);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let (head, tail) = self.head_tail?;
+
+        self.head_tail = if head == tail {
+            None
+        } else {
+            let next = mem::replace(&mut self.node_mut(head).next, NIL);
+            self.node_mut(next).prev = NIL;
+
+            Some((next, tail))
+        };
+        self.len -= 1;
+
+        Some(self.recycle(head))
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let (head, tail) = self.head_tail?;
+
+        self.head_tail = if head == tail {
+            None
+        } else {
+            let prev = mem::replace(&mut self.node_mut(tail).prev, NIL);
+            self.node_mut(prev).next = NIL;
+
+            Some((head, prev))
+        };
+        self.len -= 1;
+
+        Some(self.recycle(tail))
+    }
+
+    /// Drops every element, the `Vec` keeps its capacity.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.head_tail = None;
+        self.free = NIL;
+        self.free_len = 0;
+        self.len = 0;
+    }
+
+    fn insert(&mut self, value: T) -> u32 {
+        if self.free != NIL {
+            let index = self.free;
+            let node = &mut self.nodes[index as usize];
+            self.free = mem::replace(&mut node.next, NIL);
+            node.value = Some(value);
+            self.free_len -= 1;
+
+            return index;
+        }
+
+        let index = u32::try_from(self.nodes.len())
+            .ok()
+            .filter(|&index| index != NIL)
+            .expect("seventh::LinkedList can hold at most u32::MAX - 1 nodes");
+
+        self.nodes.push(Node {
+            value: Some(value),
+            prev: NIL,
+            next: NIL,
+        });
+
+        index
+    }
+
+    /// Takes the value out of an unlinked node and puts the node on the free list.
+    fn recycle(&mut self, index: u32) -> T {
+        let node = &mut self.nodes[index as usize];
+
+        //  If the node still has a prev and next, they are leaked.
+        debug_assert!(node.prev == NIL);
+        debug_assert!(node.next == NIL);
+
+        let value = node.value.take().unwrap();
+        node.next = mem::replace(&mut self.free, index);
+        self.free_len += 1;
+
+        value
+    }
+
+    fn node(&self, index: u32) -> &Node<T> {
+        &self.nodes[index as usize]
+    }
+
+    fn node_mut(&mut self, index: u32) -> &mut Node<T> {
+        &mut self.nodes[index as usize]
+    }
+
+    fn value(&self, index: u32) -> &T {
+        self.node(index).value.as_ref().unwrap()
+    }
+}
+
+#[cfg(feature = "invariants")]
+impl<T> LinkedList<T> {
+    /// Walks the list and its free list, checking that the links match up, that `len` and
+    /// `free_len` are right and that every node is either linked or free.
+    pub fn check_invariants(&self) -> Result<(), InvariantError> {
+        let link = |index: u32| (index != NIL).then_some(index);
+
+        invariants::check_links(
+            self.head_tail,
+            Some(self.len),
+            |&index| {
+                let node = self.nodes.get(index as usize)?;
+                node.value.as_ref()?;
+                Some((link(node.prev), link(node.next)))
+            },
+            |a, b| a == b,
+        )?;
+
+        let mut free = self.free;
+        for _ in 0..self.free_len {
+            let Some(node) = self.nodes.get(free as usize) else {
+                return Err(InvariantError::Other(
+                    "free list is shorter than `free_len`",
+                ));
+            };
+            if node.value.is_some() {
+                return Err(InvariantError::Other("a node on the free list has a value"));
+            }
+            free = node.next;
+        }
+
+        if free != NIL {
+            Err(InvariantError::Other("free list is longer than `free_len`"))
+        } else if self.len + self.free_len != self.nodes.len() {
+            Err(InvariantError::Other(
+                "some nodes are neither linked nor free",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What a list costs in memory, see [`LinkedList::memory_usage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Nodes allocated so far, in use or not.
+    pub nodes: usize,
+    pub bytes_per_node: usize,
+    /// Everything reserved for nodes, including capacity that hasn't been handed out yet.
+    pub total_bytes: usize,
+    /// How many of `nodes` are free and will be reused before allocating again.
+    pub free_slots: usize,
+}
+
+struct Node<T> {
+    value: Option<T>,
+    prev: u32,
+    next: u32,
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+pub struct IntoIter<T>(LinkedList<T>);
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+pub struct Iter<'a, T> {
+    list: &'a LinkedList<T>,
+    head_tail: Option<(u32, u32)>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        if head != tail {
+            self.head_tail = Some((self.list.node(head).next, tail));
+        }
+        self.len -= 1;
+
+        Some(self.list.value(head))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        if head != tail {
+            self.head_tail = Some((head, self.list.node(tail).prev));
+        }
+        self.len -= 1;
+
+        Some(self.list.value(tail))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let mut list: LinkedList<_> = (1..=4).collect();
+        *list.front_mut().unwrap() = 0;
+        *list.back_mut().unwrap() = 5;
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 2, 3, 5]);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![5, 3, 2, 0]
+        );
+        assert_eq!(list.iter().len(), 4);
+        assert_eq!(list.to_vec(), vec![0, 2, 3, 5]);
+        assert_eq!(list.into_iter().rev().collect::<Vec<_>>(), vec![5, 3, 2, 0]);
+    }
+
+    #[test]
+    fn freelist() {
+        let mut list = LinkedList::new();
+
+        for i in 0..1000 {
+            list.push_back(i);
+            list.push_front(i);
+            assert_eq!(list.pop_back(), Some(i));
+        }
+        assert_eq!(list.memory_usage().nodes, 1000 + 1);
+        assert_eq!(list.memory_usage().free_slots, 1);
+
+        while list.pop_front().is_some() {}
+        assert_eq!(list.memory_usage().free_slots, 1000 + 1);
+
+        list.extend(0..1000);
+        assert_eq!(list.memory_usage().nodes, 1000 + 1);
+        assert!(list.iter().copied().eq(0..1000));
+
+        list.clear();
+        assert_eq!(list.memory_usage().nodes, 0);
+        list.push_back(1);
+        assert_eq!(list.to_vec(), vec![1]);
+    }
+
+    #[test]
+    fn node_size() {
+        // two u32 links instead of two pointers or slotmap keys
+        assert_eq!(mem::size_of::<Node<&u8>>(), mem::size_of::<&u8>() + 8);
+    }
+}
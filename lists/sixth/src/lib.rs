@@ -1,13 +1,34 @@
 #![cfg_attr(not(test), no_std)]
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::cell::Cell;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::mem;
 use ghost_cell::{GhostCell, GhostToken};
 use static_rc::StaticRcRef;
 use typed_arena::Arena;
 
+#[cfg(feature = "invariants")]
+pub use invariants::InvariantError;
+
+/// A list that owns the arena its nodes live in.
+///
+/// Pushing borrows the list for `'arena`, so once it's been used it can't be moved anymore, not
+/// even to hand its arena to the next list. Size the arena with [`with_capacity`](Self::with_capacity)
+/// instead. The arena never gives a slot back, so every push takes a new one, also after a
+/// [`clear`](Self::clear): popped nodes stay in the arena until the list is dropped.
 pub struct LinkedList<'arena, 'id, T> {
     arena: Arena<Node<'arena, 'id, T>>,
-    head_tail: GhostCell<'id, Option<(HalfNodePtr<'arena, 'id, T>, HalfNodePtr<'arena, 'id, T>)>>,
+    arena_chunks: Cell<usize>,
+    head_tail: GhostCell<'id, HeadTail<'arena, 'id, T>>,
+    /// A plain `Cell`, so the length can be read without the token. Like `head_tail` it's taken
+    /// while a [`Batch`] or [`IterMut`] is alive.
+    len: Cell<usize>,
 }
 
 impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
@@ -15,6 +36,8 @@ impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
         Self {
             head_tail: GhostCell::new(None),
             arena: Arena::new(),
+            arena_chunks: Cell::new(1),
+            len: Cell::new(0),
         }
     }
 
@@ -22,15 +45,44 @@ impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
         Self {
             head_tail: GhostCell::new(None),
             arena: Arena::with_capacity(capacity),
+            arena_chunks: Cell::new(1),
+            len: Cell::new(0),
         }
     }
 
-    pub fn len(&'arena self, token: &GhostToken<'id>) -> usize {
-        self.iter(token).count()
+    pub fn len(&self) -> usize {
+        self.len.get()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len.get() == 0
+    }
+
+    /// Number of nodes allocated in the arena, including popped nodes (the arena never frees).
+    pub fn arena_len(&self) -> usize {
+        self.arena.len()
+    }
+
+    /// Number of nodes the arena can hold before it has to allocate a new chunk.
+    pub fn arena_capacity(&self) -> usize {
+        self.arena.len() + self.arena.uninitialized_array().len()
+    }
+
+    /// Popped nodes are never reused, so `free_slots` is always `0`.
+    pub fn memory_usage(&self) -> MemoryStats {
+        let bytes_per_node = mem::size_of::<Node<'arena, 'id, T>>();
+
+        MemoryStats {
+            nodes: self.arena_len(),
+            bytes_per_node,
+            total_bytes: self.arena_capacity() * bytes_per_node,
+            free_slots: 0,
+        }
     }
 
-    pub fn is_empty(&'arena self, token: &GhostToken<'id>) -> bool {
-        self.head_tail.borrow(token).is_none()
+    /// Number of chunks the arena has allocated so far.
+    pub fn arena_chunks(&self) -> usize {
+        self.arena_chunks.get()
     }
 
     pub fn iter<'a>(&'a self, token: &'a GhostToken<'id>) -> Iter<'a, 'arena, 'id, T>
@@ -43,61 +95,294 @@ impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
             .as_ref()
             .map(|head_tail| (&*head_tail.0, &*head_tail.1));
 
-        Iter { token, head_tail }
+        Iter {
+            token,
+            head_tail,
+            len: self.len.get(),
+        }
+    }
+
+    /// Iterates over the elements front to back, handing out mutable references one at a time.
+    ///
+    /// See [`IterMut`] for why this isn't an [`Iterator`].
+    pub fn iter_mut<'a>(&'a self, token: &'a mut GhostToken<'id>) -> IterMut<'a, 'arena, 'id, T> {
+        let rest = self.head_tail.borrow_mut(token).take();
+
+        IterMut {
+            list: self,
+            token,
+            front: None,
+            rest,
+            back: None,
+            len: self.len.take(),
+        }
+    }
+
+    /// Pairs the list with `token`, so it can be iterated in a `for` loop.
+    pub fn view<'a>(&'a self, token: &'a GhostToken<'id>) -> ListView<'a, 'arena, 'id, T>
+    where
+        'arena: 'a,
+    {
+        ListView { list: self, token }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self, token: &GhostToken<'id>) -> alloc::vec::Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter(token).cloned().collect()
+    }
+
+    pub fn eq_with(&self, other: &Self, token: &GhostToken<'id>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter(token).eq(other.iter(token))
+    }
+
+    /// Compares the elements lexicographically, like slices.
+    pub fn cmp_with(&self, other: &Self, token: &GhostToken<'id>) -> Ordering
+    where
+        T: Ord,
+    {
+        self.iter(token).cmp(other.iter(token))
+    }
+
+    /// Feeds the length and then the elements in order to `state`, like `Hash` would.
+    pub fn hash_with<H: Hasher>(&self, token: &GhostToken<'id>, state: &mut H)
+    where
+        T: Hash,
+    {
+        self.len().hash(state);
+
+        for value in self.iter(token) {
+            value.hash(state);
+        }
+    }
+
+    pub fn front<'a>(&'a self, token: &'a GhostToken<'id>) -> Option<&'a T>
+    where
+        'arena: 'a,
+    {
+        let (head, _) = self.head_tail.borrow(token).as_ref()?;
+
+        Some(head.borrow(token).value.as_ref().unwrap())
+    }
+
+    pub fn back<'a>(&'a self, token: &'a GhostToken<'id>) -> Option<&'a T>
+    where
+        'arena: 'a,
+    {
+        let (_, tail) = self.head_tail.borrow(token).as_ref()?;
+
+        Some(tail.borrow(token).value.as_ref().unwrap())
+    }
+
+    /// Calls `f` on the first element.
+    ///
+    /// The head lives behind the token too, so the reference can't outlive a [`batch`](Self::batch).
+    pub fn front_mut<R>(
+        &'arena self,
+        token: &mut GhostToken<'id>,
+        f: impl FnOnce(&mut T) -> R,
+    ) -> Option<R> {
+        self.batch(token, |batch| batch.front_mut().map(f))
+    }
+
+    /// Calls `f` on the last element, see [`front_mut`](Self::front_mut).
+    pub fn back_mut<R>(
+        &'arena self,
+        token: &mut GhostToken<'id>,
+        f: impl FnOnce(&mut T) -> R,
+    ) -> Option<R> {
+        self.batch(token, |batch| batch.back_mut().map(f))
     }
 
     pub fn push_front(&'arena self, value: T, token: &mut GhostToken<'id>) {
-        let (one, two) = self.new_halves(value);
+        self.batch(token, |batch| batch.push_front(value))
+    }
+
+    pub fn push_back(&'arena self, value: T, token: &mut GhostToken<'id>) {
+        self.batch(token, |batch| batch.push_back(value))
+    }
+
+    /// Pushes every element of `iter` to the back of the list in one [`batch`](Self::batch).
+    ///
+    /// Room for the elements is reserved in the arena up front, going by the lower bound of the
+    /// size hint, so the arena grows at most once for them.
+    pub fn extend_from_iter<I>(&'arena self, iter: I, token: &mut GhostToken<'id>)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        let additional = iter.size_hint().0;
+        if additional > self.arena.uninitialized_array().len() {
+            self.arena.reserve_extend(additional);
+            self.arena_chunks.set(self.arena_chunks.get() + 1);
+        }
+
+        self.batch(token, |batch| {
+            for value in iter {
+                batch.push_back(value);
+            }
+        })
+    }
+
+    pub fn pop_front(&'arena self, token: &mut GhostToken<'id>) -> Option<T> {
+        self.batch(token, |batch| batch.pop_front())
+    }
+
+    pub fn pop_back(&'arena self, token: &mut GhostToken<'id>) -> Option<T> {
+        self.batch(token, |batch| batch.pop_back())
+    }
+
+    /// Pops the first element, but only if `pred` returns `true` for it.
+    pub fn pop_front_if<F>(&'arena self, pred: F, token: &mut GhostToken<'id>) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        self.batch(token, |batch| {
+            if pred(batch.front_mut()?) {
+                batch.pop_front()
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Pops the last element, but only if `pred` returns `true` for it.
+    pub fn pop_back_if<F>(&'arena self, pred: F, token: &mut GhostToken<'id>) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        self.batch(token, |batch| {
+            if pred(batch.back_mut()?) {
+                batch.pop_back()
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Swaps the elements at `i` and `j` by relinking their nodes, see [`Batch::swap`].
+    pub fn swap(&'arena self, i: usize, j: usize, token: &mut GhostToken<'id>) {
+        // checked before the batch takes the nodes, a panic in there would lose them
+        assert!(i < self.len() && j < self.len(), "index out of bounds");
+
+        self.batch(token, |batch| batch.swap(i, j))
+    }
+
+    /// Runs several pushes and pops against the list while only borrowing its head and tail once.
+    ///
+    /// If `f` panics the nodes linked so far are left in the arena but the list is empty.
+    pub fn batch<R>(
+        &'arena self,
+        token: &mut GhostToken<'id>,
+        f: impl FnOnce(&mut Batch<'_, 'arena, 'id, T>) -> R,
+    ) -> R {
+        let head_tail = self.head_tail.borrow_mut(token).take();
+
+        let mut batch = Batch {
+            list: self,
+            token,
+            head_tail,
+            len: self.len.take(),
+        };
+        let ret = f(&mut batch);
+
+        *self.head_tail.borrow_mut(batch.token) = batch.head_tail;
+        self.len.set(batch.len);
+
+        ret
+    }
 
-        let head_tail = if let Some((head, tail)) = self.head_tail.borrow_mut(token).take() {
+    pub fn clear(&'arena self, token: &mut GhostToken<'id>) {
+        while self.pop_back(token).is_some() {}
+    }
+
+    fn new_halves(
+        &'arena self,
+        value: T,
+    ) -> (HalfNodePtr<'arena, 'id, T>, HalfNodePtr<'arena, 'id, T>) {
+        // the current chunk is full, so the arena is about to allocate a new one
+        if self.arena.uninitialized_array().is_empty() {
+            self.arena_chunks.set(self.arena_chunks.get() + 1);
+        }
+
+        let node_ptr = self.arena.alloc(Node {
+            value: Some(value),
+            prev: None,
+            next: None,
+        });
+
+        let full = FullNodePtr::new(GhostNode::from_mut(node_ptr));
+
+        StaticRcRef::split::<1, 1>(full)
+    }
+
+    fn link_front(
+        head_tail: &mut HeadTail<'arena, 'id, T>,
+        one: HalfNodePtr<'arena, 'id, T>,
+        two: HalfNodePtr<'arena, 'id, T>,
+        token: &mut GhostToken<'id>,
+    ) {
+        *head_tail = Some(if let Some((head, tail)) = head_tail.take() {
             head.borrow_mut(token).prev = Some(one);
             two.borrow_mut(token).next = Some(head);
 
             (two, tail)
         } else {
             (one, two)
-        };
-
-        *self.head_tail.borrow_mut(token) = Some(head_tail)
+        });
     }
 
-    pub fn push_back(&'arena self, value: T, token: &mut GhostToken<'id>) {
-        let (one, two) = self.new_halves(value);
-
-        let head_tail = if let Some((head, tail)) = self.head_tail.borrow_mut(token).take() {
+    fn link_back(
+        head_tail: &mut HeadTail<'arena, 'id, T>,
+        one: HalfNodePtr<'arena, 'id, T>,
+        two: HalfNodePtr<'arena, 'id, T>,
+        token: &mut GhostToken<'id>,
+    ) {
+        *head_tail = Some(if let Some((head, tail)) = head_tail.take() {
             tail.borrow_mut(token).next = Some(one);
             two.borrow_mut(token).prev = Some(tail);
 
             (head, two)
         } else {
             (one, two)
-        };
-
-        *self.head_tail.borrow_mut(token) = Some(head_tail)
+        });
     }
 
-    pub fn pop_front(&'arena self, token: &mut GhostToken<'id>) -> Option<T> {
-        let (head, tail) = self.head_tail.borrow_mut(token).take()?;
+    /// Detaches the head node and returns both halves pointing to it.
+    fn unlink_front(
+        head_tail: &mut HeadTail<'arena, 'id, T>,
+        token: &mut GhostToken<'id>,
+    ) -> Option<(HalfNodePtr<'arena, 'id, T>, HalfNodePtr<'arena, 'id, T>)> {
+        let (head, tail) = head_tail.take()?;
 
         // when there is only one element in the list
         if StaticRcRef::as_ptr(&head) == StaticRcRef::as_ptr(&tail) {
-            return Some(Self::into_inner(head, tail, token));
+            return Some((head, tail));
         }
 
         let next = head.borrow_mut(token).next.take().unwrap();
         let other_head = next.borrow_mut(token).prev.take().unwrap();
 
-        *self.head_tail.borrow_mut(token) = Some((next, tail));
+        *head_tail = Some((next, tail));
 
-        Some(Self::into_inner(head, other_head, token))
+        Some((head, other_head))
     }
 
-    pub fn pop_back(&'arena self, token: &mut GhostToken<'id>) -> Option<T> {
-        let (head, tail) = self.head_tail.borrow_mut(token).take()?;
+    /// Detaches the tail node and returns both halves pointing to it.
+    fn unlink_back(
+        head_tail: &mut HeadTail<'arena, 'id, T>,
+        token: &mut GhostToken<'id>,
+    ) -> Option<(HalfNodePtr<'arena, 'id, T>, HalfNodePtr<'arena, 'id, T>)> {
+        let (head, tail) = head_tail.take()?;
 
         // when there is only one element in the list
         if StaticRcRef::as_ptr(&head) == StaticRcRef::as_ptr(&tail) {
-            return Some(Self::into_inner(head, tail, token));
+            return Some((tail, head));
         }
 
         let prev = tail
@@ -111,28 +396,9 @@ impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
             .take()
             .expect("Non-tail should have a right node");
 
-        *self.head_tail.borrow_mut(token) = Some((head, prev));
-
-        Some(Self::into_inner(tail, other_tail, token))
-    }
+        *head_tail = Some((head, prev));
 
-    pub fn clear(&'arena self, token: &mut GhostToken<'id>) {
-        while self.pop_back(token).is_some() {}
-    }
-
-    fn new_halves(
-        &'arena self,
-        value: T,
-    ) -> (HalfNodePtr<'arena, 'id, T>, HalfNodePtr<'arena, 'id, T>) {
-        let node_ptr = self.arena.alloc(Node {
-            value: Some(value),
-            prev: None,
-            next: None,
-        });
-
-        let full = FullNodePtr::new(GhostNode::from_mut(node_ptr));
-
-        StaticRcRef::split::<1, 1>(full)
+        Some((tail, other_tail))
     }
 
     fn into_inner(
@@ -152,6 +418,194 @@ impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
     }
 }
 
+#[cfg(feature = "invariants")]
+impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
+    /// Walks the list and checks that the links match up, that `len` is right and that no node in
+    /// it has been popped.
+    pub fn check_invariants(&self, token: &GhostToken<'id>) -> Result<(), InvariantError> {
+        invariants::check_links(
+            self.head_tail
+                .borrow(token)
+                .as_ref()
+                .map(|(head, tail)| (head, tail)),
+            Some(self.len()),
+            |&node| {
+                let node = node.borrow(token);
+                node.value.as_ref()?;
+                Some((node.prev.as_ref(), node.next.as_ref()))
+            },
+            |a, b| StaticRcRef::as_ptr(a) == StaticRcRef::as_ptr(b),
+        )?;
+
+        Ok(())
+    }
+}
+
+pub struct Batch<'b, 'arena, 'id, T> {
+    list: &'arena LinkedList<'arena, 'id, T>,
+    token: &'b mut GhostToken<'id>,
+    head_tail: HeadTail<'arena, 'id, T>,
+    len: usize,
+}
+
+impl<'b, 'arena, 'id, T> Batch<'b, 'arena, 'id, T> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head_tail.is_none()
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        let (head, _) = self.head_tail.as_ref()?;
+
+        Some(head.borrow_mut(self.token).value.as_mut().unwrap())
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        let (_, tail) = self.head_tail.as_ref()?;
+
+        Some(tail.borrow_mut(self.token).value.as_mut().unwrap())
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let (one, two) = self.list.new_halves(value);
+
+        LinkedList::link_front(&mut self.head_tail, one, two, self.token);
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let (one, two) = self.list.new_halves(value);
+
+        LinkedList::link_back(&mut self.head_tail, one, two, self.token);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let (left, right) = LinkedList::unlink_front(&mut self.head_tail, self.token)?;
+        self.len -= 1;
+
+        Some(LinkedList::into_inner(left, right, self.token))
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let (left, right) = LinkedList::unlink_back(&mut self.head_tail, self.token)?;
+        self.len -= 1;
+
+        Some(LinkedList::into_inner(left, right, self.token))
+    }
+
+    /// Swaps the elements at `i` and `j` by relinking their nodes, the values stay where they are.
+    ///
+    /// Takes `O(max(i, j))` steps, panics if either index is out of bounds.
+    pub fn swap(&mut self, i: usize, j: usize) {
+        assert!(i < self.len && j < self.len, "index out of bounds");
+        let (i, j) = if i <= j { (i, j) } else { (j, i) };
+
+        let mut prefix = None;
+        let mut middle = None;
+
+        // split the list into prefix, node i, middle, node j and the rest
+        for _ in 0..i {
+            let (one, two) = LinkedList::unlink_front(&mut self.head_tail, self.token)
+                .expect("index out of bounds");
+            LinkedList::link_back(&mut prefix, one, two, self.token);
+        }
+        let node_i =
+            LinkedList::unlink_front(&mut self.head_tail, self.token).expect("index out of bounds");
+
+        if i == j {
+            LinkedList::link_front(&mut self.head_tail, node_i.0, node_i.1, self.token);
+        } else {
+            for _ in i + 1..j {
+                let (one, two) = LinkedList::unlink_front(&mut self.head_tail, self.token)
+                    .expect("index out of bounds");
+                LinkedList::link_back(&mut middle, one, two, self.token);
+            }
+            let node_j = LinkedList::unlink_front(&mut self.head_tail, self.token)
+                .expect("index out of bounds");
+
+            // and put it back together front to back, starting from the rest
+            LinkedList::link_front(&mut self.head_tail, node_i.0, node_i.1, self.token);
+            while let Some((one, two)) = LinkedList::unlink_back(&mut middle, self.token) {
+                LinkedList::link_front(&mut self.head_tail, one, two, self.token);
+            }
+            LinkedList::link_front(&mut self.head_tail, node_j.0, node_j.1, self.token);
+        }
+
+        while let Some((one, two)) = LinkedList::unlink_back(&mut prefix, self.token) {
+            LinkedList::link_front(&mut self.head_tail, one, two, self.token);
+        }
+    }
+}
+
+/// Mutable iteration over a [`LinkedList`], see [`LinkedList::iter_mut`].
+///
+/// Every node can only be reached through the token, so a `&mut T` borrows the token along with
+/// the iterator and has to be dropped before asking for the next one. That rules out implementing
+/// [`Iterator`], use `while let Some(value) = iter.next() { .. }` instead.
+///
+/// Visited nodes are unlinked from the list and linked onto one of two temporary lists, the list is
+/// put back together when the iterator is dropped. Like with [`LinkedList::batch`], the list is empty
+/// until then, and stays empty if the iterator is leaked.
+pub struct IterMut<'a, 'arena, 'id, T> {
+    list: &'a LinkedList<'arena, 'id, T>,
+    token: &'a mut GhostToken<'id>,
+    /// Nodes handed out by `next`, in list order.
+    front: HeadTail<'arena, 'id, T>,
+    rest: HeadTail<'arena, 'id, T>,
+    /// Nodes handed out by `next_back`, in list order.
+    back: HeadTail<'arena, 'id, T>,
+    len: usize,
+}
+
+impl<'a, 'arena, 'id, T> IterMut<'a, 'arena, 'id, T> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&mut T> {
+        let (one, two) = LinkedList::unlink_front(&mut self.rest, self.token)?;
+        LinkedList::link_back(&mut self.front, one, two, self.token);
+
+        let (_, tail) = self.front.as_ref()?;
+        Some(tail.borrow_mut(self.token).value.as_mut().unwrap())
+    }
+
+    pub fn next_back(&mut self) -> Option<&mut T> {
+        let (one, two) = LinkedList::unlink_back(&mut self.rest, self.token)?;
+        LinkedList::link_front(&mut self.back, one, two, self.token);
+
+        let (head, _) = self.back.as_ref()?;
+        Some(head.borrow_mut(self.token).value.as_mut().unwrap())
+    }
+}
+
+impl<'a, 'arena, 'id, T> Drop for IterMut<'a, 'arena, 'id, T> {
+    fn drop(&mut self) {
+        while let Some((one, two)) = LinkedList::unlink_back(&mut self.front, self.token) {
+            LinkedList::link_front(&mut self.rest, one, two, self.token);
+        }
+        while let Some((one, two)) = LinkedList::unlink_front(&mut self.back, self.token) {
+            LinkedList::link_back(&mut self.rest, one, two, self.token);
+        }
+
+        *self.list.head_tail.borrow_mut(self.token) = self.rest.take();
+        self.list.len.set(self.len);
+    }
+}
+
+/// What a list costs in memory, see [`LinkedList::memory_usage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Nodes allocated so far, in use or not.
+    pub nodes: usize,
+    pub bytes_per_node: usize,
+    /// Everything reserved for nodes, including capacity that hasn't been handed out yet.
+    pub total_bytes: usize,
+    /// How many of `nodes` are free and will be reused before allocating again.
+    pub free_slots: usize,
+}
+
 pub struct Node<'arena, 'id, T> {
     value: Option<T>,
     prev: Option<HalfNodePtr<'arena, 'id, T>>,
@@ -162,10 +616,39 @@ type GhostNode<'arena, 'id, T> = GhostCell<'id, Node<'arena, 'id, T>>;
 
 type HalfNodePtr<'arena, 'id, T> = StaticRcRef<'arena, GhostNode<'arena, 'id, T>, 1, 2>;
 type FullNodePtr<'arena, 'id, T> = StaticRcRef<'arena, GhostNode<'arena, 'id, T>, 2, 2>;
+type HeadTail<'arena, 'id, T> = Option<(HalfNodePtr<'arena, 'id, T>, HalfNodePtr<'arena, 'id, T>)>;
+
+/// A list and its token, see [`LinkedList::view`].
+pub struct ListView<'a, 'arena, 'id, T> {
+    list: &'a LinkedList<'arena, 'id, T>,
+    token: &'a GhostToken<'id>,
+}
+
+impl<'a, 'arena, 'id, T> Clone for ListView<'a, 'arena, 'id, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, 'arena, 'id, T> Copy for ListView<'a, 'arena, 'id, T> {}
+
+impl<'a, 'arena, 'id, T> IntoIterator for ListView<'a, 'arena, 'id, T>
+where
+    'arena: 'a,
+{
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, 'arena, 'id, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter(self.token)
+    }
+}
 
 pub struct Iter<'a, 'arena, 'id, T> {
     token: &'a GhostToken<'id>,
     head_tail: Option<(&'a GhostNode<'arena, 'id, T>, &'a GhostNode<'arena, 'id, T>)>,
+    len: usize,
 }
 
 impl<'a, 'arena, 'id, T> Iterator for Iter<'a, 'arena, 'id, T>
@@ -184,9 +667,18 @@ where
         } else {
             self.head_tail = None;
         }
+        self.len -= 1;
 
         Some(node.value.as_ref().unwrap())
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
 }
 
 impl<'a, 'arena, 'id, T> DoubleEndedIterator for Iter<'a, 'arena, 'id, T>
@@ -203,11 +695,16 @@ where
         } else {
             self.head_tail = None;
         }
+        self.len -= 1;
 
         Some(node.value.as_ref().unwrap())
     }
 }
 
+impl<'a, 'arena, 'id, T> ExactSizeIterator for Iter<'a, 'arena, 'id, T> where 'arena: 'a {}
+
+impl<'a, 'arena, 'id, T> FusedIterator for Iter<'a, 'arena, 'id, T> where 'arena: 'a {}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -259,6 +756,253 @@ mod test {
         });
     }
 
+    #[test]
+    fn arena_stats() {
+        GhostToken::new(|ref mut token| {
+            let list = LinkedList::with_capacity(4);
+
+            assert_eq!(list.arena_len(), 0);
+            assert_eq!(list.arena_capacity(), 4);
+            assert_eq!(list.arena_chunks(), 1);
+
+            for i in 0..4 {
+                list.push_back(i, token);
+            }
+
+            assert_eq!(list.arena_len(), 4);
+            assert_eq!(list.arena_capacity(), 4);
+            assert_eq!(list.arena_chunks(), 1);
+
+            list.push_back(4, token);
+            list.pop_front(token);
+
+            assert_eq!(list.arena_len(), 5);
+            assert_eq!(list.arena_capacity(), 4 + 8);
+            assert_eq!(list.arena_chunks(), 2);
+        });
+    }
+
+    #[test]
+    fn extend_from_iter() {
+        GhostToken::new(|ref mut token| {
+            let list = LinkedList::with_capacity(4);
+            list.push_back(-1, token);
+
+            list.extend_from_iter(0..1000, token);
+            assert_eq!(list.len(), 1001);
+            assert_eq!(list.arena_chunks(), 2);
+
+            assert_eq!(list.iter(token).next_back(), Some(&999));
+
+            // without a size hint to go by, the arena doubles its way up
+            let list = LinkedList::with_capacity(4);
+            list.extend_from_iter((0..1000).filter(|_| true), token);
+            assert_eq!(list.len(), 1000);
+            assert_eq!(list.arena_chunks(), 8);
+        });
+    }
+
+    #[test]
+    fn batch() {
+        GhostToken::new(|ref mut token| {
+            let list = LinkedList::new();
+            list.push_back(0, token);
+
+            let popped = list.batch(token, |batch| {
+                for i in 1..=3 {
+                    batch.push_back(i);
+                }
+                batch.push_front(-1);
+                assert_eq!(batch.len(), 5);
+
+                batch.pop_front()
+            });
+
+            assert_eq!(popped, Some(-1));
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![0, 1, 2, 3]
+            );
+            assert_eq!(list.len(), 4);
+
+            list.batch(token, |batch| while batch.pop_back().is_some() {});
+            assert!(list.is_empty());
+
+            // a panicking batch leaves the list empty, its length included
+            list.push_back(0, token);
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                list.batch(token, |batch| {
+                    batch.push_back(1);
+                    panic!("batch panicked");
+                })
+            }));
+            assert!(result.is_err());
+            assert_eq!(list.len(), 0);
+            assert!(list.iter(token).next().is_none());
+        });
+    }
+
+    #[test]
+    fn front_back() {
+        GhostToken::new(|ref mut token| {
+            let list = LinkedList::new();
+
+            assert_eq!(list.front(token), None);
+            assert_eq!(list.back_mut(token, |v| *v), None);
+
+            list.push_back(1, token);
+            assert_eq!(list.front(token), Some(&1));
+            assert_eq!(list.back(token), Some(&1));
+
+            list.push_back(2, token);
+            list.front_mut(token, |v| *v = 10);
+            assert_eq!(list.back_mut(token, |v| std::mem::replace(v, 12)), Some(2));
+            assert_eq!(list.front(token), Some(&10));
+            assert_eq!(list.back(token), Some(&12));
+
+            list.batch(token, |batch| *batch.front_mut().unwrap() += 1);
+            assert_eq!(list.front(token), Some(&11));
+        });
+    }
+
+    #[test]
+    fn compare() {
+        GhostToken::new(|ref mut token| {
+            let list = LinkedList::new();
+            let other = LinkedList::new();
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
+
+            assert!(!list.eq_with(&other, token));
+            assert_eq!(other.cmp_with(&list, token), Ordering::Less);
+
+            for i in [1, 2, 4] {
+                other.push_back(i, token);
+            }
+            assert_eq!(other.cmp_with(&list, token), Ordering::Greater);
+
+            other.pop_back(token);
+            other.push_back(3, token);
+            assert!(list.eq_with(&other, token));
+            assert_eq!(list.cmp_with(&other, token), Ordering::Equal);
+        });
+    }
+
+    #[test]
+    fn hash_with() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash<'id>(list: &LinkedList<'_, 'id, i32>, token: &GhostToken<'id>) -> u64 {
+            let mut state = DefaultHasher::new();
+            list.hash_with(token, &mut state);
+            state.finish()
+        }
+
+        GhostToken::new(|ref mut token| {
+            let list = LinkedList::new();
+            let other = LinkedList::new();
+
+            for i in [1, 2] {
+                list.push_back(i, token);
+                other.push_front(i, token);
+            }
+            assert_ne!(hash(&list, token), hash(&other, token));
+
+            other.pop_back(token);
+            other.push_front(1, token);
+            assert_eq!(hash(&list, token), hash(&other, token));
+        });
+    }
+
+    #[test]
+    fn swap() {
+        GhostToken::new(|ref mut token| {
+            let list = LinkedList::new();
+
+            for i in 0..6 {
+                list.push_back(i, token);
+            }
+            let arena_len = list.arena_len();
+
+            list.swap(1, 4, token);
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![0, 4, 2, 3, 1, 5]
+            );
+
+            list.swap(5, 0, token);
+            list.swap(2, 3, token);
+            list.swap(3, 3, token);
+            assert_eq!(
+                list.iter(token).copied().collect::<Vec<_>>(),
+                vec![5, 4, 3, 2, 1, 0]
+            );
+            assert_eq!(
+                list.iter(token).rev().copied().collect::<Vec<_>>(),
+                vec![0, 1, 2, 3, 4, 5]
+            );
+
+            assert_eq!(list.arena_len(), arena_len);
+        });
+    }
+
+    #[test]
+    fn swap_out_of_bounds() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        GhostToken::new(|ref mut token| {
+            let list = LinkedList::new();
+
+            for i in 0..3 {
+                list.push_back(i, token);
+            }
+
+            let swap = catch_unwind(AssertUnwindSafe(|| list.swap(1, 3, token)));
+            assert!(swap.is_err());
+            let swap = catch_unwind(AssertUnwindSafe(|| list.swap(5, 0, token)));
+            assert!(swap.is_err());
+
+            assert_eq!(list.len(), 3);
+            assert_eq!(list.iter(token).copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec() {
+        GhostToken::new(|ref mut token| {
+            let list = LinkedList::new();
+
+            assert_eq!(list.to_vec(token), Vec::<i32>::new());
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
+            assert_eq!(list.to_vec(token), vec![1, 2, 3]);
+        });
+    }
+
+    #[test]
+    fn memory_usage() {
+        GhostToken::new(|ref mut token| {
+            let list = LinkedList::with_capacity(4);
+            let stats = list.memory_usage();
+            assert_eq!(stats.nodes, 0);
+            assert_eq!(stats.total_bytes, 4 * stats.bytes_per_node);
+
+            list.push_back(1, token);
+            list.push_back(2, token);
+            list.pop_front(token);
+
+            let stats = list.memory_usage();
+            assert_eq!(stats.nodes, 2);
+            assert_eq!(stats.free_slots, 0);
+            assert_eq!(stats.bytes_per_node, std::mem::size_of::<Node<i32>>());
+        });
+    }
+
     #[test]
     pub fn iter() {
         GhostToken::new(|ref mut token| {
@@ -272,7 +1016,72 @@ mod test {
             assert_eq!(
                 list.iter(token).copied().collect::<Vec<_>>(),
                 vec![1, 2, 3, 4]
-            )
+            );
+
+            let mut iter = list.iter(token);
+            assert_eq!(iter.len(), 4);
+            iter.next();
+            iter.next_back();
+            assert_eq!(iter.size_hint(), (2, Some(2)));
+            iter.next();
+            iter.next();
+            assert_eq!(iter.len(), 0);
+            assert_eq!(iter.next(), None);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn iter_mut() {
+        GhostToken::new(|ref mut token| {
+            let list = LinkedList::new();
+
+            for i in 0..5 {
+                list.push_back(i, token);
+            }
+
+            let mut iter = list.iter_mut(token);
+            while let Some(value) = iter.next() {
+                *value *= 10;
+            }
+            assert_eq!(list.len(), 0);
+            drop(iter);
+            assert_eq!(list.to_vec(token), vec![0, 10, 20, 30, 40]);
+            assert_eq!(list.len(), 5);
+
+            // stopping halfway from both ends still leaves the list in order
+            let mut iter = list.iter_mut(token);
+            *iter.next().unwrap() += 1;
+            *iter.next_back().unwrap() += 2;
+            *iter.next().unwrap() += 3;
+            drop(iter);
+            assert_eq!(list.to_vec(token), vec![1, 13, 20, 30, 42]);
+            assert_eq!(list.back(token), Some(&42));
+
+            list.push_front(-1, token);
+            assert_eq!(list.iter(token).next_back(), Some(&42));
+
+            let empty = LinkedList::<i32>::new();
+            assert!(empty.iter_mut(token).next().is_none());
+        });
+    }
+
+    #[test]
+    fn view() {
+        GhostToken::new(|ref mut token| {
+            let list = LinkedList::new();
+
+            for i in 1..=3 {
+                list.push_back(i, token);
+            }
+
+            let view = list.view(token);
+            let mut sum = 0;
+            for value in view {
+                sum += value;
+            }
+            assert_eq!(sum, 6);
+            assert_eq!(view.into_iter().next_back(), Some(&3));
         });
     }
 }
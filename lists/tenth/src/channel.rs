@@ -0,0 +1,189 @@
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use futures_core::Stream;
+
+use crate::LinkedList;
+
+/// Creates an unbounded channel that queues the sent values on a shared [`LinkedList`].
+///
+/// Any number of [`ListSender`]s push to the back of the list, the single [`ListChannel`] is a
+/// `Stream` popping from its front. The stream ends once all senders are dropped and the list is
+/// empty.
+pub fn channel<T>() -> (ListSender<T>, ListChannel<T>) {
+    let shared = Arc::new(Shared {
+        list: LinkedList::new(),
+        waker: Mutex::new(None),
+        senders: AtomicUsize::new(1),
+        closed: AtomicBool::new(false),
+    });
+
+    (
+        ListSender {
+            shared: shared.clone(),
+        },
+        ListChannel { shared },
+    )
+}
+
+struct Shared<T> {
+    list: LinkedList<T>,
+    waker: Mutex<Option<Waker>>,
+    senders: AtomicUsize,
+    closed: AtomicBool,
+}
+
+impl<T> Shared<T> {
+    fn wake(&self) {
+        let waker = self.waker.lock().unwrap().take();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+pub struct ListSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> ListSender<T> {
+    /// Queues `value` and wakes the receiver, handing `value` back if the receiver was dropped.
+    pub fn send(&self, value: T) -> Result<(), T> {
+        if self.shared.closed.load(Ordering::Acquire) {
+            return Err(value);
+        }
+
+        self.shared.list.push_back(value);
+        self.shared.wake();
+        Ok(())
+    }
+}
+
+impl<T> Clone for ListSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.senders.fetch_add(1, Ordering::Relaxed);
+
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for ListSender<T> {
+    fn drop(&mut self) {
+        if self.shared.senders.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.shared.wake();
+        }
+    }
+}
+
+pub struct ListChannel<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> ListChannel<T> {
+    /// The number of values sent but not received yet.
+    pub fn len(&self) -> usize {
+        self.shared.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shared.list.is_empty()
+    }
+}
+
+impl<T> Stream for ListChannel<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        if let Some(value) = self.shared.list.pop_front() {
+            return Poll::Ready(Some(value));
+        }
+
+        // register before looking again, a send in between would otherwise find no waker
+        *self.shared.waker.lock().unwrap() = Some(cx.waker().clone());
+
+        // the senders are counted before the last look, so a value sent right before the last
+        // sender was dropped is still found
+        let closed = self.shared.senders.load(Ordering::Acquire) == 0;
+
+        match self.shared.list.pop_front() {
+            Some(value) => Poll::Ready(Some(value)),
+            None if closed => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl<T> Drop for ListChannel<T> {
+    fn drop(&mut self) {
+        self.shared.closed.store(true, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::thread;
+
+    fn poll<T>(rx: &mut ListChannel<T>) -> Poll<Option<T>> {
+        Pin::new(rx).poll_next(&mut Context::from_waker(Waker::noop()))
+    }
+
+    #[test]
+    fn send_recv() {
+        let (tx, mut rx) = channel();
+        assert_eq!(poll(&mut rx), Poll::Pending);
+
+        let other = tx.clone();
+        tx.send(1).unwrap();
+        other.send(2).unwrap();
+        assert_eq!(rx.len(), 2);
+        assert_eq!(poll(&mut rx), Poll::Ready(Some(1)));
+
+        drop(tx);
+        assert_eq!(poll(&mut rx), Poll::Ready(Some(2)));
+        assert_eq!(poll(&mut rx), Poll::Pending);
+
+        drop(other);
+        assert_eq!(poll(&mut rx), Poll::Ready(None));
+
+        let (tx, rx) = channel();
+        drop(rx);
+        assert_eq!(tx.send('a'), Err('a'));
+    }
+
+    #[test]
+    fn threads() {
+        let (tx, mut rx) = channel();
+
+        let handles: Vec<_> = (0..4)
+            .map(|t| {
+                let tx = tx.clone();
+                thread::spawn(move || {
+                    for i in 0..1000 {
+                        tx.send(t * 1000 + i).unwrap();
+                    }
+                })
+            })
+            .collect();
+        drop(tx);
+
+        let mut received = Vec::new();
+        loop {
+            match poll(&mut rx) {
+                Poll::Ready(Some(value)) => received.push(value),
+                Poll::Ready(None) => break,
+                Poll::Pending => thread::yield_now(),
+            }
+        }
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        received.sort();
+        assert_eq!(received, (0..4000).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,548 @@
+#![forbid(unsafe_code)]
+/// `first`, but thread-safe: `Arc` and `Mutex` instead of `Rc` and `RefCell`.
+///
+/// The list sits behind one `Mutex`, so every method takes `&self` and blocks until no other
+/// thread is using the list. Share it with an `Arc<LinkedList<T>>`. [`lock`](LinkedList::lock)
+/// keeps the list locked for as long as the returned [`Cursor`] lives, so a walk over the list
+/// can't be interleaved with another thread's pushes and pops.
+///
+/// Node mutexes are only ever locked while holding the list lock, they are never contended, just
+/// paid for.
+///
+/// Allocation size per value:
+/// sizeof: ArcInner<Mutex<Node<T>>> = usize + usize + Mutex + T + usize + usize
+/// => Overhead of list is around 40 bytes per entry (the size of `Mutex` depends on the platform)
+///
+/// Pros:
+/// - No Dependencies
+/// - No unsafe & fully stable rust
+/// - Implementation is `Send` and `Sync`, every method takes `&self`
+/// - No tokens required
+/// - Doubles as an async multi-producer channel with the `futures` feature
+///
+/// Cons:
+/// - Needs `std` for `Mutex`
+/// - Every operation locks the list and every node it touches
+/// - One lock for the whole list, threads take turns instead of working in parallel
+/// - No `.iter()` for the same reasons as `first`, walk with a [`Cursor`] instead
+/// - We have to manually drop() pointers when removing nodes, just like `first`
+/// - Allocates every node on the heap individually
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+#[cfg(feature = "futures")]
+mod channel;
+
+#[cfg(feature = "futures")]
+pub use channel::{channel, ListChannel, ListSender};
+
+#[cfg(feature = "invariants")]
+pub use invariants::InvariantError;
+
+pub struct LinkedList<T> {
+    ends: Mutex<Ends<T>>,
+}
+
+impl<T> LinkedList<T> {
+    pub fn new() -> Self {
+        Self {
+            ends: Mutex::new(Ends {
+                len: 0,
+                head_tail: None,
+            }),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Locks the list until the returned cursor is dropped, the cursor starts at the front.
+    pub fn lock(&self) -> Cursor<'_, T> {
+        let ends = lock(&self.ends);
+        let current = ends.head_tail.as_ref().map(|(head, _)| head.clone());
+
+        Cursor { ends, current }
+    }
+
+    pub fn push_front(&self, value: T) {
+        lock(&self.ends).push_front(value)
+    }
+
+    pub fn push_back(&self, value: T) {
+        lock(&self.ends).push_back(value)
+    }
+
+    pub fn pop_front(&self) -> Option<T> {
+        lock(&self.ends).pop_front()
+    }
+
+    pub fn pop_back(&self) -> Option<T> {
+        lock(&self.ends).pop_back()
+    }
+
+    pub fn clear(&self) {
+        lock(&self.ends).clear()
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut cursor = self.lock();
+        let mut vec = Vec::with_capacity(cursor.len());
+
+        loop {
+            match cursor.current() {
+                Some(value) => vec.push(value.clone()),
+                None => break,
+            }
+            cursor.move_next();
+        }
+
+        vec
+    }
+}
+
+#[cfg(feature = "invariants")]
+impl<T> LinkedList<T> {
+    /// Locks the list, walks it and checks that the links match up and that `len` is right.
+    ///
+    /// Every node is only locked while its links are read.
+    pub fn check_invariants(&self) -> Result<(), InvariantError> {
+        let ends = lock(&self.ends);
+
+        invariants::check_links(
+            ends.head_tail.clone(),
+            Some(ends.len),
+            |node| {
+                let node = lock(node);
+                Some((node.prev.clone(), node.next.clone()))
+            },
+            Arc::ptr_eq,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Poisoning is ignored, no method panics while the list is half linked.
+fn lock<U>(mutex: &Mutex<U>) -> MutexGuard<'_, U> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+struct Ends<T> {
+    len: usize,
+    head_tail: Option<(NodeRef<T>, NodeRef<T>)>,
+}
+
+impl<T> Ends<T> {
+    fn push_front(&mut self, value: T) {
+        let new_head = Node::new(value);
+
+        let head_tail = if let Some((head, tail)) = self.head_tail.take() {
+            lock(&head).prev = Some(new_head.clone());
+            lock(&new_head).next = Some(head);
+
+            (new_head, tail)
+        } else {
+            (new_head.clone(), new_head)
+        };
+
+        self.head_tail = Some(head_tail);
+        self.len += 1;
+    }
+
+    fn push_back(&mut self, value: T) {
+        let new_tail = Node::new(value);
+
+        let head_tail = if let Some((head, tail)) = self.head_tail.take() {
+            lock(&tail).next = Some(new_tail.clone());
+            lock(&new_tail).prev = Some(tail);
+
+            (head, new_tail)
+        } else {
+            (new_tail.clone(), new_tail)
+        };
+
+        self.head_tail = Some(head_tail);
+        self.len += 1;
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        let (head, tail) = self.head_tail.take()?;
+        self.len -= 1;
+
+        if Arc::ptr_eq(&head, &tail) {
+            drop(tail);
+            return Some(Node::into_value(head));
+        }
+
+        let new_head = lock(&head).next.take().unwrap();
+        drop(lock(&new_head).prev.take().unwrap());
+
+        self.head_tail = Some((new_head, tail));
+
+        Some(Node::into_value(head))
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        let (head, tail) = self.head_tail.take()?;
+        self.len -= 1;
+
+        if Arc::ptr_eq(&head, &tail) {
+            drop(head);
+            return Some(Node::into_value(tail));
+        }
+
+        let new_tail = lock(&tail).prev.take().unwrap();
+        drop(lock(&new_tail).next.take().unwrap());
+
+        self.head_tail = Some((head, new_tail));
+
+        Some(Node::into_value(tail))
+    }
+
+    fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T> Drop for Ends<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+struct Node<T> {
+    value: T,
+    prev: Option<NodeRef<T>>,
+    next: Option<NodeRef<T>>,
+}
+
+impl<T> Node<T> {
+    fn new(value: T) -> NodeRef<T> {
+        Arc::new(Mutex::new(Node {
+            value,
+            prev: None,
+            next: None,
+        }))
+    }
+
+    /// Unwraps a node that has been unlinked from both of its neighbours.
+    fn into_value(node: NodeRef<T>) -> T {
+        Arc::try_unwrap(node)
+            .ok()
+            .expect("neighbours to be dropped")
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+            .value
+    }
+}
+
+type NodeRef<T> = Arc<Mutex<Node<T>>>;
+
+/// A position in a locked list, see [`LinkedList::lock`].
+///
+/// Like the cursors of `std::collections::LinkedList` it can also point at a "ghost" position past
+/// the back and before the front, where [`current`](Cursor::current) is `None`.
+pub struct Cursor<'a, T> {
+    ends: MutexGuard<'a, Ends<T>>,
+    current: Option<NodeRef<T>>,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    pub fn len(&self) -> usize {
+        self.ends.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ends.len == 0
+    }
+
+    /// The element the cursor points at, `None` at the ghost position.
+    pub fn current(&self) -> Option<ValueGuard<'_, T>> {
+        self.current.as_ref().map(|node| ValueGuard(lock(node)))
+    }
+
+    /// Moves to the next element, from the back to the ghost and from the ghost to the front.
+    pub fn move_next(&mut self) {
+        self.current = match self.current.take() {
+            Some(node) => {
+                let next = lock(&node).next.clone();
+                next
+            }
+            None => self.ends.head_tail.as_ref().map(|(head, _)| head.clone()),
+        };
+    }
+
+    /// Moves to the previous element, from the front to the ghost and from the ghost to the back.
+    pub fn move_prev(&mut self) {
+        self.current = match self.current.take() {
+            Some(node) => {
+                let prev = lock(&node).prev.clone();
+                prev
+            }
+            None => self.ends.head_tail.as_ref().map(|(_, tail)| tail.clone()),
+        };
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.ends.push_front(value)
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.ends.push_back(value)
+    }
+
+    /// Inserts after the current element, at the ghost position that is the front.
+    pub fn insert_after(&mut self, value: T) {
+        let Some(current) = &self.current else {
+            return self.ends.push_front(value);
+        };
+        let Some(next) = lock(current).next.take() else {
+            return self.ends.push_back(value);
+        };
+
+        let node = Node::new(value);
+        {
+            let mut new = lock(&node);
+            new.prev = Some(current.clone());
+            new.next = Some(next.clone());
+        }
+        lock(&next).prev = Some(node.clone());
+        lock(current).next = Some(node);
+        self.ends.len += 1;
+    }
+
+    /// Removes the current element and moves to the next one, at the ghost position nothing is
+    /// removed.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let current = self.current.take()?;
+        let (is_head, is_tail) = {
+            let node = lock(&current);
+            (node.prev.is_none(), node.next.is_none())
+        };
+
+        if is_head || is_tail {
+            // the cursor's reference has to go before the list can unwrap the node
+            drop(current);
+
+            if is_head {
+                let value = self.ends.pop_front();
+                self.current = self.ends.head_tail.as_ref().map(|(head, _)| head.clone());
+                return value;
+            }
+            return self.ends.pop_back();
+        }
+
+        let (prev, next) = {
+            let mut node = lock(&current);
+            (node.prev.take().unwrap(), node.next.take().unwrap())
+        };
+
+        lock(&prev).next = Some(next.clone());
+        lock(&next).prev = Some(prev);
+        self.current = Some(next);
+        self.ends.len -= 1;
+
+        Some(Node::into_value(current))
+    }
+}
+
+/// Access to the element a [`Cursor`] points at, holds that node's lock.
+pub struct ValueGuard<'a, T>(MutexGuard<'a, Node<T>>);
+
+impl<'a, T> Deref for ValueGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0.value
+    }
+}
+
+impl<'a, T> DerefMut for ValueGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0.value
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let ends = self.ends.get_mut().unwrap_or_else(PoisonError::into_inner);
+
+        for value in iter {
+            ends.push_back(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        let mut cursor = self.lock();
+
+        loop {
+            match cursor.current() {
+                Some(value) => list.entry(&*value),
+                None => break,
+            };
+            cursor.move_next();
+        }
+
+        list.finish()
+    }
+}
+
+pub struct IntoIter<T>(LinkedList<T>);
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn push_pop() {
+        let list = LinkedList::new();
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+
+        list.push_back(2);
+        list.push_back(3);
+        list.push_front(1);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn cursor() {
+        let list: LinkedList<_> = (1..=4).collect();
+
+        {
+            let mut cursor = list.lock();
+            assert_eq!(cursor.current().as_deref(), Some(&1));
+
+            cursor.move_next();
+            *cursor.current().unwrap() = 20;
+            cursor.insert_after(25);
+            cursor.move_next();
+            cursor.move_next();
+            assert_eq!(cursor.remove_current(), Some(3));
+            assert_eq!(cursor.current().as_deref(), Some(&4));
+            assert_eq!(cursor.len(), 4);
+
+            cursor.move_next();
+            assert!(cursor.current().is_none());
+            cursor.insert_after(0);
+            cursor.move_prev();
+            cursor.insert_after(5);
+            assert_eq!(cursor.remove_current(), Some(4));
+            assert_eq!(cursor.current().as_deref(), Some(&5));
+        }
+
+        assert_eq!(list.to_vec(), vec![0, 1, 20, 25, 5]);
+        assert_eq!(format!("{list:?}"), "[0, 1, 20, 25, 5]");
+
+        let mut cursor = list.lock();
+        assert_eq!(cursor.remove_current(), Some(0));
+        assert_eq!(cursor.current().as_deref(), Some(&1));
+        cursor.move_prev();
+        assert_eq!(cursor.remove_current(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.remove_current(), Some(5));
+        assert!(cursor.current().is_none());
+        drop(cursor);
+
+        assert_eq!(list.into_iter().rev().collect::<Vec<_>>(), vec![25, 20, 1]);
+    }
+
+    #[test]
+    fn threads() {
+        fn assert_send_sync<S: Send + Sync>() {}
+        assert_send_sync::<LinkedList<i32>>();
+
+        let list = Arc::new(LinkedList::new());
+
+        let handles: Vec<_> = (0..4)
+            .map(|thread| {
+                let list = list.clone();
+                thread::spawn(move || {
+                    for i in 0..1000 {
+                        list.push_back(thread * 1000 + i);
+                        if i % 2 == 0 {
+                            list.push_front(list.pop_back().unwrap());
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let mut values = list.to_vec();
+        assert_eq!(values.len(), 4000);
+        assert_eq!(list.len(), 4000);
+
+        values.sort();
+        assert!(values.into_iter().eq(0..4000));
+    }
+}
@@ -0,0 +1,19 @@
+#![no_main]
+
+//! Replays arbitrary `ops` logs against `third::LinkedList` and a `VecDeque` holding the same
+//! elements and checks both produce the same trace.
+
+use libfuzzer_sys::fuzz_target;
+use ops::{replay, Op};
+use std::collections::VecDeque;
+use third::LinkedList;
+
+fuzz_target!(|input: (LinkedList<u8>, Vec<Op<u8>>)| {
+    let (mut list, ops) = input;
+    let mut model: VecDeque<u8> = list.iter().copied().collect();
+
+    assert_eq!(
+        replay(&mut list, &mut (), &ops),
+        replay(&mut model, &mut (), &ops)
+    );
+});
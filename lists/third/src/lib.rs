@@ -13,33 +13,86 @@
 /// - No unsafe & fully stable rust
 /// - Implementation is `Send` and `Sync`
 /// - No tokens required
-/// - Iteration works
+/// - Iteration works, also in parallel with the `rayon` feature
+/// - Nodes can live in a `DenseSlotMap` or `HopSlotMap` instead, see [`SlotMapLike`]
 ///
 /// Cons:
 /// - lot's of `unwrap()` => no compile time guarantees (similar to first's explicit `drop`)
 /// - still 3x slower than `VecDeque`
-use slotmap::{DefaultKey, SlotMap};
-
-pub struct LinkedList<T> {
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem;
+use slotmap::SlotMap;
+
+#[allow(deprecated)]
+pub use slotmap::{new_key_type, DefaultKey, DenseSlotMap, HopSlotMap, Key, SecondaryMap};
+
+pub mod lru;
+mod map;
+#[cfg(feature = "rayon")]
+mod par;
+mod sorted;
+mod timer;
+
+pub use lru::LruCache;
+pub use map::SlotMapLike;
+pub use sorted::SortedList;
+pub use timer::TimerQueue;
+
+#[cfg(feature = "invariants")]
+pub use invariants::InvariantError;
+
+/// The nodes live in a `SlotMap` by default, any other map implementing [`SlotMapLike`] can be
+/// picked through `M`, along with a key type of its own made with [`new_key_type!`].
+///
+/// # Keys
+///
+/// Every push or insert returns the key of the new node. A key stays valid until its element is
+/// removed, moving, sorting or rotating the list doesn't change it. Keys are versioned, so once an
+/// element is gone its key never refers to another element, not even after the slot is reused.
+/// [`compact`](Self::compact) and [`append`](Self::append) are the only operations handing out new
+/// keys for elements that are still there, their `_with` variants report the mapping.
+///
+/// That makes keys usable in a [`SecondaryMap`] to attach extra data to elements without changing
+/// `T`, see [`LinkedList::secondary_map`].
+pub struct LinkedList<T, K: Key = DefaultKey, M = SlotMap<K, Node<T, K>>> {
     len: usize,
-    arena: SlotMap<DefaultKey, Node<T>>,
-    head_tail: Option<(DefaultKey, DefaultKey)>,
+    arena: M,
+    head_tail: Option<(K, K)>,
+    _values: PhantomData<T>,
 }
 
+/// A list storing its nodes in a [`DenseSlotMap`].
+pub type DenseList<T, K = DefaultKey> = LinkedList<T, K, DenseSlotMap<K, Node<T, K>>>;
+
+/// A list storing its nodes in a [`HopSlotMap`].
+#[allow(deprecated)]
+pub type HopList<T, K = DefaultKey> = LinkedList<T, K, HopSlotMap<K, Node<T, K>>>;
+
 impl<T> LinkedList<T> {
     pub fn new() -> Self {
-        Self {
-            head_tail: None,
-            arena: SlotMap::new(),
-            len: 0,
-        }
+        Self::with_map_capacity(0)
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_map_capacity(capacity)
+    }
+}
+
+impl<T, K: Key, M: SlotMapLike<K, Node<T, K>>> LinkedList<T, K, M> {
+    /// Like [`LinkedList::with_capacity`], but for any key and map type.
+    pub fn with_map_capacity(capacity: usize) -> Self {
         Self {
             head_tail: None,
-            arena: SlotMap::with_capacity(capacity),
+            arena: M::with_capacity(capacity),
             len: 0,
+            _values: PhantomData,
         }
     }
 
@@ -47,19 +100,435 @@ impl<T> LinkedList<T> {
         self.len
     }
 
-    pub fn iter<'a>(&'a self) -> Iter<'a, T> {
+    /// Every slot in the `SlotMap` counts as a node, the ones not holding an element are free.
+    pub fn memory_usage(&self) -> MemoryStats {
+        let bytes_per_node = self.arena.bytes_per_slot();
+        let nodes = self.arena.capacity();
+
+        MemoryStats {
+            nodes,
+            bytes_per_node,
+            total_bytes: nodes * bytes_per_node,
+            free_slots: nodes - self.len,
+        }
+    }
+
+    pub fn iter<'a>(&'a self) -> Iter<'a, T, K, M> {
         Iter {
             list: self,
             head_tail: self.head_tail,
+            len: self.len,
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> alloc::vec::Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    pub fn keys(&self) -> Keys<'_, T, K, M> {
+        Keys {
+            list: self,
+            head_tail: self.head_tail,
         }
     }
 
-    pub fn push_front(&mut self, value: T) {
-        let new_head = self.insert(value);
+    /// Iterates over the elements front to back, same as [`iter`](Self::iter).
+    pub fn values(&self) -> Iter<'_, T, K, M> {
+        self.iter()
+    }
+
+    /// Iterates over the elements along with their keys, front to back.
+    pub fn iter_with_keys(&self) -> IterWithKeys<'_, T, K, M> {
+        IterWithKeys {
+            list: self,
+            head_tail: self.head_tail,
+            len: self.len,
+        }
+    }
+
+    /// An empty [`SecondaryMap`] with room for a value per element, for data keyed by this list's
+    /// keys.
+    ///
+    /// The map isn't updated along with the list, values of removed elements stay around until they
+    /// are removed from the map or [`retain`](SecondaryMap::retain)ed away with
+    /// [`contains_key`](Self::contains_key). They can't be mistaken for data of a later element though.
+    pub fn secondary_map<V>(&self) -> SecondaryMap<K, V> {
+        SecondaryMap::with_capacity(self.len)
+    }
 
+    /// Whether `key` refers to an element of this list.
+    pub fn contains_key(&self, key: K) -> bool {
+        self.arena.contains_key(key)
+    }
+
+    /// Returns the element identified by `key`, `None` if it has been removed.
+    pub fn get_key(&self, key: K) -> Option<&T> {
+        Some(&self.node(key)?.value)
+    }
+
+    pub fn get_key_mut(&mut self, key: K) -> Option<&mut T> {
+        Some(&mut self.node_mut(key)?.value)
+    }
+
+    /// Makes room for `additional` more elements, or reports that the allocation failed instead of aborting.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.arena.try_reserve(additional).map_err(|_| AllocError)
+    }
+
+    /// Like [`push_front`](Self::push_front), but `value` is dropped and an error returned if the
+    /// list can't grow.
+    pub fn try_push_front(&mut self, value: T) -> Result<K, AllocError> {
+        self.try_reserve(1)?;
+
+        Ok(self.push_front(value))
+    }
+
+    /// Like [`push_back`](Self::push_back), but `value` is dropped and an error returned if the
+    /// list can't grow.
+    pub fn try_push_back(&mut self, value: T) -> Result<K, AllocError> {
+        self.try_reserve(1)?;
+
+        Ok(self.push_back(value))
+    }
+
+    pub fn push_front(&mut self, value: T) -> K {
+        let new_head = self.insert_node(value);
+
+        self.link_front(new_head);
+        self.len += 1;
+
+        new_head
+    }
+
+    pub fn push_back(&mut self, value: T) -> K {
+        let new_tail = self.insert_node(value);
+
+        self.link_back(new_tail);
+        self.len += 1;
+
+        new_tail
+    }
+
+    /// Inserts `value` so it ends up at `index`, walking from whichever end is closer.
+    ///
+    /// Panics if `index` is greater than the length of the list.
+    pub fn insert(&mut self, index: usize, value: T) -> K {
+        assert!(index <= self.len, "index out of bounds");
+
+        if index == self.len {
+            return self.push_back(value);
+        }
+
+        let next = self.key_at(index).unwrap();
+        let prev = self.node(next).unwrap().prev;
+        let key = self.insert_node(value);
+
+        let node = self.node_mut(key).unwrap();
+        node.prev = prev;
+        node.next = Some(next);
+        self.node_mut(next).unwrap().prev = Some(key);
+
+        match prev {
+            Some(prev) => self.node_mut(prev).unwrap().next = Some(key),
+            None => self.head_tail = self.head_tail.map(|(_, tail)| (key, tail)),
+        }
+        self.len += 1;
+
+        key
+    }
+
+    /// Removes the element at `index`, walking from whichever end is closer.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let key = self.key_at(index)?;
+
+        self.remove_key(key)
+    }
+
+    /// Returns the element at `index`, walking from whichever end is closer.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let key = self.key_at(index)?;
+
+        Some(&self.node(key).unwrap().value)
+    }
+
+    /// Returns the element at `index`, walking from whichever end is closer.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let key = self.key_at(index)?;
+
+        Some(&mut self.node_mut(key).unwrap().value)
+    }
+
+    /// Removes the node identified by `key` from the list, returning its value.
+    ///
+    /// Returns `None` if `key` does not refer to a node of this list.
+    pub fn remove_key(&mut self, key: K) -> Option<T> {
+        self.arena.contains_key(key).then(|| self.unlink(key).value)
+    }
+
+    /// Removes all elements for which `f` returns `false`, walking the list once front to back.
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        self.retain_mut(|value| f(value))
+    }
+
+    /// Like [`LinkedList::retain`], but passes a mutable reference to each element.
+    pub fn retain_mut(&mut self, mut f: impl FnMut(&mut T) -> bool) {
+        let mut current = self.head_tail.map(|(head, _)| head);
+
+        while let Some(key) = current {
+            let node = self.node_mut(key).unwrap();
+            current = node.next;
+
+            if !f(&mut node.value) {
+                self.remove_key(key);
+            }
+        }
+    }
+
+    /// Relinks the node identified by `key` to the front of the list without moving its value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` does not refer to a node of this list.
+    pub fn move_to_front(&mut self, key: K) {
+        assert!(
+            self.arena.contains_key(key),
+            "key does not belong to this list"
+        );
+
+        if self.head_tail.map(|(head, _)| head) != Some(key) {
+            self.detach(key);
+            self.link_front(key);
+        }
+    }
+
+    /// Relinks the node identified by `key` to the back of the list without moving its value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` does not refer to a node of this list.
+    pub fn move_to_back(&mut self, key: K) {
+        assert!(
+            self.arena.contains_key(key),
+            "key does not belong to this list"
+        );
+
+        if self.head_tail.map(|(_, tail)| tail) != Some(key) {
+            self.detach(key);
+            self.link_back(key);
+        }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let (head, _) = self.head_tail?;
+
+        Some(self.unlink(head).value)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let (_, tail) = self.head_tail?;
+
+        Some(self.unlink(tail).value)
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    /// Moves all elements of `other` to the back of `self`, leaving `other` empty.
+    ///
+    /// The moved nodes get new keys, use [`LinkedList::append_with`] to keep external indexes in sync.
+    pub fn append(&mut self, other: &mut Self) {
+        self.append_with(other, |_, _| {})
+    }
+
+    /// Like [`LinkedList::append`], but calls `on_remap(old_key, new_key)` for every moved node.
+    pub fn append_with(&mut self, other: &mut Self, mut on_remap: impl FnMut(K, K)) {
+        let mut current = other.head_tail.take().map(|(head, _)| head);
+
+        while let Some(old_key) = current {
+            let node = other.remove_node(old_key).unwrap();
+            current = node.next;
+
+            let new_key = self.push_back(node.value);
+            on_remap(old_key, new_key);
+        }
+
+        other.len = 0;
+    }
+
+    /// Re-inserts all nodes into a fresh, tightly sized arena in list order.
+    ///
+    /// This releases the capacity left behind by removals and makes iteration walk memory linearly.
+    /// All keys are invalidated, use [`LinkedList::compact_with`] to keep external indexes in sync.
+    pub fn compact(&mut self) {
+        self.compact_with(|_, _| {})
+    }
+
+    /// Like [`LinkedList::compact`], but calls `on_remap(old_key, new_key)` for every node.
+    ///
+    /// A new key may be equal to the old key of a node that has not been remapped yet,
+    /// so build the updated index separately instead of patching it in place.
+    pub fn compact_with(&mut self, mut on_remap: impl FnMut(K, K)) {
+        let mut arena = M::with_capacity(self.len);
+        let mut head = None;
+        let mut prev: Option<K> = None;
+        let mut current = self.head_tail.take().map(|(head, _)| head);
+
+        while let Some(old_key) = current {
+            let node = self.remove_node(old_key).unwrap();
+            current = node.next;
+
+            let new_key = arena.insert(Node {
+                value: node.value,
+                prev,
+                next: None,
+            });
+
+            match prev {
+                Some(prev) => arena.get_mut(prev).unwrap().next = Some(new_key),
+                None => head = Some(new_key),
+            }
+
+            on_remap(old_key, new_key);
+            prev = Some(new_key);
+        }
+
+        self.arena = arena;
+        self.head_tail = head.zip(prev);
+    }
+
+    /// Moves the first `n` elements to the back of the list.
+    ///
+    /// Takes `O(min(n, len - n))` steps, panics if `n` is greater than the length of the list.
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(n <= self.len, "rotate by more than the length of the list");
+
+        let Some((head, tail)) = self.head_tail else {
+            return;
+        };
+        if n == 0 || n == self.len {
+            return;
+        }
+
+        // walk to the node that becomes the new tail from whichever end is closer
+        let mut new_tail = head;
+        if n <= self.len - n {
+            for _ in 1..n {
+                new_tail = self.node(new_tail).unwrap().next.unwrap();
+            }
+        } else {
+            new_tail = tail;
+            for _ in 0..self.len - n {
+                new_tail = self.node(new_tail).unwrap().prev.unwrap();
+            }
+        }
+
+        // close the ring, then cut it open after the new tail
+        self.node_mut(tail).unwrap().next = Some(head);
+        self.node_mut(head).unwrap().prev = Some(tail);
+
+        let new_head = self.node_mut(new_tail).unwrap().next.take().unwrap();
+        self.node_mut(new_head).unwrap().prev = None;
+
+        self.head_tail = Some((new_head, new_tail));
+    }
+
+    /// Moves the last `n` elements to the front of the list.
+    ///
+    /// Takes `O(min(n, len - n))` steps, panics if `n` is greater than the length of the list.
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(n <= self.len, "rotate by more than the length of the list");
+
+        self.rotate_left(self.len - n)
+    }
+
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+
+    /// Whether `compare` holds for every pair of neighbours, like [`slice::is_sorted_by`].
+    pub fn is_sorted_by<F>(&self, mut compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut iter = self.iter();
+        let Some(mut prev) = iter.next() else {
+            return true;
+        };
+        iter.all(|value| compare(mem::replace(&mut prev, value), value))
+    }
+
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp)
+    }
+
+    pub fn sort_by_key<U, F>(&mut self, mut f: F)
+    where
+        U: Ord,
+        F: FnMut(&T) -> U,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Stable bottom-up merge sort that only relinks keys, values stay where they are in the arena.
+    ///
+    /// If `cmp` panics, the list still holds every element, in no particular order.
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let Some((head, _)) = self.head_tail else {
+            return;
+        };
+
+        // runs are merged along the `next` links only, `prev` is fixed up when `sort` is dropped
+        let mut sort = Sort {
+            list: self,
+            sorted: None,
+            merged: None,
+            left: None,
+            right: None,
+            rest: Some(head),
+        };
+        let mut width = 1;
+        loop {
+            let mut merges = 0;
+
+            while let Some(left) = sort.rest {
+                sort.left = Some(left);
+                sort.right = sort.list.split_after(left, width);
+                sort.rest = sort
+                    .right
+                    .and_then(|right| sort.list.split_after(right, width));
+
+                sort.merge(&mut cmp);
+                merges += 1;
+            }
+
+            if merges <= 1 {
+                break;
+            }
+            sort.rest = sort.sorted.take().map(|(head, _)| head);
+            width *= 2;
+        }
+    }
+
+    fn link_front(&mut self, new_head: K) {
         let head_tail = if let Some((head, tail)) = self.head_tail.take() {
-            self.get_mut(head).unwrap().prev = Some(new_head);
-            self.get_mut(new_head).unwrap().next = Some(head);
+            self.node_mut(head).unwrap().prev = Some(new_head);
+            self.node_mut(new_head).unwrap().next = Some(head);
 
             (new_head, tail)
         } else {
@@ -67,15 +536,12 @@ impl<T> LinkedList<T> {
         };
 
         self.head_tail = Some(head_tail);
-        self.len += 1;
     }
 
-    pub fn push_back(&mut self, value: T) {
-        let new_tail = self.insert(value);
-
+    fn link_back(&mut self, new_tail: K) {
         let head_tail = if let Some((head, tail)) = self.head_tail.take() {
-            self.get_mut(tail).unwrap().next = Some(new_tail);
-            self.get_mut(new_tail).unwrap().prev = Some(tail);
+            self.node_mut(tail).unwrap().next = Some(new_tail);
+            self.node_mut(new_tail).unwrap().prev = Some(tail);
 
             (head, new_tail)
         } else {
@@ -83,47 +549,104 @@ impl<T> LinkedList<T> {
         };
 
         self.head_tail = Some(head_tail);
+    }
+
+    /// Inserts `value` after the last element for which `goes_before(elem, &value)` holds, walking
+    /// from the back. Keeps a list sorted in `O(1)` when values arrive mostly in order.
+    fn insert_after_last(&mut self, value: T, mut goes_before: impl FnMut(&T, &T) -> bool) -> K {
+        let mut prev = self.head_tail.map(|(_, tail)| tail);
+        while let Some(key) = prev {
+            let node = self.node(key).unwrap();
+            if goes_before(&node.value, &value) {
+                break;
+            }
+            prev = node.prev;
+        }
+
+        let key = self.insert_node(value);
+        self.link_after(prev, key);
         self.len += 1;
+
+        key
     }
 
-    pub fn pop_front(&mut self) -> Option<T> {
-        let (head, tail) = self.head_tail.take()?;
+    /// Links `key` in right after `prev`, or at the front if there is none.
+    fn link_after(&mut self, prev: Option<K>, key: K) {
+        let Some(prev) = prev else {
+            return self.link_front(key);
+        };
+        let Some(next) = self.node(prev).unwrap().next else {
+            return self.link_back(key);
+        };
+
+        let node = self.node_mut(key).unwrap();
+        node.prev = Some(prev);
+        node.next = Some(next);
+        self.node_mut(prev).unwrap().next = Some(key);
+        self.node_mut(next).unwrap().prev = Some(key);
+    }
+
+    fn key_at(&self, index: usize) -> Option<K> {
+        let (head, tail) = self.head_tail?;
+        if index >= self.len {
+            return None;
+        }
 
-        if head == tail {
-            return Some(self.remove(head).unwrap().value);
+        let mut key = head;
+        if index <= self.len / 2 {
+            for _ in 0..index {
+                key = self.node(key).unwrap().next.unwrap();
+            }
+        } else {
+            key = tail;
+            for _ in index + 1..self.len {
+                key = self.node(key).unwrap().prev.unwrap();
+            }
         }
 
-        let new_head = self.get_mut(head).unwrap().next.take().unwrap();
-        self.get_mut(new_head).unwrap().prev.take().unwrap();
+        Some(key)
+    }
 
-        self.head_tail = Some((new_head, tail));
-        self.len -= 1;
+    /// Cuts the `next` chain after `n` nodes starting at `start` and returns the start of the rest.
+    fn split_after(&mut self, start: K, n: usize) -> Option<K> {
+        let mut last = start;
+        for _ in 1..n {
+            last = self.node(last).unwrap().next?;
+        }
 
-        Some(self.remove(head).unwrap().value)
+        self.node_mut(last).unwrap().next.take()
     }
 
-    pub fn pop_back(&mut self) -> Option<T> {
-        let (head, tail) = self.head_tail.take()?;
+    /// Unlinks the node from its neighbours, but leaves it in the arena.
+    fn detach(&mut self, key: K) {
+        let node = self.node_mut(key).unwrap();
+        let prev = node.prev.take();
+        let next = node.next.take();
 
-        if head == tail {
-            // they are pointing to the same thing, but let's keep the symmetry
-            return Some(self.remove(tail).unwrap().value);
+        if let Some(prev) = prev {
+            self.node_mut(prev).unwrap().next = next;
+        }
+        if let Some(next) = next {
+            self.node_mut(next).unwrap().prev = prev;
         }
 
-        let new_tail = self.get_mut(head).unwrap().prev.take().unwrap();
-        self.get_mut(new_tail).unwrap().next.take().unwrap();
+        let (head, tail) = self.head_tail.take().unwrap();
+        let head = if head == key { next } else { Some(head) };
+        let tail = if tail == key { prev } else { Some(tail) };
 
-        self.head_tail = Some((head, new_tail));
-        self.len -= 1;
-
-        Some(self.remove(tail).unwrap().value)
+        self.head_tail = head.zip(tail);
     }
 
-    pub fn clear(&mut self) {
-        while self.pop_front().is_some() {}
+    /// Detaches the node and removes it from the arena. Every removal goes through here, so this is
+    /// the only place `len` shrinks.
+    fn unlink(&mut self, key: K) -> Node<T, K> {
+        self.detach(key);
+        self.len -= 1;
+
+        self.remove_node(key).unwrap()
     }
 
-    fn insert(&mut self, value: T) -> DefaultKey {
+    fn insert_node(&mut self, value: T) -> K {
         self.arena.insert(Node {
             value,
             prev: None,
@@ -131,83 +654,541 @@ impl<T> LinkedList<T> {
         })
     }
 
-    fn get_mut(&mut self, node_ref: DefaultKey) -> Option<&mut Node<T>> {
+    fn node_mut(&mut self, node_ref: K) -> Option<&mut Node<T, K>> {
         self.arena.get_mut(node_ref)
     }
 
-    fn get(&self, node_ref: DefaultKey) -> Option<&Node<T>> {
+    fn node(&self, node_ref: K) -> Option<&Node<T, K>> {
         self.arena.get(node_ref)
     }
 
-    fn remove(&mut self, node_ref: DefaultKey) -> Option<Node<T>> {
+    fn remove_node(&mut self, node_ref: K) -> Option<Node<T, K>> {
         self.arena.remove(node_ref)
     }
 }
 
-struct Node<T> {
+/// A [`LinkedList::sort_by`] in progress. Every run is a chain of `next` links ending in `None`,
+/// dropping it chains them back together in this order and fixes up the `prev` links, so a
+/// panicking comparison doesn't lose any elements.
+struct Sort<'a, T, K: Key, M: SlotMapLike<K, Node<T, K>>> {
+    list: &'a mut LinkedList<T, K, M>,
+    /// The runs merged in this pass so far.
+    sorted: Option<(K, K)>,
+    /// The run `left` and `right` are being merged into.
+    merged: Option<(K, K)>,
+    left: Option<K>,
+    right: Option<K>,
+    /// Whatever this pass hasn't reached yet.
+    rest: Option<K>,
+}
+
+impl<'a, T, K: Key, M: SlotMapLike<K, Node<T, K>>> Sort<'a, T, K, M> {
+    /// Merges `left` and `right` onto the end of `sorted`.
+    fn merge(&mut self, cmp: &mut impl FnMut(&T, &T) -> Ordering) {
+        loop {
+            let from_left = match (self.left, self.right) {
+                // prefer the left run on ties to keep the sort stable
+                (Some(l), Some(r)) => {
+                    let list = &*self.list;
+                    cmp(&list.node(l).unwrap().value, &list.node(r).unwrap().value)
+                        != Ordering::Greater
+                }
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+
+            let run = if from_left {
+                &mut self.left
+            } else {
+                &mut self.right
+            };
+            let key = run.unwrap();
+            *run = self.list.node_mut(key).unwrap().next.take();
+
+            self.merged = Some(match self.merged {
+                Some((head, tail)) => {
+                    self.list.node_mut(tail).unwrap().next = Some(key);
+                    (head, key)
+                }
+                None => (key, key),
+            });
+        }
+
+        let (head, tail) = self.merged.take().unwrap();
+        self.sorted = Some(match self.sorted {
+            Some((sorted_head, sorted_tail)) => {
+                self.list.node_mut(sorted_tail).unwrap().next = Some(head);
+                (sorted_head, tail)
+            }
+            None => (head, tail),
+        });
+    }
+}
+
+impl<'a, T, K: Key, M: SlotMapLike<K, Node<T, K>>> Drop for Sort<'a, T, K, M> {
+    fn drop(&mut self) {
+        let runs = [
+            self.sorted.map(|(head, _)| head),
+            self.merged.map(|(head, _)| head),
+            self.left,
+            self.right,
+            self.rest,
+        ];
+
+        let (mut head, mut prev) = (None, None);
+        for mut current in runs {
+            if let (Some(prev), Some(_)) = (prev, current) {
+                self.list.node_mut(prev).unwrap().next = current;
+            }
+            while let Some(key) = current {
+                head.get_or_insert(key);
+                let node = self.list.node_mut(key).unwrap();
+                node.prev = prev;
+                prev = Some(key);
+                current = node.next;
+            }
+        }
+
+        self.list.head_tail = head.zip(prev);
+    }
+}
+
+#[cfg(feature = "invariants")]
+impl<T, K: Key, M: SlotMapLike<K, Node<T, K>>> LinkedList<T, K, M> {
+    /// Walks the list and checks that every key resolves, that the links match up and that `len`
+    /// is right.
+    pub fn check_invariants(&self) -> Result<(), InvariantError> {
+        invariants::check_links(
+            self.head_tail,
+            Some(self.len),
+            |&key| self.node(key).map(|node| (node.prev, node.next)),
+            |a, b| a == b,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "snapshot")]
+impl<T, K: Key, M: SlotMapLike<K, Node<T, K>>> LinkedList<T, K, M> {
+    /// Panics unless a snapshot of `self` restores to the same elements in the same order, and two
+    /// restores of it agree on every key.
+    ///
+    /// Keys are not part of the snapshot, a list restored from it hands out fresh ones that only
+    /// depend on the number of elements. Keys returned by later calls stay equal as long as both
+    /// lists see the same operations.
+    pub fn assert_roundtrip(&self)
+    where
+        T: snapshot::serde::Serialize
+            + snapshot::serde::de::DeserializeOwned
+            + PartialEq
+            + core::fmt::Debug,
+    {
+        let bytes = snapshot::encode(self.iter()).expect("failed to take snapshot");
+
+        let restore = || {
+            let values = snapshot::decode(&bytes).expect("failed to restore snapshot");
+            let mut list = Self::with_map_capacity(values.capacity_hint());
+            for value in values {
+                list.push_back(value.expect("failed to restore snapshot"));
+            }
+            list
+        };
+        let (a, b) = (restore(), restore());
+
+        assert!(self.iter().eq(a.iter()), "restored elements differ");
+        assert!(a.keys().eq(b.keys()), "restored keys differ");
+    }
+}
+
+/// The `SlotMap` couldn't allocate room for more nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocError;
+
+impl fmt::Display for AllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("memory allocation failed")
+    }
+}
+
+/// What a list costs in memory, see [`LinkedList::memory_usage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Nodes allocated so far, in use or not.
+    pub nodes: usize,
+    pub bytes_per_node: usize,
+    /// Everything reserved for nodes, including capacity that hasn't been handed out yet.
+    pub total_bytes: usize,
+    /// How many of `nodes` are free and will be reused before allocating again.
+    pub free_slots: usize,
+}
+
+/// A node as stored in the map, only public so it can be named in `M`.
+pub struct Node<T, K = DefaultKey> {
     value: T,
-    prev: Option<DefaultKey>,
-    next: Option<DefaultKey>,
+    prev: Option<K>,
+    next: Option<K>,
+}
+
+impl<T, K: Key, M: SlotMapLike<K, Node<T, K>>> Extend<T> for LinkedList<T, K, M> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.arena.reserve(iter.size_hint().0);
+
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T, K: Key, M: SlotMapLike<K, Node<T, K>>> FromIterator<T> for LinkedList<T, K, M> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+
+        let mut list = Self::with_map_capacity(iter.size_hint().0);
+        list.extend(iter);
+        list
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, K: Key, M: SlotMapLike<K, Node<T, K>>> From<alloc::collections::LinkedList<T>>
+    for LinkedList<T, K, M>
+{
+    fn from(std: alloc::collections::LinkedList<T>) -> Self {
+        std.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, K: Key, M: SlotMapLike<K, Node<T, K>>> From<LinkedList<T, K, M>>
+    for alloc::collections::LinkedList<T>
+{
+    fn from(list: LinkedList<T, K, M>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T: fmt::Debug, K: Key, M: SlotMapLike<K, Node<T, K>>> fmt::Debug for LinkedList<T, K, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, K: Key, M: SlotMapLike<K, Node<T, K>>> PartialEq for LinkedList<T, K, M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, K: Key, M: SlotMapLike<K, Node<T, K>>> Eq for LinkedList<T, K, M> {}
+
+impl<T: PartialOrd, K: Key, M: SlotMapLike<K, Node<T, K>>> PartialOrd for LinkedList<T, K, M> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+/// Lexicographic, like slices.
+impl<T: Ord, K: Key, M: SlotMapLike<K, Node<T, K>>> Ord for LinkedList<T, K, M> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// Hashes the length followed by the elements in order.
+impl<T: Hash, K: Key, M: SlotMapLike<K, Node<T, K>>> Hash for LinkedList<T, K, M> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+
+        for value in self.iter() {
+            value.hash(state);
+        }
+    }
+}
+
+/// Serializes as a sequence, front to back.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, K: Key, M: SlotMapLike<K, Node<T, K>>> serde::Serialize
+    for LinkedList<T, K, M>
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.len))?;
+        for value in self.iter() {
+            seq.serialize_element(value)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, K: Key, M: SlotMapLike<K, Node<T, K>>> serde::Deserialize<'de>
+    for LinkedList<T, K, M>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ListVisitor<T, K, M>(core::marker::PhantomData<(T, K, M)>);
+
+        impl<'de, T: serde::Deserialize<'de>, K: Key, M: SlotMapLike<K, Node<T, K>>>
+            serde::de::Visitor<'de> for ListVisitor<T, K, M>
+        {
+            type Value = LinkedList<T, K, M>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                // the hint comes from the input, so don't let it allocate arbitrarily much up front
+                let capacity = seq.size_hint().unwrap_or(0).min(4096);
+                let mut list = LinkedList::with_map_capacity(capacity);
+
+                while let Some(value) = seq.next_element()? {
+                    list.push_back(value);
+                }
+
+                Ok(list)
+            }
+        }
+
+        deserializer.deserialize_seq(ListVisitor(core::marker::PhantomData))
+    }
+}
+
+/// Collects an arbitrary `Vec` and then moves and removes arbitrary elements, so lists with the same
+/// contents don't always share the same slot layout.
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>, K: Key, M: SlotMapLike<K, Node<T, K>>>
+    arbitrary::Arbitrary<'a> for LinkedList<T, K, M>
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let values: alloc::vec::Vec<T> = u.arbitrary()?;
+        let mut list: Self = values.into_iter().collect();
+
+        for op in u.arbitrary_iter::<u8>()? {
+            let op = op?;
+            if list.len == 0 {
+                break;
+            }
+
+            let value = list.remove(usize::from(op / 3) % list.len).unwrap();
+            match op % 3 {
+                0 => {
+                    list.push_front(value);
+                }
+                1 => {
+                    list.push_back(value);
+                }
+                // removed for good
+                _ => {}
+            }
+        }
+
+        Ok(list)
+    }
 }
 
-pub struct IntoIter<T>(LinkedList<T>);
+pub struct IntoIter<T, K: Key = DefaultKey, M = SlotMap<K, Node<T, K>>>(LinkedList<T, K, M>);
 
-impl<T> IntoIterator for LinkedList<T> {
+impl<T, K: Key, M: SlotMapLike<K, Node<T, K>>> IntoIterator for LinkedList<T, K, M> {
     type Item = T;
 
-    type IntoIter = IntoIter<T>;
+    type IntoIter = IntoIter<T, K, M>;
 
     fn into_iter(self) -> Self::IntoIter {
         IntoIter(self)
     }
 }
 
-impl<T> Iterator for IntoIter<T> {
+impl<T, K: Key, M: SlotMapLike<K, Node<T, K>>> Iterator for IntoIter<T, K, M> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
         self.0.pop_front()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, K: Key, M: SlotMapLike<K, Node<T, K>>> FusedIterator for IntoIter<T, K, M> {}
+
+/// [`IntoIter`] as a `Stream`, every element is ready right away.
+#[cfg(feature = "futures")]
+pub struct IntoStream<T, K: Key = DefaultKey, M = SlotMap<K, Node<T, K>>>(IntoIter<T, K, M>);
+
+#[cfg(feature = "futures")]
+impl<T, K: Key, M: SlotMapLike<K, Node<T, K>>> LinkedList<T, K, M> {
+    pub fn into_stream(self) -> IntoStream<T, K, M> {
+        IntoStream(self.into_iter())
+    }
+}
+
+/// The elements are never pinned, they are moved out as they are popped.
+#[cfg(feature = "futures")]
+impl<T, K: Key, M> Unpin for IntoStream<T, K, M> {}
+
+#[cfg(feature = "futures")]
+impl<T, K: Key, M: SlotMapLike<K, Node<T, K>>> futures_core::Stream for IntoStream<T, K, M> {
+    type Item = T;
+
+    fn poll_next(
+        mut self: core::pin::Pin<&mut Self>,
+        _: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Option<T>> {
+        core::task::Poll::Ready(self.0.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
 }
 
-pub struct Iter<'a, T> {
-    list: &'a LinkedList<T>,
-    head_tail: Option<(DefaultKey, DefaultKey)>,
+pub struct Iter<'a, T, K: Key = DefaultKey, M = SlotMap<K, Node<T, K>>> {
+    list: &'a LinkedList<T, K, M>,
+    head_tail: Option<(K, K)>,
+    len: usize,
 }
 
-impl<'a, T> Iterator for Iter<'a, T> {
+impl<'a, T, K: Key, M: SlotMapLike<K, Node<T, K>>> Iterator for Iter<'a, T, K, M> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
         let (head, tail) = self.head_tail.take()?;
 
-        let node = self.list.get(head).unwrap();
+        let node = self.list.node(head).unwrap();
 
         if head != tail {
             self.head_tail = node.next.map(|n| (n, tail));
         } else {
             self.head_tail = None;
         }
+        self.len -= 1;
 
         Some(&node.value)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
 }
 
-impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+impl<'a, T, K: Key, M: SlotMapLike<K, Node<T, K>>> DoubleEndedIterator for Iter<'a, T, K, M> {
     fn next_back(&mut self) -> Option<Self::Item> {
         let (head, tail) = self.head_tail.take()?;
 
-        let node = self.list.get(tail).unwrap();
+        let node = self.list.node(tail).unwrap();
 
         if head != tail {
             self.head_tail = node.prev.map(|n| (head, n));
         } else {
             self.head_tail = None;
         }
+        self.len -= 1;
 
         Some(&node.value)
     }
 }
 
+impl<'a, T, K: Key, M: SlotMapLike<K, Node<T, K>>> FusedIterator for Iter<'a, T, K, M> {}
+
+/// Iterates over the keys of the list front to back.
+pub struct Keys<'a, T, K: Key = DefaultKey, M = SlotMap<K, Node<T, K>>> {
+    list: &'a LinkedList<T, K, M>,
+    head_tail: Option<(K, K)>,
+}
+
+impl<'a, T, K: Key, M: SlotMapLike<K, Node<T, K>>> Iterator for Keys<'a, T, K, M> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        if head != tail {
+            self.head_tail = self.list.node(head).unwrap().next.map(|n| (n, tail));
+        }
+
+        Some(head)
+    }
+}
+
+impl<'a, T, K: Key, M: SlotMapLike<K, Node<T, K>>> DoubleEndedIterator for Keys<'a, T, K, M> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        if head != tail {
+            self.head_tail = self.list.node(tail).unwrap().prev.map(|n| (head, n));
+        }
+
+        Some(tail)
+    }
+}
+
+impl<'a, T, K: Key, M: SlotMapLike<K, Node<T, K>>> FusedIterator for Keys<'a, T, K, M> {}
+
+/// Iterates over `(key, &value)` pairs of the list front to back.
+pub struct IterWithKeys<'a, T, K: Key = DefaultKey, M = SlotMap<K, Node<T, K>>> {
+    list: &'a LinkedList<T, K, M>,
+    head_tail: Option<(K, K)>,
+    len: usize,
+}
+
+impl<'a, T, K: Key, M: SlotMapLike<K, Node<T, K>>> Iterator for IterWithKeys<'a, T, K, M> {
+    type Item = (K, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        let node = self.list.node(head).unwrap();
+
+        if head != tail {
+            self.head_tail = node.next.map(|n| (n, tail));
+        }
+        self.len -= 1;
+
+        Some((head, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T, K: Key, M: SlotMapLike<K, Node<T, K>>> DoubleEndedIterator
+    for IterWithKeys<'a, T, K, M>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        let node = self.list.node(tail).unwrap();
+
+        if head != tail {
+            self.head_tail = node.prev.map(|n| (head, n));
+        }
+        self.len -= 1;
+
+        Some((tail, &node.value))
+    }
+}
+
+impl<'a, T, K: Key, M: SlotMapLike<K, Node<T, K>>> ExactSizeIterator for IterWithKeys<'a, T, K, M> {}
+
+impl<'a, T, K: Key, M: SlotMapLike<K, Node<T, K>>> FusedIterator for IterWithKeys<'a, T, K, M> {}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -251,6 +1232,51 @@ mod test {
         assert_eq!(list.pop_back(), None);
     }
 
+    #[test]
+    fn pop_matches_vec_deque() {
+        use std::collections::VecDeque;
+
+        let mut list = LinkedList::new();
+        let mut model = VecDeque::new();
+        let mut keys = Vec::new();
+
+        // a fixed xorshift sequence, so every removal path runs against lists of all lengths
+        let mut seed = 0x2545_f491_u32;
+        for i in 0..2000 {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+
+            match seed % 6 {
+                0 => {
+                    keys.push(list.push_back(i));
+                    model.push_back(i);
+                }
+                1 => {
+                    keys.push(list.push_front(i));
+                    model.push_front(i);
+                }
+                2 => assert_eq!(list.pop_front(), model.pop_front()),
+                3 => assert_eq!(list.pop_back(), model.pop_back()),
+                4 => {
+                    let index = (seed as usize / 6) % (model.len() + 1);
+                    assert_eq!(list.remove(index), model.remove(index));
+                }
+                _ => {
+                    let Some(key) = keys.pop() else { continue };
+                    let expected = list
+                        .get_key(key)
+                        .map(|value| model.iter().position(|v| v == value).unwrap())
+                        .and_then(|index| model.remove(index));
+                    assert_eq!(list.remove_key(key), expected);
+                }
+            }
+
+            assert_eq!(list.len(), model.len());
+            assert!(list.iter().eq(model.iter()));
+        }
+    }
+
     #[test]
     pub fn iter() {
         let mut list = LinkedList::new();
@@ -263,6 +1289,347 @@ mod test {
         assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4])
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.to_vec(), Vec::<i32>::new());
+
+        for i in 1..=3 {
+            list.push_back(i);
+        }
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn std_conversions() {
+        let std: std::collections::LinkedList<_> = (1..=3).collect();
+
+        let mut list: LinkedList<_> = LinkedList::from(std);
+        list.push_front(0);
+        assert_eq!(list.to_vec(), vec![0, 1, 2, 3]);
+
+        let std = std::collections::LinkedList::from(list);
+        assert_eq!(std.into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+
+        let dense: DenseList<_> = std::collections::LinkedList::from([1, 2]).into();
+        assert_eq!(dense.len(), 2);
+    }
+
+    #[test]
+    fn iter_last_count() {
+        let mut list = LinkedList::new();
+
+        for i in 0..4 {
+            list.push_back(i);
+        }
+        assert_eq!(list.pop_front(), Some(0));
+
+        assert_eq!(list.iter().count(), 3);
+        assert_eq!(list.iter().last(), Some(&3));
+
+        let mut iter = list.iter();
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.count(), 1);
+
+        list.clear();
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.iter().count(), 0);
+        assert_eq!(list.iter().last(), None);
+    }
+
+    #[test]
+    fn extend_collect() {
+        let mut list: LinkedList<_> = (1..=3).collect();
+        assert_eq!(list.len(), 3);
+
+        list.extend(vec![4, 5]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+
+        let list: LinkedList<i32> = core::iter::empty().collect();
+        assert!(list.head_tail.is_none());
+    }
+
+    #[test]
+    fn compare() {
+        let list: LinkedList<_> = [1, 2, 3].into_iter().collect();
+        let mut other = LinkedList::new();
+
+        assert!(list != other);
+        assert!(other < list);
+
+        other.push_front(3);
+        other.push_front(2);
+        assert!(other > list);
+
+        other.push_front(1);
+        assert!(list == other);
+        assert_eq!(list.cmp(&other), Ordering::Equal);
+
+        other.push_back(0);
+        assert!(list < other);
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::HashMap;
+
+        let mut counts = HashMap::new();
+        for values in [vec![1, 2], vec![1, 2], vec![2, 1], vec![]] {
+            let list: LinkedList<_> = values.into_iter().collect();
+            *counts.entry(list).or_insert(0) += 1;
+        }
+
+        let key: LinkedList<_> = [1, 2].into_iter().collect();
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&key], 2);
+        assert_eq!(counts[&LinkedList::new()], 1);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let list: LinkedList<_> = ["a", "b", "c"].into_iter().collect();
+
+        let bytes = postcard::to_allocvec(&list).unwrap();
+        // a seq is encoded exactly like a slice
+        assert_eq!(bytes, postcard::to_allocvec(&["a", "b", "c"][..]).unwrap());
+
+        let restored: LinkedList<String> = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(
+            restored.iter().map(String::as_str).collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+        assert_eq!(restored.len(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "futures")]
+    fn into_stream() {
+        use core::pin::Pin;
+        use core::task::{Context, Poll, Waker};
+        use futures_core::Stream;
+
+        let list: LinkedList<_> = (1..=2).collect();
+        let mut stream = list.into_stream();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        assert_eq!(stream.size_hint(), (2, Some(2)));
+        assert_eq!(
+            Pin::new(&mut stream).poll_next(&mut cx),
+            Poll::Ready(Some(1))
+        );
+        assert_eq!(
+            Pin::new(&mut stream).poll_next(&mut cx),
+            Poll::Ready(Some(2))
+        );
+        assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(None));
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let bytes: Vec<u8> = (0..=255).cycle().take(1024).collect();
+        let mut u = Unstructured::new(&bytes);
+
+        while !u.is_empty() {
+            let list = LinkedList::<u16>::arbitrary(&mut u).unwrap();
+
+            let forward: Vec<_> = list.iter().collect();
+            let mut backward: Vec<_> = list.iter().rev().collect();
+            backward.reverse();
+
+            assert_eq!(forward.len(), list.len());
+            assert_eq!(forward, backward);
+        }
+    }
+
+    #[test]
+    fn keys() {
+        let mut list = LinkedList::new();
+
+        let b = list.push_back(2);
+        let a = list.push_front(1);
+        let c = list.push_back(3);
+
+        assert_eq!(list.keys().collect::<Vec<_>>(), vec![a, b, c]);
+        assert_eq!(list.keys().rev().collect::<Vec<_>>(), vec![c, b, a]);
+    }
+
+    #[test]
+    fn sort() {
+        let mut list: LinkedList<i32> = [5, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5].into_iter().collect();
+        let keys: Vec<_> = list.keys().collect();
+        assert!(!list.is_sorted());
+
+        list.sort();
+        assert!(list.is_sorted());
+        assert!(!list.is_sorted_by(|a, b| a < b));
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![1, 1, 2, 3, 4, 5, 5, 5, 5, 6, 9]
+        );
+        assert_eq!(list.iter().next_back(), Some(&9));
+        assert_eq!(list.len(), 11);
+
+        // keys follow their values
+        assert_eq!(list.keys().next_back(), Some(keys[5]));
+        assert_eq!(list.keys().nth(4), Some(keys[2]));
+
+        let mut list: LinkedList<_> = [3, 1, 3, 2, 1, 3].into_iter().enumerate().collect();
+        list.sort_by_key(|(_, key)| *key);
+        assert!(list.is_sorted_by(|a, b| a.1 <= b.1));
+        assert_eq!(
+            list.into_iter().collect::<Vec<_>>(),
+            vec![(1, 1), (4, 1), (3, 2), (0, 3), (2, 3), (5, 3)]
+        );
+
+        let mut list = LinkedList::<i32>::new();
+        list.sort();
+        assert!(list.head_tail.is_none());
+    }
+
+    #[test]
+    fn sort_panic() {
+        // panic at every comparison a full sort makes
+        for panic_at in 1..=30 {
+            let mut list: LinkedList<i32> = [5, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5].into_iter().collect();
+
+            let mut comparisons = 0;
+            let sorted = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                list.sort_by(|a, b| {
+                    comparisons += 1;
+                    assert!(comparisons < panic_at, "comparator panicked");
+                    a.cmp(b)
+                })
+            }));
+            if sorted.is_ok() {
+                break;
+            }
+
+            assert_eq!(list.len(), 11);
+            assert_eq!(list.iter().rev().count(), 11);
+            let mut values = list.iter().copied().collect::<Vec<_>>();
+            values.sort();
+            assert_eq!(values, vec![1, 1, 2, 3, 4, 5, 5, 5, 5, 6, 9]);
+
+            list.sort();
+            assert!(list.is_sorted());
+            assert_eq!(list.iter().rev().count(), 11);
+        }
+    }
+
+    #[test]
+    fn rotate() {
+        let mut list: LinkedList<_> = (0..5).collect();
+
+        list.rotate_left(2);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![2, 3, 4, 0, 1]
+        );
+
+        list.rotate_left(4);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 0]
+        );
+
+        list.rotate_right(1);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![4, 3, 2, 1, 0]
+        );
+
+        list.rotate_left(0);
+        list.rotate_right(5);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+
+        let mut list = LinkedList::<i32>::new();
+        list.rotate_left(0);
+        assert!(list.head_tail.is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn rotate_out_of_bounds() {
+        let mut list: LinkedList<_> = (0..2).collect();
+        list.rotate_right(3);
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut list: LinkedList<_> = (0..6).collect();
+
+        list.insert(0, 10);
+        list.insert(2, 11);
+        list.insert(6, 12);
+        list.insert(list.len(), 13);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![10, 0, 11, 1, 2, 3, 12, 4, 5, 13]
+        );
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![13, 5, 4, 12, 3, 2, 1, 11, 0, 10]
+        );
+
+        assert_eq!(list.remove(0), Some(10));
+        assert_eq!(list.remove(1), Some(11));
+        assert_eq!(list.remove(4), Some(12));
+        assert_eq!(list.remove(6), Some(13));
+        assert_eq!(list.remove(6), None);
+        assert_eq!(list.len(), 6);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+
+        let mut list = LinkedList::new();
+        list.insert(0, 1);
+        assert_eq!(list.remove(0), Some(1));
+        assert!(list.head_tail.is_none());
+    }
+
+    #[test]
+    fn get() {
+        let mut list: LinkedList<_> = (0..5).collect();
+
+        assert_eq!(list.get(0), Some(&0));
+        assert_eq!(list.get(1), Some(&1));
+        assert_eq!(list.get(3), Some(&3));
+        assert_eq!(list.get(4), Some(&4));
+        assert_eq!(list.get(5), None);
+
+        *list.get_mut(3).unwrap() = 30;
+        assert_eq!(list.get_mut(5), None);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![0, 1, 2, 30, 4]
+        );
+
+        assert_eq!(LinkedList::<i32>::new().get(0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_out_of_bounds() {
+        let mut list: LinkedList<_> = (0..2).collect();
+        list.insert(3, 0);
+    }
+
     #[test]
     fn send_sync() {
         let mut list = LinkedList::new();
@@ -278,6 +1645,258 @@ mod test {
         assert_eq!(len, 1);
     }
 
+    #[test]
+    fn append() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+
+        let mut other = LinkedList::new();
+        let three = other.push_back(3);
+        let four = other.push_back(4);
+
+        let mut remapped = Vec::new();
+        list.append_with(&mut other, |old, new| remapped.push((old, new)));
+
+        assert_eq!(other.len(), 0);
+        assert_eq!(other.iter().next(), None);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+        assert_eq!(remapped.len(), 2);
+        assert_eq!(remapped[0].0, three);
+        assert_eq!(remapped[1].0, four);
+        assert_eq!(list.node(remapped[0].1).unwrap().value, 3);
+        assert_eq!(list.node(remapped[1].1).unwrap().value, 4);
+    }
+
+    #[test]
+    fn compact() {
+        let mut list = LinkedList::new();
+        let mut keys = Vec::new();
+
+        for i in 0..8 {
+            keys.push((list.push_front(i), i));
+        }
+        for _ in 0..4 {
+            list.pop_front();
+        }
+
+        let mut remapped = Vec::new();
+        list.compact_with(|old, new| remapped.push((old, new)));
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(remapped.len(), 4);
+
+        for (old, new) in remapped {
+            let (_, value) = keys.iter().find(|(key, _)| *key == old).unwrap();
+            assert_eq!(list.node(new).unwrap().value, *value);
+        }
+    }
+
+    #[test]
+    fn remove_key() {
+        let mut list = LinkedList::new();
+
+        let one = list.push_back(1);
+        let two = list.push_back(2);
+        let three = list.push_back(3);
+
+        assert_eq!(list.remove_key(two), Some(2));
+        assert_eq!(list.remove_key(two), None);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 3]);
+
+        assert_eq!(list.remove_key(three), Some(3));
+        assert_eq!(list.remove_key(one), Some(1));
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.iter().next(), None);
+    }
+
+    #[test]
+    fn retain() {
+        let mut list = LinkedList::new();
+
+        for i in 0..10 {
+            list.push_back(i);
+        }
+
+        list.retain(|i| i % 3 != 0);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 4, 5, 7, 8]
+        );
+        assert_eq!(list.len(), 6);
+
+        list.retain_mut(|i| {
+            *i *= 10;
+            *i > 40
+        });
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![50, 70, 80]);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![80, 70, 50]
+        );
+        assert_eq!(list.len(), 3);
+
+        list.retain(|_| false);
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.iter().next(), None);
+    }
+
+    #[test]
+    fn move_to_front_back() {
+        let mut list = LinkedList::new();
+
+        let one = list.push_back(1);
+        let two = list.push_back(2);
+        let three = list.push_back(3);
+
+        list.move_to_front(two);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 1, 3]);
+
+        list.move_to_front(three);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+
+        list.move_to_front(three);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+
+        list.move_to_back(three);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 1, 3]);
+
+        list.move_to_back(one);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 3, 1]);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![1, 3, 2]
+        );
+        assert_eq!(list.len(), 3);
+
+        let mut single = LinkedList::new();
+        let only = single.push_back(1);
+        single.move_to_back(only);
+        single.move_to_front(only);
+        assert_eq!(single.iter().copied().collect::<Vec<_>>(), vec![1]);
+    }
+
+    new_key_type! {
+        struct NodeKey;
+    }
+
+    fn exercise_map<M: SlotMapLike<NodeKey, Node<i32, NodeKey>>>() {
+        let mut list = LinkedList::<i32, NodeKey, M>::with_map_capacity(4);
+
+        let keys: Vec<NodeKey> = (0..6).map(|i| list.push_back(i)).collect();
+        assert_eq!(list.remove_key(keys[2]), Some(2));
+        assert_eq!(list.remove_key(keys[2]), None);
+        list.move_to_front(keys[5]);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![5, 0, 1, 3, 4]
+        );
+
+        list.sort();
+        list.compact();
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![0, 1, 3, 4, 5]
+        );
+        assert_eq!(list.keys().count(), 5);
+        assert_eq!(
+            list.memory_usage().free_slots,
+            list.memory_usage().nodes - 5
+        );
+
+        let other: LinkedList<i32, NodeKey, M> = list.iter().copied().collect();
+        assert!(list == other);
+    }
+
+    #[test]
+    fn maps() {
+        exercise_map::<SlotMap<_, _>>();
+        exercise_map::<DenseSlotMap<_, _>>();
+        #[allow(deprecated)]
+        exercise_map::<HopSlotMap<_, _>>();
+
+        let mut list: DenseList<&str> = DenseList::with_map_capacity(0);
+        let key: DefaultKey = list.push_back("dense");
+        assert_eq!(list.remove_key(key), Some("dense"));
+    }
+
+    #[test]
+    fn iter_with_keys() {
+        let mut list = LinkedList::new();
+        let b = list.push_back('b');
+        let a = list.push_front('a');
+        let c = list.push_back('c');
+
+        assert_eq!(
+            list.iter_with_keys().collect::<Vec<_>>(),
+            vec![(a, &'a'), (b, &'b'), (c, &'c')]
+        );
+        assert_eq!(
+            list.iter_with_keys()
+                .rev()
+                .map(|(key, _)| key)
+                .collect::<Vec<_>>(),
+            list.keys().rev().collect::<Vec<_>>()
+        );
+        assert!(list.values().eq(list.iter()));
+
+        let mut iter = list.iter_with_keys();
+        assert_eq!(iter.next_back(), Some((c, &'c')));
+        assert_eq!(iter.len(), 2);
+
+        // capture handles while scanning, then act on them
+        let vowels: Vec<_> = list
+            .iter_with_keys()
+            .filter(|(_, value)| "aeiou".contains(**value))
+            .map(|(key, _)| key)
+            .collect();
+        for key in vowels {
+            list.move_to_back(key);
+        }
+        assert_eq!(
+            list.values().copied().collect::<Vec<_>>(),
+            vec!['b', 'c', 'a']
+        );
+    }
+
+    #[test]
+    fn secondary_map() {
+        let mut list = LinkedList::new();
+        let mut weights = list.secondary_map();
+
+        for name in ["a", "b", "c"] {
+            let key = list.push_back(name);
+            weights.insert(key, name.len() * 10);
+        }
+        let keys: Vec<_> = list.keys().collect();
+
+        // keys survive reordering
+        list.sort_by(|a, b| b.cmp(a));
+        assert_eq!(list.get_key(keys[0]), Some(&"a"));
+        *list.get_key_mut(keys[2]).unwrap() = "C";
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec!["C", "b", "a"]
+        );
+        assert_eq!(list.keys().map(|key| weights[key]).sum::<usize>(), 30);
+
+        // a removed key stays dead, even after its slot is reused
+        assert_eq!(list.remove_key(keys[1]), Some("b"));
+        let d = list.push_back("d");
+        assert!(!list.contains_key(keys[1]));
+        assert_eq!(list.get_key(keys[1]), None);
+        assert_eq!(weights.get(d), None);
+
+        weights.retain(|key, _| list.contains_key(key));
+        assert_eq!(weights.len(), 2);
+    }
+
     #[derive(Default)]
     struct Big([usize; 32]);
 
@@ -290,6 +1909,38 @@ mod test {
         }
     }
 
+    #[test]
+    fn try_push() {
+        let mut list = LinkedList::new();
+
+        assert_eq!(list.try_reserve(usize::MAX), Err(AllocError));
+        list.try_reserve(4).unwrap();
+        assert!(list.memory_usage().free_slots >= 4);
+
+        let back = list.try_push_back(2).unwrap();
+        list.try_push_front(1).unwrap();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(list.remove_key(back), Some(2));
+    }
+
+    #[test]
+    fn memory_usage() {
+        let mut list = LinkedList::with_capacity(8);
+        let stats = list.memory_usage();
+        assert!(stats.nodes >= 8);
+        assert_eq!(stats.free_slots, stats.nodes);
+        assert_eq!(stats.total_bytes, stats.nodes * stats.bytes_per_node);
+        assert!(stats.bytes_per_node >= std::mem::size_of::<Node<u64>>());
+
+        for i in 0..3u64 {
+            list.push_back(i);
+        }
+        assert_eq!(list.memory_usage().free_slots, stats.nodes - 3);
+
+        list.pop_front();
+        assert_eq!(list.memory_usage().free_slots, stats.nodes - 2);
+    }
+
     #[test]
     fn node_size() {
         // sizeof: DefaultKey = 8
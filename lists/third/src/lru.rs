@@ -0,0 +1,225 @@
+use core::borrow::Borrow;
+use core::hash::Hash;
+use core::iter::FusedIterator;
+use hashbrown::HashMap;
+
+use crate::{DefaultKey, LinkedList};
+
+/// A least-recently-used cache built on top of the slotmap `LinkedList`.
+///
+/// The list keeps the entries in recency order (most recently used at the front),
+/// while the map points from each cache key to its node in the list.
+/// Lookups, insertions and evictions are all O(1).
+pub struct LruCache<K, V> {
+    capacity: usize,
+    map: HashMap<K, DefaultKey>,
+    list: LinkedList<(K, V)>,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            map: HashMap::with_capacity(capacity),
+            list: LinkedList::with_capacity(capacity),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Returns the value for `key` and marks it as most recently used.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let node_ref = *self.map.get(key)?;
+        self.list.move_to_front(node_ref);
+
+        Some(&self.list.node(node_ref).unwrap().value.1)
+    }
+
+    /// Returns the value for `key` mutably and marks it as most recently used.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let node_ref = *self.map.get(key)?;
+        self.list.move_to_front(node_ref);
+
+        Some(&mut self.list.node_mut(node_ref).unwrap().value.1)
+    }
+
+    /// Returns the value for `key` without changing its recency.
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let node_ref = *self.map.get(key)?;
+
+        Some(&self.list.node(node_ref).unwrap().value.1)
+    }
+
+    /// Inserts `value` as the most recently used entry, returning the previous value for `key`.
+    ///
+    /// If the cache is full, the least recently used entry is evicted.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&node_ref) = self.map.get(&key) {
+            self.list.move_to_front(node_ref);
+
+            let entry = &mut self.list.node_mut(node_ref).unwrap().value.1;
+            return Some(core::mem::replace(entry, value));
+        }
+
+        let node_ref = self.list.push_front((key.clone(), value));
+        self.map.insert(key, node_ref);
+
+        if self.map.len() > self.capacity {
+            self.pop_lru();
+        }
+
+        None
+    }
+
+    /// Removes the entry for `key`, returning its value.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let node_ref = self.map.remove(key)?;
+
+        self.list.remove_key(node_ref).map(|(_, value)| value)
+    }
+
+    /// Removes and returns the least recently used entry.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        let (_, tail) = self.list.head_tail?;
+        let (key, value) = self.list.remove_key(tail).unwrap();
+
+        self.map.remove(&key);
+
+        Some((key, value))
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.list.clear();
+    }
+
+    /// Iterates over the entries from most to least recently used.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter(self.list.iter())
+    }
+}
+
+pub struct Iter<'a, K, V>(crate::Iter<'a, (K, V)>);
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(key, value)| (key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.0.last().map(|(key, value)| (key, value))
+    }
+
+    fn count(self) -> usize {
+        self.0.count()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Iter<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(key, value)| (key, value))
+    }
+}
+
+impl<'a, K, V> FusedIterator for Iter<'a, K, V> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn get_put() {
+        let mut cache = LruCache::new(2);
+
+        assert_eq!(cache.put("a", 1), None);
+        assert_eq!(cache.put("b", 2), None);
+        assert_eq!(cache.get("a"), Some(&1));
+
+        // "b" is the least recently used entry now
+        assert_eq!(cache.put("c", 3), None);
+        assert_eq!(cache.len(), 2);
+        assert!(!cache.contains("b"));
+        assert_eq!(cache.get("b"), None);
+
+        assert_eq!(cache.put("a", 10), Some(1));
+        assert_eq!(cache.peek("a"), Some(&10));
+        assert_eq!(cache.capacity(), 2);
+    }
+
+    #[test]
+    fn pop_lru() {
+        let mut cache = LruCache::new(3);
+
+        cache.put(1, "one");
+        cache.put(2, "two");
+        cache.put(3, "three");
+        cache.get(&1);
+
+        assert_eq!(cache.pop_lru(), Some((2, "two")));
+        assert_eq!(cache.pop_lru(), Some((3, "three")));
+        assert_eq!(cache.pop_lru(), Some((1, "one")));
+        assert_eq!(cache.pop_lru(), None);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn iter() {
+        let mut cache = LruCache::new(3);
+
+        cache.put(1, 'a');
+        cache.put(2, 'b');
+        cache.put(3, 'c');
+        *cache.get_mut(&1).unwrap() = 'z';
+        cache.peek(&2);
+        cache.remove(&3);
+
+        assert_eq!(
+            cache.iter().collect::<Vec<_>>(),
+            vec![(&1, &'z'), (&2, &'b')]
+        );
+        assert_eq!(
+            cache.iter().rev().collect::<Vec<_>>(),
+            vec![(&2, &'b'), (&1, &'z')]
+        );
+    }
+}
@@ -0,0 +1,91 @@
+#![allow(deprecated)]
+
+use core::mem;
+use slotmap::{DenseSlotMap, HopSlotMap, Key, SlotMap};
+
+use crate::AllocError;
+
+/// The parts of a `slotmap` map the [`LinkedList`](crate::LinkedList) needs, so it can store its
+/// nodes in any of them.
+///
+/// - [`SlotMap`] is the default, lookups are a single index and version check
+/// - [`DenseSlotMap`] keeps the nodes packed in a `Vec` at the cost of an extra indirection on
+///   every lookup, which pays off when the list is iterated far more often than it changes
+/// - [`HopSlotMap`] skips over runs of free slots, so it stays fast when lots of nodes get removed
+///   (deprecated upstream since `slotmap` 1.1, but still supported here)
+pub trait SlotMapLike<K: Key, V> {
+    fn with_capacity(capacity: usize) -> Self;
+
+    /// Slots that can be occupied without reallocating, used and free ones.
+    fn capacity(&self) -> usize;
+
+    /// What one slot costs, including the map's own bookkeeping.
+    fn bytes_per_slot(&self) -> usize;
+
+    fn reserve(&mut self, additional: usize);
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError>;
+
+    fn contains_key(&self, key: K) -> bool;
+
+    fn insert(&mut self, value: V) -> K;
+
+    fn remove(&mut self, key: K) -> Option<V>;
+
+    fn get(&self, key: K) -> Option<&V>;
+
+    fn get_mut(&mut self, key: K) -> Option<&mut V>;
+}
+
+macro_rules! impl_slot_map_like {
+    ($map:ident, $bytes_per_slot:expr) => {
+        impl<K: Key, V> SlotMapLike<K, V> for $map<K, V> {
+            fn with_capacity(capacity: usize) -> Self {
+                $map::with_capacity_and_key(capacity)
+            }
+
+            fn capacity(&self) -> usize {
+                $map::capacity(self)
+            }
+
+            fn bytes_per_slot(&self) -> usize {
+                $bytes_per_slot
+            }
+
+            fn reserve(&mut self, additional: usize) {
+                $map::reserve(self, additional)
+            }
+
+            fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+                $map::try_reserve(self, additional).map_err(|_| AllocError)
+            }
+
+            fn contains_key(&self, key: K) -> bool {
+                $map::contains_key(self, key)
+            }
+
+            fn insert(&mut self, value: V) -> K {
+                $map::insert(self, value)
+            }
+
+            fn remove(&mut self, key: K) -> Option<V> {
+                $map::remove(self, key)
+            }
+
+            fn get(&self, key: K) -> Option<&V> {
+                $map::get(self, key)
+            }
+
+            fn get_mut(&mut self, key: K) -> Option<&mut V> {
+                $map::get_mut(self, key)
+            }
+        }
+    };
+}
+
+// a `u32` version next to every value
+impl_slot_map_like!(SlotMap, mem::size_of::<(V, u32)>());
+// the values and their keys live in two `Vec`s, the slots only hold a version and an index
+impl_slot_map_like!(DenseSlotMap, mem::size_of::<V>() + mem::size_of::<K>() + 8);
+// like `SlotMap`, but a free slot holds three `u32`s to find the end of its run
+impl_slot_map_like!(HopSlotMap, mem::size_of::<(V, u32)>().max(16));
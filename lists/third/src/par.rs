@@ -0,0 +1,65 @@
+#![allow(deprecated)]
+
+use alloc::vec::Vec;
+use rayon::prelude::*;
+use slotmap::{DenseSlotMap, HopSlotMap, Key, SlotMap};
+
+use crate::{LinkedList, Node, SlotMapLike};
+
+impl<T: Sync, K: Key, M: SlotMapLike<K, Node<T, K>>> LinkedList<T, K, M> {
+    /// A parallel iterator over the elements, front to back.
+    ///
+    /// The references are collected on the calling thread first, which takes `O(n)`.
+    pub fn par_iter(&self) -> rayon::vec::IntoIter<&T> {
+        self.iter().collect::<Vec<_>>().into_par_iter()
+    }
+}
+
+macro_rules! impl_par_iter_mut {
+    ($map:ident) => {
+        impl<T: Send, K: Key> LinkedList<T, K, $map<K, Node<T, K>>> {
+            /// A parallel iterator over the elements in no particular order, the order the map
+            /// stores them in.
+            ///
+            /// Like [`par_iter`](Self::par_iter), the references are collected first.
+            pub fn par_iter_mut(&mut self) -> rayon::vec::IntoIter<&mut T> {
+                self.arena
+                    .values_mut()
+                    .map(|node| &mut node.value)
+                    .collect::<Vec<_>>()
+                    .into_par_iter()
+            }
+        }
+    };
+}
+
+impl_par_iter_mut!(SlotMap);
+impl_par_iter_mut!(DenseSlotMap);
+impl_par_iter_mut!(HopSlotMap);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::DenseList;
+
+    #[test]
+    fn par_iter() {
+        let mut list: LinkedList<u64> = (0..1000).collect();
+        list.push_front(1000);
+
+        let doubled: Vec<_> = list.par_iter().map(|value| value * 2).collect();
+        assert_eq!(doubled[0], 2000);
+        assert_eq!(
+            doubled[1..],
+            (0..1000).map(|value| value * 2).collect::<Vec<_>>()
+        );
+
+        list.par_iter_mut().for_each(|value| *value += 1);
+        assert_eq!(list.iter().next(), Some(&1001));
+        assert_eq!(list.par_iter().sum::<u64>(), (1..=1001).sum());
+
+        let mut list: DenseList<_> = (0..10).collect();
+        list.par_iter_mut().for_each(|value| *value *= 10);
+        assert_eq!(list.to_vec(), (0..100).step_by(10).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,256 @@
+use core::fmt;
+
+use crate::{DefaultKey, IntoIter, Iter, LinkedList};
+
+/// A [`LinkedList`] that is always sorted in ascending order.
+///
+/// Only operations that keep the order are exposed, equal elements stay in insertion order.
+/// Keys returned by [`insert`](Self::insert) work the same as the list's.
+pub struct SortedList<T: Ord> {
+    list: LinkedList<T>,
+}
+
+impl<T: Ord> SortedList<T> {
+    pub fn new() -> Self {
+        Self {
+            list: LinkedList::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.len() == 0
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.list.iter().next()
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.list.iter().next_back()
+    }
+
+    pub fn get_key(&self, key: DefaultKey) -> Option<&T> {
+        self.list.get_key(key)
+    }
+
+    /// Inserts `value` after all elements that are less than or equal to it.
+    ///
+    /// Walks from the back, so inserting in ascending order takes `O(1)`.
+    pub fn insert(&mut self, value: T) -> DefaultKey {
+        self.list
+            .insert_after_last(value, |elem, value| elem <= value)
+    }
+
+    /// Stops at the first element that isn't less than `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        self.find(value).is_some()
+    }
+
+    /// Removes the first element equal to `value`, stopping early like
+    /// [`contains`](Self::contains).
+    pub fn remove(&mut self, value: &T) -> Option<T> {
+        let key = self.find(value)?;
+
+        self.list.remove_key(key)
+    }
+
+    pub fn remove_key(&mut self, key: DefaultKey) -> Option<T> {
+        self.list.remove_key(key)
+    }
+
+    pub fn pop_first(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    pub fn pop_last(&mut self) -> Option<T> {
+        let (_, tail) = self.list.head_tail?;
+
+        self.list.remove_key(tail)
+    }
+
+    pub fn retain(&mut self, f: impl FnMut(&T) -> bool) {
+        self.list.retain(f)
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear()
+    }
+
+    /// Moves all elements of `other` into `self` in a single pass over both lists, leaving
+    /// `other` empty. On ties the elements already in `self` come first.
+    ///
+    /// The moved elements get new keys.
+    pub fn merge(&mut self, other: &mut Self) {
+        let mut prev = None;
+        let mut next = self.list.head_tail.map(|(head, _)| head);
+
+        while let Some(value) = other.list.pop_front() {
+            while let Some(key) = next {
+                let node = self.list.node(key).unwrap();
+                if node.value > value {
+                    break;
+                }
+                prev = Some(key);
+                next = node.next;
+            }
+
+            let key = self.list.insert_node(value);
+            self.list.link_after(prev, key);
+            self.list.len += 1;
+            prev = Some(key);
+        }
+    }
+
+    pub fn as_list(&self) -> &LinkedList<T> {
+        &self.list
+    }
+
+    pub fn into_list(self) -> LinkedList<T> {
+        self.list
+    }
+
+    fn find(&self, value: &T) -> Option<DefaultKey> {
+        self.list
+            .iter_with_keys()
+            .take_while(|(_, elem)| *elem <= value)
+            .find(|(_, elem)| *elem == value)
+            .map(|(key, _)| key)
+    }
+}
+
+impl<T: Ord> Default for SortedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sorts the list, keeping the keys of its elements.
+impl<T: Ord> From<LinkedList<T>> for SortedList<T> {
+    fn from(mut list: LinkedList<T>) -> Self {
+        list.sort();
+
+        Self { list }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SortedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<LinkedList<T>>())
+    }
+}
+
+impl<T: Ord> Extend<T> for SortedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T: Ord> IntoIterator for SortedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+}
+
+impl<T: Ord + fmt::Debug> fmt::Debug for SortedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.list.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn insert() {
+        let mut list = SortedList::new();
+        assert_eq!(list.first(), None);
+
+        let keys: Vec<_> = [5, 1, 4, 1, 5, 9, 2, 6]
+            .into_iter()
+            .map(|value| list.insert(value))
+            .collect();
+
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![1, 1, 2, 4, 5, 5, 6, 9]
+        );
+        assert_eq!(list.first(), Some(&1));
+        assert_eq!(list.last(), Some(&9));
+        assert_eq!(list.get_key(keys[2]), Some(&4));
+        assert!(list.as_list().is_sorted());
+
+        assert!(list.contains(&6));
+        assert!(!list.contains(&3));
+        assert!(!list.contains(&10));
+
+        assert_eq!(list.remove(&5), Some(5));
+        assert_eq!(list.remove(&3), None);
+        assert_eq!(list.remove_key(keys[0]), None);
+        assert_eq!(list.pop_first(), Some(1));
+        assert_eq!(list.pop_last(), Some(9));
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 4, 5, 6]);
+    }
+
+    #[test]
+    fn merge() {
+        let mut list: SortedList<_> = [(3, 'a'), (1, 'b'), (5, 'c')]
+            .into_iter()
+            .map(|(n, c)| Pair(n, c))
+            .collect();
+        let mut other: SortedList<_> = [(3, 'd'), (0, 'e'), (6, 'f'), (1, 'g')]
+            .into_iter()
+            .map(|(n, c)| Pair(n, c))
+            .collect();
+
+        list.merge(&mut other);
+        assert!(other.is_empty());
+        assert_eq!(list.len(), 7);
+        assert_eq!(
+            list.iter().map(|pair| pair.1).collect::<String>(),
+            "ebgadcf"
+        );
+
+        list.extend([Pair(2, 'h'), Pair(7, 'i')]);
+        list.retain(|pair| pair.0 % 2 == 1);
+        assert_eq!(list.iter().map(|pair| pair.1).collect::<String>(), "bgadci");
+    }
+
+    /// Ordered by the number only, so ties show which side an element came from.
+    #[derive(Debug)]
+    struct Pair(i32, char);
+
+    impl PartialEq for Pair {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Pair {}
+
+    impl PartialOrd for Pair {
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Pair {
+        fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+}
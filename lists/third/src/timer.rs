@@ -0,0 +1,131 @@
+use crate::{DefaultKey, LinkedList};
+
+/// A queue of values waiting for a deadline, kept in deadline order on top of the slotmap
+/// `LinkedList`.
+///
+/// The deadline can be anything ordered, raw `u64` ticks by default or e.g. `Instant`. Values
+/// with the same deadline expire in insertion order. Inserting returns a key that can cancel the
+/// timer in `O(1)` until it expires.
+pub struct TimerQueue<T, D = u64> {
+    list: LinkedList<(D, T)>,
+}
+
+impl<T, D: Ord + Copy> TimerQueue<T, D> {
+    pub fn new() -> Self {
+        Self {
+            list: LinkedList::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.len() == 0
+    }
+
+    /// The earliest pending deadline.
+    pub fn next_deadline(&self) -> Option<D> {
+        self.list.iter().next().map(|(deadline, _)| *deadline)
+    }
+
+    /// Schedules `value` for `deadline`, walking from the latest deadline since timers are mostly
+    /// inserted in order.
+    pub fn insert(&mut self, deadline: D, value: T) -> DefaultKey {
+        self.list
+            .insert_after_last((deadline, value), |(other, _), (deadline, _)| {
+                other <= deadline
+            })
+    }
+
+    pub fn deadline(&self, key: DefaultKey) -> Option<D> {
+        self.list.get_key(key).map(|(deadline, _)| *deadline)
+    }
+
+    pub fn get(&self, key: DefaultKey) -> Option<&T> {
+        self.list.get_key(key).map(|(_, value)| value)
+    }
+
+    pub fn get_mut(&mut self, key: DefaultKey) -> Option<&mut T> {
+        self.list.get_key_mut(key).map(|(_, value)| value)
+    }
+
+    /// Removes a pending timer, returning `None` if it has expired or was cancelled already.
+    pub fn cancel(&mut self, key: DefaultKey) -> Option<T> {
+        self.list.remove_key(key).map(|(_, value)| value)
+    }
+
+    /// Removes all timers whose deadline is at or before `now`, in the order they expire.
+    pub fn pop_expired(&mut self, now: D) -> LinkedList<T> {
+        let mut expired = LinkedList::new();
+
+        while self.next_deadline().is_some_and(|deadline| deadline <= now) {
+            let (_, value) = self.list.pop_front().unwrap();
+            expired.push_back(value);
+        }
+
+        expired
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear()
+    }
+}
+
+impl<T, D: Ord + Copy> Default for TimerQueue<T, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pop_expired() {
+        let mut timers = TimerQueue::new();
+        assert_eq!(timers.next_deadline(), None);
+
+        timers.insert(30, "c");
+        timers.insert(10, "a");
+        timers.insert(30, "d");
+        timers.insert(20, "b");
+        timers.insert(50, "e");
+        assert_eq!(timers.next_deadline(), Some(10));
+
+        assert!(timers.pop_expired(5).iter().next().is_none());
+
+        let expired = timers.pop_expired(30);
+        assert_eq!(
+            expired.into_iter().collect::<Vec<_>>(),
+            vec!["a", "b", "c", "d"]
+        );
+        assert_eq!(timers.len(), 1);
+        assert_eq!(timers.next_deadline(), Some(50));
+
+        assert_eq!(timers.pop_expired(u64::MAX).len(), 1);
+        assert!(timers.is_empty());
+    }
+
+    #[test]
+    fn cancel() {
+        let mut timers = TimerQueue::new();
+
+        let a = timers.insert(10, 'a');
+        let b = timers.insert(20, 'b');
+        let c = timers.insert(5, 'c');
+
+        assert_eq!(timers.deadline(a), Some(10));
+        *timers.get_mut(b).unwrap() = 'B';
+        assert_eq!(timers.cancel(c), Some('c'));
+        assert_eq!(timers.cancel(c), None);
+        assert_eq!(timers.next_deadline(), Some(10));
+
+        let expired = timers.pop_expired(15);
+        assert_eq!(expired.into_iter().collect::<Vec<_>>(), vec!['a']);
+        assert_eq!(timers.cancel(a), None);
+        assert_eq!(timers.get(b), Some(&'B'));
+    }
+}
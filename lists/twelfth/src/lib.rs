@@ -0,0 +1,420 @@
+#![cfg_attr(not(test), no_std)]
+#![forbid(unsafe_code)]
+/// Like `fourth`, but the nodes live in a `bumpalo::Bump` instead of a `typed_arena::Arena`.
+///
+/// A `Bump` isn't typed, so one of them can back lists of different element types along with
+/// anything else allocated during a generation. `Bump::reset` frees all of it at once and keeps the
+/// memory for the next generation, the borrow checker makes sure no list outlives the reset.
+/// [`extend_batch`](LinkedList::extend_batch) allocates a whole run of nodes as one slice.
+///
+/// Allocation size per value:
+/// sizeof: Node<T> = Option<T> + usize + usize
+/// => Overhead of list is between 16 and 24 bytes per entry
+///
+/// Pros:
+/// - No unsafe & fully stable rust
+/// - Allocating is a pointer bump, a batch of nodes is a single allocation
+/// - One arena for everything in a generation, freed in one go
+/// - Popped nodes go on a free list and are reused
+///
+/// Cons:
+/// - `Bump` never runs destructors, values still in a list when the list is dropped or the `Bump`
+///   is reset are leaked (call `clear` first if they own resources)
+/// - Needs a `GhostToken` for every operation
+/// - Memory is only given back when the whole `Bump` is reset or dropped
+extern crate alloc;
+
+use alloc::vec::Vec;
+use bumpalo::Bump;
+use core::iter::FusedIterator;
+use ghost_cell::{GhostCell, GhostToken};
+
+#[cfg(feature = "invariants")]
+pub use invariants::InvariantError;
+
+pub struct LinkedList<'arena, 'id, T> {
+    bump: &'arena Bump,
+    len: usize,
+    head_tail: Option<(NodeRef<'arena, 'id, T>, NodeRef<'arena, 'id, T>)>,
+    /// Popped nodes, chained through `next`, handed out again before allocating new ones.
+    free: Option<NodeRef<'arena, 'id, T>>,
+}
+
+impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
+    pub fn new(bump: &'arena Bump) -> Self {
+        Self {
+            bump,
+            len: 0,
+            head_tail: None,
+            free: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head_tail.is_none()
+    }
+
+    pub fn iter<'a>(&'a self, token: &'a GhostToken<'id>) -> Iter<'a, 'arena, 'id, T> {
+        Iter {
+            token,
+            head_tail: self.head_tail,
+            len: self.len,
+        }
+    }
+
+    pub fn to_vec(&self, token: &GhostToken<'id>) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter(token).cloned().collect()
+    }
+
+    pub fn front<'a>(&'a self, token: &'a GhostToken<'id>) -> Option<&'a T> {
+        self.head_tail
+            .map(|(head, _)| head.borrow(token).value.as_ref().unwrap())
+    }
+
+    pub fn back<'a>(&'a self, token: &'a GhostToken<'id>) -> Option<&'a T> {
+        self.head_tail
+            .map(|(_, tail)| tail.borrow(token).value.as_ref().unwrap())
+    }
+
+    pub fn front_mut<'a>(&'a mut self, token: &'a mut GhostToken<'id>) -> Option<&'a mut T> {
+        self.head_tail
+            .map(|(head, _)| head.borrow_mut(token).value.as_mut().unwrap())
+    }
+
+    pub fn back_mut<'a>(&'a mut self, token: &'a mut GhostToken<'id>) -> Option<&'a mut T> {
+        self.head_tail
+            .map(|(_, tail)| tail.borrow_mut(token).value.as_mut().unwrap())
+    }
+
+    pub fn push_front(&mut self, value: T, token: &mut GhostToken<'id>) {
+        let node = self.insert(value, token);
+        self.link_front(node, node, token);
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, value: T, token: &mut GhostToken<'id>) {
+        let node = self.insert(value, token);
+        self.link_back(node, node, token);
+        self.len += 1;
+    }
+
+    /// Pushes every value to the back, allocating all of their nodes as one slice.
+    ///
+    /// Free nodes are not reused, the batch always comes fresh from the `Bump`.
+    pub fn extend_batch<I>(&mut self, values: I, token: &mut GhostToken<'id>)
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let mut nodes = self
+            .bump
+            .alloc_slice_fill_iter(values.into_iter().map(Node::new))
+            .iter_mut()
+            .map(|node| &*GhostCell::from_mut(node));
+
+        let Some(first) = nodes.next() else {
+            return;
+        };
+        let mut last = first;
+        self.len += 1;
+
+        for node in nodes {
+            last.borrow_mut(token).next = Some(node);
+            node.borrow_mut(token).prev = Some(last);
+            last = node;
+            self.len += 1;
+        }
+
+        self.link_back(first, last, token);
+    }
+
+    pub fn pop_front(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+        let (head, tail) = self.head_tail.take()?;
+        self.len -= 1;
+
+        if head.as_ptr() != tail.as_ptr() {
+            let next = head.borrow_mut(token).next.take().unwrap();
+            next.borrow_mut(token).prev = None;
+
+            self.head_tail = Some((next, tail));
+        }
+
+        Some(self.recycle(head, token))
+    }
+
+    pub fn pop_back(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+        let (head, tail) = self.head_tail.take()?;
+        self.len -= 1;
+
+        if head.as_ptr() != tail.as_ptr() {
+            let prev = tail.borrow_mut(token).prev.take().unwrap();
+            prev.borrow_mut(token).next = None;
+
+            self.head_tail = Some((head, prev));
+        }
+
+        Some(self.recycle(tail, token))
+    }
+
+    pub fn clear(&mut self, token: &mut GhostToken<'id>) {
+        while self.pop_back(token).is_some() {}
+    }
+
+    fn insert(&mut self, value: T, token: &mut GhostToken<'id>) -> NodeRef<'arena, 'id, T> {
+        if let Some(node_ref) = self.free {
+            let node = node_ref.borrow_mut(token);
+            self.free = node.next.take();
+            node.value = Some(value);
+
+            return node_ref;
+        }
+
+        GhostCell::from_mut(self.bump.alloc_with(|| Node::new(value)))
+    }
+
+    /// Takes the value out of an unlinked node and puts the node on the free list.
+    fn recycle(&mut self, node_ref: NodeRef<'arena, 'id, T>, token: &mut GhostToken<'id>) -> T {
+        let node = node_ref.borrow_mut(token);
+
+        //  If the node still has a prev and next, they are leaked.
+        debug_assert!(node.prev.is_none());
+        debug_assert!(node.next.is_none());
+
+        let value = node.value.take().unwrap();
+        node.next = self.free.replace(node_ref);
+
+        value
+    }
+
+    /// Links the chain `first..=last` in before the head.
+    fn link_front(
+        &mut self,
+        first: NodeRef<'arena, 'id, T>,
+        last: NodeRef<'arena, 'id, T>,
+        token: &mut GhostToken<'id>,
+    ) {
+        let head_tail = match self.head_tail {
+            Some((head, tail)) => {
+                last.borrow_mut(token).next = Some(head);
+                head.borrow_mut(token).prev = Some(last);
+
+                (first, tail)
+            }
+            None => (first, last),
+        };
+
+        self.head_tail = Some(head_tail);
+    }
+
+    /// Links the chain `first..=last` in after the tail.
+    fn link_back(
+        &mut self,
+        first: NodeRef<'arena, 'id, T>,
+        last: NodeRef<'arena, 'id, T>,
+        token: &mut GhostToken<'id>,
+    ) {
+        let head_tail = match self.head_tail {
+            Some((head, tail)) => {
+                tail.borrow_mut(token).next = Some(first);
+                first.borrow_mut(token).prev = Some(tail);
+
+                (head, last)
+            }
+            None => (first, last),
+        };
+
+        self.head_tail = Some(head_tail);
+    }
+}
+
+#[cfg(feature = "invariants")]
+impl<'arena, 'id, T> LinkedList<'arena, 'id, T> {
+    /// Walks the list and its free list, checking that the links match up, that `len` is right and
+    /// that exactly the free nodes have no value.
+    pub fn check_invariants(&self, token: &GhostToken<'id>) -> Result<(), InvariantError> {
+        invariants::check_links(
+            self.head_tail,
+            Some(self.len),
+            |node| {
+                let node = node.borrow(token);
+                node.value.as_ref()?;
+                Some((node.prev, node.next))
+            },
+            |a, b| core::ptr::eq(*a, *b),
+        )?;
+
+        // the free list isn't counted, a cycle in it is caught by bounding the walk to the bump's
+        // capacity instead
+        let mut free = self.free;
+        let mut free_len = 0;
+        while let Some(node) = free {
+            let node = node.borrow(token);
+            if node.value.is_some() {
+                return Err(InvariantError::Other("a node on the free list has a value"));
+            }
+            free_len += 1;
+            if free_len > self.bump.allocated_bytes() {
+                return Err(InvariantError::Other("the free list runs in a cycle"));
+            }
+            free = node.next;
+        }
+
+        Ok(())
+    }
+}
+
+struct Node<'arena, 'id, T> {
+    value: Option<T>,
+    prev: Option<NodeRef<'arena, 'id, T>>,
+    next: Option<NodeRef<'arena, 'id, T>>,
+}
+
+impl<'arena, 'id, T> Node<'arena, 'id, T> {
+    fn new(value: T) -> Self {
+        Self {
+            value: Some(value),
+            prev: None,
+            next: None,
+        }
+    }
+}
+
+type NodeRef<'arena, 'id, T> = &'arena GhostCell<'id, Node<'arena, 'id, T>>;
+
+pub struct Iter<'a, 'arena, 'id, T> {
+    token: &'a GhostToken<'id>,
+    head_tail: Option<(NodeRef<'arena, 'id, T>, NodeRef<'arena, 'id, T>)>,
+    len: usize,
+}
+
+impl<'a, 'arena, 'id, T> Iterator for Iter<'a, 'arena, 'id, T>
+where
+    'arena: 'a,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        let node = head.borrow(self.token);
+
+        if head.as_ptr() != tail.as_ptr() {
+            self.head_tail = node.next.map(|n| (n, tail));
+        }
+        self.len -= 1;
+
+        Some(node.value.as_ref().unwrap())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, 'arena, 'id, T> DoubleEndedIterator for Iter<'a, 'arena, 'id, T>
+where
+    'arena: 'a,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        let node = tail.borrow(self.token);
+
+        if head.as_ptr() != tail.as_ptr() {
+            self.head_tail = node.prev.map(|n| (head, n));
+        }
+        self.len -= 1;
+
+        Some(node.value.as_ref().unwrap())
+    }
+}
+
+impl<'a, 'arena, 'id, T> FusedIterator for Iter<'a, 'arena, 'id, T> where 'arena: 'a {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn push_pop() {
+        GhostToken::new(|ref mut token| {
+            let bump = Bump::new();
+            let mut list = LinkedList::new(&bump);
+            assert_eq!(list.pop_front(token), None);
+            assert_eq!(list.pop_back(token), None);
+
+            list.push_back(2, token);
+            list.push_back(3, token);
+            list.push_front(1, token);
+            assert_eq!(list.len(), 3);
+            assert_eq!(list.front(token), Some(&1));
+            assert_eq!(list.back(token), Some(&3));
+
+            assert_eq!(list.pop_back(token), Some(3));
+            assert_eq!(list.pop_front(token), Some(1));
+            assert_eq!(list.pop_front(token), Some(2));
+            assert_eq!(list.pop_back(token), None);
+            assert!(list.is_empty());
+
+            // popped nodes are reused
+            let allocated = bump.allocated_bytes();
+            list.push_back(4, token);
+            list.push_front(5, token);
+            assert_eq!(bump.allocated_bytes(), allocated);
+            assert_eq!(list.to_vec(token), vec![5, 4]);
+        });
+    }
+
+    #[test]
+    fn batch() {
+        GhostToken::new(|ref mut token| {
+            let bump = Bump::new();
+            let mut list = LinkedList::new(&bump);
+
+            list.extend_batch(Vec::new(), token);
+            assert!(list.is_empty());
+
+            list.push_back(0, token);
+            list.extend_batch(1..4, token);
+            list.extend_batch([4], token);
+            *list.back_mut(token).unwrap() *= 10;
+            assert_eq!(list.len(), 5);
+            assert_eq!(list.to_vec(token), vec![0, 1, 2, 3, 40]);
+            assert_eq!(
+                list.iter(token).rev().copied().collect::<Vec<_>>(),
+                vec![40, 3, 2, 1, 0]
+            );
+        });
+    }
+
+    #[test]
+    fn generations() {
+        let mut bump = Bump::new();
+
+        for _ in 0..3 {
+            GhostToken::new(|ref mut token| {
+                let mut ints = LinkedList::new(&bump);
+                let mut strs = LinkedList::new(&bump);
+
+                ints.extend_batch(0..100, token);
+                strs.push_back("gen", token);
+                assert_eq!(ints.iter(token).sum::<i32>(), 4950);
+                assert_eq!(strs.pop_front(token), Some("gen"));
+            });
+
+            // every list borrowing the bump is gone, so it can be reset
+            bump.reset();
+        }
+    }
+}
@@ -0,0 +1,297 @@
+#![forbid(unsafe_code)]
+
+//! A serializable log of list operations and an interpreter for every list variant.
+//!
+//! Property tests, benchmarks and fuzzers all describe their workload as a `Vec<Op<T>>`
+//! and replay it through the interpreters, with [`model`] (a `VecDeque`) as the reference.
+//! New list methods get a variant here and are then covered everywhere at once.
+//!
+//! Every interpreter drives its list through [`ListOps`]. Ops a variant has no native method for
+//! fall back to pushes and pops, so every log is valid for every variant.
+use bumpalo::Bump;
+use ghost_cell::GhostToken;
+use list_traits::ListOps;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use typed_arena::Arena;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Op<T> {
+    PushFront(T),
+    PushBack(T),
+    PopFront,
+    PopBack,
+    Len,
+    Clear,
+    /// The index is taken modulo `len + 1`, so it's always in bounds.
+    Insert(usize, T),
+    /// The index is taken modulo `len`, removing from an empty list yields `None`.
+    Remove(usize),
+    Append(Vec<T>),
+    /// Splits off everything from the index (modulo `len + 1`) onwards.
+    SplitOff(usize),
+}
+
+/// What a single op returned, ops without a return value don't produce an output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Output<T> {
+    Popped(Option<T>),
+    Len(usize),
+    Removed(Option<T>),
+    Split(Vec<T>),
+}
+
+/// The outputs of a replayed log, followed by whatever was left in the list (front to back).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace<T> {
+    pub outputs: Vec<Output<T>>,
+    pub contents: Vec<T>,
+}
+
+pub type Interpreter<T> = fn(&[Op<T>]) -> Trace<T>;
+
+/// Every list variant, by crate name.
+pub fn interpreters<T: Clone>() -> [(&'static str, Interpreter<T>); 14] {
+    [
+        ("first", first),
+        ("second", second),
+        ("third", third),
+        ("fourth", fourth),
+        ("fifth", fifth),
+        ("sixth", sixth),
+        ("seventh", seventh),
+        ("eighth", eighth),
+        ("ninth", ninth),
+        ("tenth", tenth),
+        ("eleventh", eleventh),
+        ("twelfth", twelfth),
+        ("thirteenth", thirteenth),
+        ("fourteenth", fourteenth),
+    ]
+}
+
+/// A pseudo random log of `len` ops, the same `seed` always gives the same log.
+///
+/// Pushes are twice as likely as pops so the list grows over time. Only the ops every variant
+/// supports natively are generated, so benchmarks don't measure the fallbacks.
+pub fn generate(seed: u64, len: usize) -> Vec<Op<u32>> {
+    // xorshift64, zero is its only fixed point
+    let mut state = seed | 1;
+
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+
+            let value = (state >> 32) as u32;
+            match state % 16 {
+                0..=4 => Op::PushBack(value),
+                5..=9 => Op::PushFront(value),
+                10..=12 => Op::PopFront,
+                13 | 14 => Op::PopBack,
+                _ => Op::Len,
+            }
+        })
+        .collect()
+}
+
+/// Runs `ops` on a list that may already hold elements, the interpreters below start out empty.
+pub fn replay<T: Clone, L: ListOps<T>>(
+    list: &mut L,
+    cx: &mut L::Context,
+    ops: &[Op<T>],
+) -> Trace<T> {
+    let outputs = ops
+        .iter()
+        .cloned()
+        .filter_map(|op| match op {
+            Op::PushFront(value) => {
+                list.push_front(value, cx);
+                None
+            }
+            Op::PushBack(value) => {
+                list.push_back(value, cx);
+                None
+            }
+            Op::PopFront => Some(Output::Popped(list.pop_front(cx))),
+            Op::PopBack => Some(Output::Popped(list.pop_back(cx))),
+            Op::Len => Some(Output::Len(list.len(cx))),
+            Op::Clear => {
+                list.clear(cx);
+                None
+            }
+            Op::Insert(index, value) => {
+                list.insert(index % (list.len(cx) + 1), value, cx);
+                None
+            }
+            Op::Remove(index) => Some(Output::Removed(match list.len(cx) {
+                0 => None,
+                len => list.remove(index % len, cx),
+            })),
+            Op::Append(values) => {
+                list.append(values, cx);
+                None
+            }
+            Op::SplitOff(index) => {
+                let index = index % (list.len(cx) + 1);
+                Some(Output::Split(list.split_off(index, cx)))
+            }
+        })
+        .collect();
+
+    // draining also empties the list, which `second` requires before drop
+    Trace {
+        outputs,
+        contents: std::iter::from_fn(|| list.pop_front(cx)).collect(),
+    }
+}
+
+pub fn model<T: Clone>(ops: &[Op<T>]) -> Trace<T> {
+    replay(&mut VecDeque::new(), &mut (), ops)
+}
+
+pub fn first<T: Clone>(ops: &[Op<T>]) -> Trace<T> {
+    replay(&mut first::LinkedList::new(), &mut (), ops)
+}
+
+pub fn second<T: Clone>(ops: &[Op<T>]) -> Trace<T> {
+    GhostToken::new(|ref mut token| replay(&mut second::LinkedList::new(), token, ops))
+}
+
+pub fn third<T: Clone>(ops: &[Op<T>]) -> Trace<T> {
+    replay(&mut third::LinkedList::new(), &mut (), ops)
+}
+
+pub fn fourth<T: Clone>(ops: &[Op<T>]) -> Trace<T> {
+    GhostToken::new(|ref mut token| {
+        let arena = Arena::new();
+        replay(&mut fourth::LinkedList::new(&arena), token, ops)
+    })
+}
+
+pub fn fifth<T: Clone>(ops: &[Op<T>]) -> Trace<T> {
+    GhostToken::new(|ref mut token| {
+        let arena = Arena::new();
+        replay(&mut fifth::LinkedList::new(&arena), token, ops)
+    })
+}
+
+pub fn sixth<T: Clone>(ops: &[Op<T>]) -> Trace<T> {
+    GhostToken::new(|ref mut token| {
+        let list = sixth::LinkedList::new();
+        replay(&mut &list, token, ops)
+    })
+}
+
+pub fn seventh<T: Clone>(ops: &[Op<T>]) -> Trace<T> {
+    replay(&mut seventh::LinkedList::new(), &mut (), ops)
+}
+
+pub fn eighth<T: Clone>(ops: &[Op<T>]) -> Trace<T> {
+    replay(&mut eighth::LinkedList::new(), &mut (), ops)
+}
+
+pub fn ninth<T: Clone>(ops: &[Op<T>]) -> Trace<T> {
+    replay(&mut ninth::LinkedList::new(), &mut (), ops)
+}
+
+pub fn tenth<T: Clone>(ops: &[Op<T>]) -> Trace<T> {
+    replay(&mut tenth::LinkedList::new(), &mut (), ops)
+}
+
+pub fn eleventh<T: Clone>(ops: &[Op<T>]) -> Trace<T> {
+    replay(&mut eleventh::LinkedList::new(), &mut (), ops)
+}
+
+pub fn twelfth<T: Clone>(ops: &[Op<T>]) -> Trace<T> {
+    GhostToken::new(|ref mut token| {
+        let bump = Bump::new();
+        replay(&mut twelfth::LinkedList::new(&bump), token, ops)
+    })
+}
+
+pub fn thirteenth<T: Clone>(ops: &[Op<T>]) -> Trace<T> {
+    replay(&mut thirteenth::LinkedList::new(), &mut (), ops)
+}
+
+pub fn fourteenth<T: Clone>(ops: &[Op<T>]) -> Trace<T> {
+    replay(&mut fourteenth::LinkedList::new(), &mut (), ops)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fixed_log() {
+        let ops = vec![
+            Op::PushBack(1),
+            Op::PushFront(0),
+            Op::PushBack(2),
+            Op::Len,
+            Op::PopBack,
+            Op::PopFront,
+            Op::Clear,
+            Op::PopFront,
+            Op::PushBack(3),
+        ];
+
+        let trace = model(&ops);
+        assert_eq!(
+            trace.outputs,
+            vec![
+                Output::Len(3),
+                Output::Popped(Some(2)),
+                Output::Popped(Some(0)),
+                Output::Popped(None),
+            ]
+        );
+        assert_eq!(trace.contents, vec![3]);
+
+        for (name, interpret) in interpreters() {
+            assert_eq!(interpret(&ops), trace, "{name}");
+        }
+    }
+
+    #[test]
+    fn positional_log() {
+        let ops = vec![
+            Op::PushBack(1),
+            Op::PushBack(2),
+            Op::PushBack(3),
+            Op::Insert(1, 9),
+            Op::Insert(10, 8),
+            Op::Remove(7),
+            Op::Append(vec![4, 5]),
+            Op::SplitOff(4),
+            Op::Len,
+            Op::Clear,
+            Op::Remove(0),
+            Op::Insert(3, 7),
+        ];
+
+        let trace = model(&ops);
+        assert_eq!(
+            trace.outputs,
+            vec![
+                Output::Removed(Some(9)),
+                Output::Split(vec![4, 5]),
+                Output::Len(4),
+                Output::Removed(None),
+            ]
+        );
+        assert_eq!(trace.contents, vec![7]);
+
+        for (name, interpret) in interpreters() {
+            assert_eq!(interpret(&ops), trace, "{name}");
+        }
+    }
+
+    #[test]
+    fn generate_is_deterministic() {
+        assert_eq!(generate(7, 100), generate(7, 100));
+        assert_ne!(generate(7, 100), generate(8, 100));
+    }
+}
@@ -0,0 +1,28 @@
+use listslistslists_ops::{interpreters, model, Op};
+use proptest::prelude::*;
+
+fn op() -> impl Strategy<Value = Op<u8>> {
+    prop_oneof![
+        3 => any::<u8>().prop_map(Op::PushFront),
+        3 => any::<u8>().prop_map(Op::PushBack),
+        2 => Just(Op::PopFront),
+        2 => Just(Op::PopBack),
+        1 => Just(Op::Len),
+        1 => Just(Op::Clear),
+        2 => (any::<usize>(), any::<u8>()).prop_map(|(index, value)| Op::Insert(index, value)),
+        1 => any::<usize>().prop_map(Op::Remove),
+        1 => prop::collection::vec(any::<u8>(), 0..8).prop_map(Op::Append),
+        1 => any::<usize>().prop_map(Op::SplitOff),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn matches_model(ops in prop::collection::vec(op(), 0..64)) {
+        let expected = model(&ops);
+
+        for (name, interpret) in interpreters() {
+            prop_assert_eq!(interpret(&ops), expected.clone(), "{}", name);
+        }
+    }
+}
@@ -0,0 +1,283 @@
+#![cfg_attr(not(test), no_std)]
+#![forbid(unsafe_code)]
+
+//! A canonical byte format for list contents, shared by all list variants.
+//!
+//! The format only describes the elements in order, never the internal representation,
+//! so a snapshot taken from one variant can be restored into any other one.
+//!
+//! Layout:
+//! ```text
+//! magic: b"LIST" | version: u8 | len: u64 (LE) | len * (size: u32 (LE) | element: [u8; size])
+//! ```
+//! Every element is encoded with `postcard`.
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::{Deserialize, Serialize};
+
+pub use serde;
+
+pub const MAGIC: [u8; 4] = *b"LIST";
+pub const VERSION: u8 = 1;
+
+const HEADER_LEN: usize = MAGIC.len() + 1 + 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The bytes don't start with [`MAGIC`].
+    InvalidMagic,
+    /// The snapshot was written by a newer, incompatible version of the format.
+    UnsupportedVersion(u8),
+    /// The snapshot ended in the middle of the header or an element.
+    UnexpectedEnd,
+    /// There are bytes left after the last element, or inside an element after its value.
+    TrailingBytes,
+    /// An element could not be encoded or decoded.
+    Element(postcard::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidMagic => f.write_str("not a list snapshot"),
+            Error::UnsupportedVersion(version) => {
+                write!(f, "unsupported snapshot version {}", version)
+            }
+            Error::UnexpectedEnd => f.write_str("snapshot ended unexpectedly"),
+            Error::TrailingBytes => f.write_str("snapshot has trailing bytes"),
+            Error::Element(err) => write!(f, "failed to encode or decode element: {}", err),
+        }
+    }
+}
+
+impl From<postcard::Error> for Error {
+    fn from(err: postcard::Error) -> Self {
+        Error::Element(err)
+    }
+}
+
+/// Writes a snapshot one element at a time.
+pub struct Encoder {
+    len: u64,
+    bytes: Vec<u8>,
+}
+
+impl Encoder {
+    pub fn new() -> Self {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&MAGIC);
+        bytes.push(VERSION);
+        // patched in `finish` once the number of elements is known
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+
+        Self { len: 0, bytes }
+    }
+
+    pub fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let element = postcard::to_allocvec(value)?;
+        let size = u32::try_from(element.len())
+            .map_err(|_| Error::Element(postcard::Error::SerializeBufferFull))?;
+
+        self.bytes.extend_from_slice(&size.to_le_bytes());
+        self.bytes.extend_from_slice(&element);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Vec<u8> {
+        self.bytes[MAGIC.len() + 1..HEADER_LEN].copy_from_slice(&self.len.to_le_bytes());
+        self.bytes
+    }
+}
+
+impl Default for Encoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Encodes all `values` in order.
+pub fn encode<'a, T, I>(values: I) -> Result<Vec<u8>, Error>
+where
+    T: Serialize + 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    let mut encoder = Encoder::new();
+
+    for value in values {
+        encoder.push(value)?;
+    }
+
+    Ok(encoder.finish())
+}
+
+/// Validates the header of `bytes` and returns an iterator decoding the elements in order.
+///
+/// The iterator yields an error (and then stops) if an element is malformed or if there are
+/// bytes left after the last element.
+pub fn decode<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<Decoder<'de, T>, Error> {
+    if bytes.len() < HEADER_LEN {
+        return Err(Error::UnexpectedEnd);
+    }
+    if bytes[..MAGIC.len()] != MAGIC {
+        return Err(Error::InvalidMagic);
+    }
+    if bytes[MAGIC.len()] != VERSION {
+        return Err(Error::UnsupportedVersion(bytes[MAGIC.len()]));
+    }
+
+    let len = u64::from_le_bytes(bytes[MAGIC.len() + 1..HEADER_LEN].try_into().unwrap());
+
+    Ok(Decoder {
+        remaining: len,
+        bytes: &bytes[HEADER_LEN..],
+        _marker: PhantomData,
+    })
+}
+
+pub struct Decoder<'de, T> {
+    remaining: u64,
+    bytes: &'de [u8],
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'de, T> Decoder<'de, T> {
+    /// Number of elements left in the snapshot, saturating at `usize::MAX`.
+    pub fn len(&self) -> usize {
+        usize::try_from(self.remaining).unwrap_or(usize::MAX)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// Number of elements worth pre-allocating for.
+    ///
+    /// Unlike [`Decoder::len`] this is bounded by the size of the input,
+    /// so a malformed length can't trigger a huge allocation.
+    pub fn capacity_hint(&self) -> usize {
+        self.len().min(self.bytes.len() / 4)
+    }
+
+    fn fail(&mut self, err: Error) -> Option<Result<T, Error>> {
+        self.remaining = 0;
+        self.bytes = &[];
+        Some(Err(err))
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Iterator for Decoder<'de, T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            if !self.bytes.is_empty() {
+                return self.fail(Error::TrailingBytes);
+            }
+            return None;
+        }
+
+        if self.bytes.len() < 4 {
+            return self.fail(Error::UnexpectedEnd);
+        }
+        let (size, rest) = self.bytes.split_at(4);
+        let size = u32::from_le_bytes(size.try_into().unwrap()) as usize;
+
+        if rest.len() < size {
+            return self.fail(Error::UnexpectedEnd);
+        }
+        let (element, rest) = rest.split_at(size);
+
+        match postcard::take_from_bytes(element) {
+            Ok((value, [])) => {
+                self.remaining -= 1;
+                self.bytes = rest;
+                Some(Ok(value))
+            }
+            Ok((_, _)) => self.fail(Error::TrailingBytes),
+            Err(err) => self.fail(Error::Element(err)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the header can claim any length, and one more item may be the error that ends it
+        let upper = usize::try_from(self.remaining)
+            .ok()
+            .and_then(|n| n.checked_add(1));
+
+        (0, upper)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let bytes = encode(&[1u32, 2, 3]).unwrap();
+
+        let decoder = decode::<u32>(&bytes).unwrap();
+        assert_eq!(decoder.len(), 3);
+        assert_eq!(decoder.collect::<Result<Vec<_>, _>>(), Ok(vec![1, 2, 3]));
+
+        let bytes = encode::<String, _>(&[]).unwrap();
+        assert_eq!(bytes.len(), HEADER_LEN);
+        assert_eq!(decode::<String>(&bytes).unwrap().next(), None);
+    }
+
+    #[test]
+    fn invalid() {
+        let bytes = encode(&[String::from("a"), String::from("b")]).unwrap();
+
+        assert!(matches!(
+            decode::<String>(&bytes[..3]),
+            Err(Error::UnexpectedEnd)
+        ));
+        assert!(matches!(
+            decode::<String>(b"NOPE\x01\0\0\0\0\0\0\0\0"),
+            Err(Error::InvalidMagic)
+        ));
+
+        let mut newer = bytes.clone();
+        newer[4] = VERSION + 1;
+        assert!(matches!(
+            decode::<String>(&newer),
+            Err(Error::UnsupportedVersion(_))
+        ));
+
+        let truncated = decode::<String>(&bytes[..bytes.len() - 1]).unwrap();
+        assert_eq!(
+            truncated.collect::<Result<Vec<_>, _>>(),
+            Err(Error::UnexpectedEnd)
+        );
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(
+            decode::<String>(&trailing)
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>(),
+            Err(Error::TrailingBytes)
+        );
+    }
+
+    #[test]
+    fn malformed_len() {
+        let mut bytes = encode(&[1u8]).unwrap();
+        bytes[MAGIC.len() + 1..HEADER_LEN].copy_from_slice(&u64::MAX.to_le_bytes());
+
+        let decoder = decode::<u8>(&bytes).unwrap();
+        assert_eq!(decoder.len(), usize::MAX);
+        assert_eq!(decoder.size_hint(), (0, None));
+        assert!(decoder.capacity_hint() <= bytes.len());
+        assert_eq!(
+            decoder.collect::<Result<Vec<_>, _>>(),
+            Err(Error::UnexpectedEnd)
+        );
+    }
+}
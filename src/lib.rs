@@ -0,0 +1,183 @@
+#![cfg_attr(not(test), no_std)]
+#![forbid(unsafe_code)]
+
+//! Every list implementation in one crate, each behind a feature of the same name (all on by default).
+//!
+//! | module           | feature        | nodes                              | links                       |
+//! |------------------|----------------|------------------------------------|-----------------------------|
+//! | [`rc`]           | `rc`           | heap, one allocation per node      | `Rc<RefCell<_>>`            |
+//! | [`ghostcell`]    | `ghostcell`    | heap, one allocation per node      | `StaticRc` + `GhostCell`    |
+//! | [`slotmap`]      | `slotmap`      | `SlotMap`                          | keys                        |
+//! | [`arena`]        | `arena`        | borrowed `typed_arena::Arena`      | `&GhostCell`                |
+//! | [`arena_rc`]     | `arena-rc`     | borrowed `typed_arena::Arena`      | `StaticRcRef` + `GhostCell` |
+//! | [`owned_arena`]  | `owned-arena`  | `typed_arena::Arena` owned by list | `StaticRcRef` + `GhostCell` |
+//! | [`compact`]      | `compact`      | one `Vec`                          | `u32` indices               |
+//! | [`ring`]         | `ring`         | one `Vec`, circular                | `u32` indices               |
+//! | [`indexed`]      | `indexed`      | heap, one allocation per node      | treap with subtree sizes    |
+//! | [`shared`]       | `shared`       | heap, one allocation per node      | `Arc<Mutex<_>>`             |
+//! | [`functional`]   | `functional`   | heap, one allocation per node      | two immutable `Rc` lists    |
+//! | [`bump`]         | `bump`         | borrowed `bumpalo::Bump`           | `&GhostCell`                |
+//! | [`slab`]         | `slab`         | `Slab`                             | `usize` keys                |
+//! | [`generational`] | `generational` | `generational_arena::Arena`        | `Index` keys                |
+//!
+//! Every module exposes its list as `LinkedList`.
+//!
+//! The variants' own features (`alloc`, `serde`, `snapshot`, `arbitrary`, `futures`, `rayon`,
+//! `debug-viz`, `invariants` and `nightly`) have the same name here and turn on in every enabled
+//! variant that has them.
+
+/// `Rc` and `RefCell`, no tokens needed.
+#[cfg(feature = "rc")]
+pub mod rc {
+    pub use first::*;
+}
+
+/// Heap allocated nodes owned through `StaticRc` halves, the list must be cleared before drop.
+#[cfg(feature = "ghostcell")]
+pub mod ghostcell {
+    pub use second::*;
+}
+
+/// Nodes in a `SlotMap`, no tokens and stable keys for every element.
+#[cfg(feature = "slotmap")]
+pub mod slotmap {
+    pub use third::*;
+}
+
+/// Nodes in a `typed_arena::Arena` the caller owns, linked with plain references.
+#[cfg(feature = "arena")]
+pub mod arena {
+    pub use fourth::*;
+}
+
+/// Nodes in a `typed_arena::Arena` the caller owns, owned through `StaticRcRef` halves.
+#[cfg(feature = "arena-rc")]
+pub mod arena_rc {
+    pub use fifth::*;
+}
+
+/// Like [`arena_rc`], but the list owns its arena.
+#[cfg(feature = "owned-arena")]
+pub mod owned_arena {
+    pub use sixth::*;
+}
+
+/// All nodes in one `Vec`, linked by `u32` indices, no tokens and the smallest nodes.
+#[cfg(feature = "compact")]
+pub mod compact {
+    pub use seventh::*;
+}
+
+/// Like [`compact`], but the tail links back to the head and rotating only moves the head.
+#[cfg(feature = "ring")]
+pub mod ring {
+    pub use eighth::*;
+}
+
+/// A balanced tree posing as a list, `get`, `insert` and `split_off` by index are `O(log n)`.
+#[cfg(feature = "indexed")]
+pub mod indexed {
+    pub use ninth::*;
+}
+
+/// [`rc`] made thread-safe with `Arc` and `Mutex`, every method takes `&self` and blocks. Needs `std`.
+#[cfg(feature = "shared")]
+pub mod shared {
+    pub use tenth::*;
+}
+
+/// A persistent deque made of two immutable `Rc` lists, `clone()` is `O(1)` and shares every node.
+#[cfg(feature = "functional")]
+pub mod functional {
+    pub use eleventh::*;
+}
+
+/// Like [`arena`], but the nodes live in a `bumpalo::Bump` that can be reset between generations.
+#[cfg(feature = "bump")]
+pub mod bump {
+    pub use twelfth::*;
+}
+
+/// Like [`slotmap`], but in a `Slab` with plain `usize` keys and no generations to catch stale ones.
+#[cfg(feature = "slab")]
+pub mod slab {
+    pub use thirteenth::*;
+}
+
+/// Like [`slotmap`], but in a `generational_arena::Arena`, stale keys are caught by a generation.
+#[cfg(feature = "generational")]
+pub mod generational {
+    pub use fourteenth::*;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bumpalo::Bump;
+    use ghost_cell::GhostToken;
+    use typed_arena::Arena;
+
+    #[test]
+    fn reexports() {
+        let mut list = rc::LinkedList::new();
+        list.push_back(1);
+        assert_eq!(list.pop_front(), Some(1));
+
+        let mut list = slotmap::LinkedList::new();
+        list.push_back(1);
+        assert_eq!(list.pop_front(), Some(1));
+
+        let mut list = compact::LinkedList::new();
+        list.push_back(1);
+        assert_eq!(list.pop_front(), Some(1));
+
+        let mut list = ring::LinkedList::new();
+        list.push_back(1);
+        assert_eq!(list.pop_front(), Some(1));
+
+        let mut list = indexed::LinkedList::new();
+        list.push_back(1);
+        assert_eq!(list.pop_front(), Some(1));
+
+        let list = shared::LinkedList::new();
+        list.push_back(1);
+        assert_eq!(list.pop_front(), Some(1));
+
+        let mut list = functional::LinkedList::new();
+        list.push_back(1);
+        assert_eq!(list.pop_front(), Some(1));
+
+        let mut list = slab::LinkedList::new();
+        list.push_back(1);
+        assert_eq!(list.pop_front(), Some(1));
+
+        let mut list = generational::LinkedList::new();
+        list.push_back(1);
+        assert_eq!(list.pop_front(), Some(1));
+
+        GhostToken::new(|ref mut token| {
+            let mut list = ghostcell::LinkedList::new();
+            list.push_back(1, token);
+            assert_eq!(list.pop_front(token), Some(1));
+
+            let arena = Arena::new();
+            let mut list = arena::LinkedList::new(&arena);
+            list.push_back(1, token);
+            assert_eq!(list.pop_front(token), Some(1));
+
+            let arena = Arena::new();
+            let mut list = arena_rc::LinkedList::new(&arena);
+            list.push_back(1, token);
+            assert_eq!(list.pop_front(token), Some(1));
+
+            let list = owned_arena::LinkedList::new();
+            list.push_back(1, token);
+            assert_eq!(list.pop_front(token), Some(1));
+
+            let bump = Bump::new();
+            let mut list = bump::LinkedList::new(&bump);
+            list.push_back(1, token);
+            assert_eq!(list.pop_front(token), Some(1));
+        });
+    }
+}
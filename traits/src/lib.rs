@@ -0,0 +1,1028 @@
+#![cfg_attr(not(test), no_std)]
+#![forbid(unsafe_code)]
+
+//! A common interface over every list variant, so benchmarks, tests and downstream code can be
+//! written once and run against all of them.
+//!
+//! The variants differ in what has to be passed alongside the list (nothing, a `GhostToken`, ...),
+//! that's the [`ListOps::Context`].
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use ghost_cell::GhostToken;
+
+pub trait ListOps<T> {
+    /// What every call needs besides the list itself, `()` for lists that don't need anything.
+    type Context;
+
+    fn push_front(&mut self, value: T, cx: &mut Self::Context);
+    fn push_back(&mut self, value: T, cx: &mut Self::Context);
+    fn pop_front(&mut self, cx: &mut Self::Context) -> Option<T>;
+    fn pop_back(&mut self, cx: &mut Self::Context) -> Option<T>;
+    fn len(&self, cx: &Self::Context) -> usize;
+
+    /// The elements front to back.
+    fn to_vec(&self, cx: &Self::Context) -> Vec<T>
+    where
+        T: Clone;
+
+    /// Calls `f` on every element front to back.
+    fn for_each<F: FnMut(&T)>(&self, f: F, cx: &Self::Context);
+
+    /// Calls `f` on every element back to front.
+    fn for_each_rev<F: FnMut(&T)>(&self, f: F, cx: &Self::Context);
+
+    fn is_empty(&self, cx: &Self::Context) -> bool {
+        self.len(cx) == 0
+    }
+
+    fn clear(&mut self, cx: &mut Self::Context) {
+        while self.pop_front(cx).is_some() {}
+    }
+
+    /// Pops the first element, but only if `pred` returns `true` for it.
+    ///
+    /// By default the element is popped and pushed back if `pred` rejects it.
+    fn pop_front_if<F>(&mut self, pred: F, cx: &mut Self::Context) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        let mut value = self.pop_front(cx)?;
+        if pred(&mut value) {
+            return Some(value);
+        }
+        self.push_front(value, cx);
+        None
+    }
+
+    /// Pops the last element, but only if `pred` returns `true` for it.
+    fn pop_back_if<F>(&mut self, pred: F, cx: &mut Self::Context) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        let mut value = self.pop_back(cx)?;
+        if pred(&mut value) {
+            return Some(value);
+        }
+        self.push_back(value, cx);
+        None
+    }
+
+    /// Inserts `value` at `index`, `index <= len`.
+    ///
+    /// By default everything from `index` on is moved off the back and pushed again afterwards.
+    fn insert(&mut self, index: usize, value: T, cx: &mut Self::Context) {
+        let back = self.split_off(index, cx);
+        self.push_back(value, cx);
+        self.append(back, cx);
+    }
+
+    /// Removes the element at `index`, `index < len`.
+    fn remove(&mut self, index: usize, cx: &mut Self::Context) -> Option<T> {
+        let mut back = self.split_off(index, cx).into_iter();
+        let removed = back.next();
+        self.append(back.collect(), cx);
+        removed
+    }
+
+    /// Pushes `values` to the back in order.
+    fn append(&mut self, values: Vec<T>, cx: &mut Self::Context) {
+        for value in values {
+            self.push_back(value, cx);
+        }
+    }
+
+    /// Removes everything from `index` on, `index <= len`, and returns it front to back.
+    fn split_off(&mut self, index: usize, cx: &mut Self::Context) -> Vec<T> {
+        let mut back: Vec<T> = (index..self.len(cx))
+            .filter_map(|_| self.pop_back(cx))
+            .collect();
+        back.reverse();
+        back
+    }
+
+    /// Encodes the elements front to back in the shared `snapshot` format.
+    #[cfg(feature = "snapshot")]
+    fn snapshot(&self, cx: &Self::Context) -> Result<Vec<u8>, snapshot::Error>
+    where
+        T: Clone + snapshot::serde::Serialize,
+    {
+        snapshot::encode(&self.to_vec(cx))
+    }
+
+    /// Appends the elements of a snapshot taken from any variant to the back of the list.
+    ///
+    /// The whole snapshot is decoded first, so nothing is appended if it is malformed.
+    #[cfg(feature = "snapshot")]
+    fn restore<'de>(
+        &mut self,
+        bytes: &'de [u8],
+        cx: &mut Self::Context,
+    ) -> Result<(), snapshot::Error>
+    where
+        T: snapshot::serde::Deserialize<'de>,
+    {
+        let values = snapshot::decode(bytes)?;
+        let mut decoded = Vec::with_capacity(values.capacity_hint());
+        for value in values {
+            decoded.push(value?);
+        }
+
+        for value in decoded {
+            self.push_back(value, cx);
+        }
+        Ok(())
+    }
+}
+
+/// The reference the variants are checked against.
+impl<T> ListOps<T> for VecDeque<T> {
+    type Context = ();
+
+    fn push_front(&mut self, value: T, _: &mut ()) {
+        VecDeque::push_front(self, value)
+    }
+
+    fn push_back(&mut self, value: T, _: &mut ()) {
+        VecDeque::push_back(self, value)
+    }
+
+    fn pop_front(&mut self, _: &mut ()) -> Option<T> {
+        VecDeque::pop_front(self)
+    }
+
+    fn pop_back(&mut self, _: &mut ()) -> Option<T> {
+        VecDeque::pop_back(self)
+    }
+
+    fn len(&self, _: &()) -> usize {
+        VecDeque::len(self)
+    }
+
+    fn to_vec(&self, _: &()) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    fn for_each<F: FnMut(&T)>(&self, f: F, _: &()) {
+        self.iter().for_each(f)
+    }
+
+    fn for_each_rev<F: FnMut(&T)>(&self, f: F, _: &()) {
+        self.iter().rev().for_each(f)
+    }
+
+    fn clear(&mut self, _: &mut ()) {
+        VecDeque::clear(self)
+    }
+
+    fn insert(&mut self, index: usize, value: T, _: &mut ()) {
+        VecDeque::insert(self, index, value)
+    }
+
+    fn remove(&mut self, index: usize, _: &mut ()) -> Option<T> {
+        VecDeque::remove(self, index)
+    }
+
+    fn append(&mut self, values: Vec<T>, _: &mut ()) {
+        self.extend(values)
+    }
+
+    fn split_off(&mut self, index: usize, _: &mut ()) -> Vec<T> {
+        VecDeque::split_off(self, index).into()
+    }
+}
+
+impl<T> ListOps<T> for first::LinkedList<T> {
+    type Context = ();
+
+    fn push_front(&mut self, value: T, _: &mut ()) {
+        first::LinkedList::push_front(self, value)
+    }
+
+    fn push_back(&mut self, value: T, _: &mut ()) {
+        first::LinkedList::push_back(self, value)
+    }
+
+    fn pop_front(&mut self, _: &mut ()) -> Option<T> {
+        first::LinkedList::pop_front(self)
+    }
+
+    fn pop_back(&mut self, _: &mut ()) -> Option<T> {
+        first::LinkedList::pop_back(self)
+    }
+
+    fn len(&self, _: &()) -> usize {
+        first::LinkedList::len(self)
+    }
+
+    fn to_vec(&self, _: &()) -> Vec<T>
+    where
+        T: Clone,
+    {
+        first::LinkedList::to_vec(self)
+    }
+
+    fn for_each<F: FnMut(&T)>(&self, f: F, _: &()) {
+        first::LinkedList::for_each(self, f)
+    }
+
+    fn for_each_rev<F: FnMut(&T)>(&self, f: F, _: &()) {
+        first::LinkedList::for_each_rev(self, f)
+    }
+
+    fn clear(&mut self, _: &mut ()) {
+        first::LinkedList::clear(self)
+    }
+}
+
+impl<'id, T> ListOps<T> for second::LinkedList<'id, T> {
+    type Context = GhostToken<'id>;
+
+    fn push_front(&mut self, value: T, token: &mut GhostToken<'id>) {
+        second::LinkedList::push_front(self, value, token)
+    }
+
+    fn push_back(&mut self, value: T, token: &mut GhostToken<'id>) {
+        second::LinkedList::push_back(self, value, token)
+    }
+
+    fn pop_front(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+        second::LinkedList::pop_front(self, token)
+    }
+
+    fn pop_back(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+        second::LinkedList::pop_back(self, token)
+    }
+
+    fn len(&self, _: &GhostToken<'id>) -> usize {
+        second::LinkedList::len(self)
+    }
+
+    fn to_vec(&self, token: &GhostToken<'id>) -> Vec<T>
+    where
+        T: Clone,
+    {
+        second::LinkedList::to_vec(self, token)
+    }
+
+    fn for_each<F: FnMut(&T)>(&self, f: F, token: &GhostToken<'id>) {
+        self.iter(token).for_each(f)
+    }
+
+    fn for_each_rev<F: FnMut(&T)>(&self, f: F, token: &GhostToken<'id>) {
+        self.iter(token).rev().for_each(f)
+    }
+
+    fn clear(&mut self, token: &mut GhostToken<'id>) {
+        second::LinkedList::clear(self, token)
+    }
+
+    fn pop_front_if<F>(&mut self, pred: F, token: &mut GhostToken<'id>) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        second::LinkedList::pop_front_if(self, pred, token)
+    }
+
+    fn pop_back_if<F>(&mut self, pred: F, token: &mut GhostToken<'id>) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        second::LinkedList::pop_back_if(self, pred, token)
+    }
+}
+
+impl<T> ListOps<T> for third::LinkedList<T> {
+    type Context = ();
+
+    fn push_front(&mut self, value: T, _: &mut ()) {
+        third::LinkedList::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T, _: &mut ()) {
+        third::LinkedList::push_back(self, value);
+    }
+
+    fn pop_front(&mut self, _: &mut ()) -> Option<T> {
+        third::LinkedList::pop_front(self)
+    }
+
+    fn pop_back(&mut self, _: &mut ()) -> Option<T> {
+        third::LinkedList::pop_back(self)
+    }
+
+    fn len(&self, _: &()) -> usize {
+        third::LinkedList::len(self)
+    }
+
+    fn to_vec(&self, _: &()) -> Vec<T>
+    where
+        T: Clone,
+    {
+        third::LinkedList::to_vec(self)
+    }
+
+    fn for_each<F: FnMut(&T)>(&self, f: F, _: &()) {
+        self.iter().for_each(f)
+    }
+
+    fn for_each_rev<F: FnMut(&T)>(&self, f: F, _: &()) {
+        self.iter().rev().for_each(f)
+    }
+
+    fn clear(&mut self, _: &mut ()) {
+        third::LinkedList::clear(self)
+    }
+
+    fn insert(&mut self, index: usize, value: T, _: &mut ()) {
+        third::LinkedList::insert(self, index, value);
+    }
+
+    fn remove(&mut self, index: usize, _: &mut ()) -> Option<T> {
+        third::LinkedList::remove(self, index)
+    }
+
+    fn append(&mut self, values: Vec<T>, _: &mut ()) {
+        let mut other = values.into_iter().collect();
+        third::LinkedList::append(self, &mut other)
+    }
+}
+
+impl<'arena, 'id, T> ListOps<T> for fourth::LinkedList<'arena, 'id, T> {
+    type Context = GhostToken<'id>;
+
+    fn push_front(&mut self, value: T, token: &mut GhostToken<'id>) {
+        fourth::LinkedList::push_front(self, value, token)
+    }
+
+    fn push_back(&mut self, value: T, token: &mut GhostToken<'id>) {
+        fourth::LinkedList::push_back(self, value, token)
+    }
+
+    fn pop_front(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+        fourth::LinkedList::pop_front(self, token)
+    }
+
+    fn pop_back(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+        fourth::LinkedList::pop_back(self, token)
+    }
+
+    fn len(&self, _: &GhostToken<'id>) -> usize {
+        fourth::LinkedList::len(self)
+    }
+
+    fn to_vec(&self, token: &GhostToken<'id>) -> Vec<T>
+    where
+        T: Clone,
+    {
+        fourth::LinkedList::to_vec(self, token)
+    }
+
+    fn for_each<F: FnMut(&T)>(&self, f: F, token: &GhostToken<'id>) {
+        self.iter(token).for_each(f)
+    }
+
+    fn for_each_rev<F: FnMut(&T)>(&self, f: F, token: &GhostToken<'id>) {
+        self.iter(token).rev().for_each(f)
+    }
+
+    fn clear(&mut self, token: &mut GhostToken<'id>) {
+        fourth::LinkedList::clear(self, token)
+    }
+
+    fn pop_front_if<F>(&mut self, pred: F, token: &mut GhostToken<'id>) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        fourth::LinkedList::pop_front_if(self, pred, token)
+    }
+
+    fn pop_back_if<F>(&mut self, pred: F, token: &mut GhostToken<'id>) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        fourth::LinkedList::pop_back_if(self, pred, token)
+    }
+
+    fn insert(&mut self, index: usize, value: T, token: &mut GhostToken<'id>) {
+        self.cursor_at_mut(index, token).insert_before(value)
+    }
+
+    fn remove(&mut self, index: usize, token: &mut GhostToken<'id>) -> Option<T> {
+        self.cursor_at_mut(index, token).remove_current()
+    }
+}
+
+impl<'arena, 'id, T> ListOps<T> for fifth::LinkedList<'arena, 'id, T> {
+    type Context = GhostToken<'id>;
+
+    fn push_front(&mut self, value: T, token: &mut GhostToken<'id>) {
+        fifth::LinkedList::push_front(self, value, token)
+    }
+
+    fn push_back(&mut self, value: T, token: &mut GhostToken<'id>) {
+        fifth::LinkedList::push_back(self, value, token)
+    }
+
+    fn pop_front(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+        fifth::LinkedList::pop_front(self, token)
+    }
+
+    fn pop_back(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+        fifth::LinkedList::pop_back(self, token)
+    }
+
+    fn len(&self, _: &GhostToken<'id>) -> usize {
+        fifth::LinkedList::len(self)
+    }
+
+    fn to_vec(&self, token: &GhostToken<'id>) -> Vec<T>
+    where
+        T: Clone,
+    {
+        fifth::LinkedList::to_vec(self, token)
+    }
+
+    fn for_each<F: FnMut(&T)>(&self, f: F, token: &GhostToken<'id>) {
+        self.iter(token).for_each(f)
+    }
+
+    fn for_each_rev<F: FnMut(&T)>(&self, f: F, token: &GhostToken<'id>) {
+        self.iter(token).rev().for_each(f)
+    }
+
+    fn is_empty(&self, _: &GhostToken<'id>) -> bool {
+        fifth::LinkedList::is_empty(self)
+    }
+
+    fn clear(&mut self, token: &mut GhostToken<'id>) {
+        fifth::LinkedList::clear(self, token)
+    }
+
+    fn pop_front_if<F>(&mut self, pred: F, token: &mut GhostToken<'id>) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        fifth::LinkedList::pop_front_if(self, pred, token)
+    }
+
+    fn pop_back_if<F>(&mut self, pred: F, token: &mut GhostToken<'id>) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        fifth::LinkedList::pop_back_if(self, pred, token)
+    }
+}
+
+/// `sixth` only needs shared access and has to be borrowed for `'arena`, so the trait is
+/// implemented for that borrow.
+impl<'arena, 'id, T> ListOps<T> for &'arena sixth::LinkedList<'arena, 'id, T> {
+    type Context = GhostToken<'id>;
+
+    fn push_front(&mut self, value: T, token: &mut GhostToken<'id>) {
+        sixth::LinkedList::push_front(self, value, token)
+    }
+
+    fn push_back(&mut self, value: T, token: &mut GhostToken<'id>) {
+        sixth::LinkedList::push_back(self, value, token)
+    }
+
+    fn pop_front(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+        sixth::LinkedList::pop_front(self, token)
+    }
+
+    fn pop_back(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+        sixth::LinkedList::pop_back(self, token)
+    }
+
+    fn len(&self, _: &GhostToken<'id>) -> usize {
+        sixth::LinkedList::len(self)
+    }
+
+    fn to_vec(&self, token: &GhostToken<'id>) -> Vec<T>
+    where
+        T: Clone,
+    {
+        sixth::LinkedList::to_vec(self, token)
+    }
+
+    fn for_each<F: FnMut(&T)>(&self, f: F, token: &GhostToken<'id>) {
+        self.iter(token).for_each(f)
+    }
+
+    fn for_each_rev<F: FnMut(&T)>(&self, f: F, token: &GhostToken<'id>) {
+        self.iter(token).rev().for_each(f)
+    }
+
+    fn is_empty(&self, _: &GhostToken<'id>) -> bool {
+        sixth::LinkedList::is_empty(self)
+    }
+
+    fn clear(&mut self, token: &mut GhostToken<'id>) {
+        sixth::LinkedList::clear(self, token)
+    }
+
+    fn pop_front_if<F>(&mut self, pred: F, token: &mut GhostToken<'id>) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        sixth::LinkedList::pop_front_if(self, pred, token)
+    }
+
+    fn pop_back_if<F>(&mut self, pred: F, token: &mut GhostToken<'id>) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        sixth::LinkedList::pop_back_if(self, pred, token)
+    }
+}
+
+impl<T> ListOps<T> for seventh::LinkedList<T> {
+    type Context = ();
+
+    fn push_front(&mut self, value: T, _: &mut ()) {
+        seventh::LinkedList::push_front(self, value)
+    }
+
+    fn push_back(&mut self, value: T, _: &mut ()) {
+        seventh::LinkedList::push_back(self, value)
+    }
+
+    fn pop_front(&mut self, _: &mut ()) -> Option<T> {
+        seventh::LinkedList::pop_front(self)
+    }
+
+    fn pop_back(&mut self, _: &mut ()) -> Option<T> {
+        seventh::LinkedList::pop_back(self)
+    }
+
+    fn len(&self, _: &()) -> usize {
+        seventh::LinkedList::len(self)
+    }
+
+    fn to_vec(&self, _: &()) -> Vec<T>
+    where
+        T: Clone,
+    {
+        seventh::LinkedList::to_vec(self)
+    }
+
+    fn for_each<F: FnMut(&T)>(&self, f: F, _: &()) {
+        self.iter().for_each(f)
+    }
+
+    fn for_each_rev<F: FnMut(&T)>(&self, f: F, _: &()) {
+        self.iter().rev().for_each(f)
+    }
+
+    fn is_empty(&self, _: &()) -> bool {
+        seventh::LinkedList::is_empty(self)
+    }
+
+    fn clear(&mut self, _: &mut ()) {
+        seventh::LinkedList::clear(self)
+    }
+}
+
+impl<T> ListOps<T> for eighth::LinkedList<T> {
+    type Context = ();
+
+    fn push_front(&mut self, value: T, _: &mut ()) {
+        eighth::LinkedList::push_front(self, value)
+    }
+
+    fn push_back(&mut self, value: T, _: &mut ()) {
+        eighth::LinkedList::push_back(self, value)
+    }
+
+    fn pop_front(&mut self, _: &mut ()) -> Option<T> {
+        eighth::LinkedList::pop_front(self)
+    }
+
+    fn pop_back(&mut self, _: &mut ()) -> Option<T> {
+        eighth::LinkedList::pop_back(self)
+    }
+
+    fn len(&self, _: &()) -> usize {
+        eighth::LinkedList::len(self)
+    }
+
+    fn to_vec(&self, _: &()) -> Vec<T>
+    where
+        T: Clone,
+    {
+        eighth::LinkedList::to_vec(self)
+    }
+
+    fn for_each<F: FnMut(&T)>(&self, f: F, _: &()) {
+        self.iter().for_each(f)
+    }
+
+    fn for_each_rev<F: FnMut(&T)>(&self, f: F, _: &()) {
+        self.iter().rev().for_each(f)
+    }
+
+    fn is_empty(&self, _: &()) -> bool {
+        eighth::LinkedList::is_empty(self)
+    }
+
+    fn clear(&mut self, _: &mut ()) {
+        eighth::LinkedList::clear(self)
+    }
+}
+
+impl<T> ListOps<T> for ninth::LinkedList<T> {
+    type Context = ();
+
+    fn push_front(&mut self, value: T, _: &mut ()) {
+        ninth::LinkedList::push_front(self, value)
+    }
+
+    fn push_back(&mut self, value: T, _: &mut ()) {
+        ninth::LinkedList::push_back(self, value)
+    }
+
+    fn pop_front(&mut self, _: &mut ()) -> Option<T> {
+        ninth::LinkedList::pop_front(self)
+    }
+
+    fn pop_back(&mut self, _: &mut ()) -> Option<T> {
+        ninth::LinkedList::pop_back(self)
+    }
+
+    fn len(&self, _: &()) -> usize {
+        ninth::LinkedList::len(self)
+    }
+
+    fn to_vec(&self, _: &()) -> Vec<T>
+    where
+        T: Clone,
+    {
+        ninth::LinkedList::to_vec(self)
+    }
+
+    fn for_each<F: FnMut(&T)>(&self, f: F, _: &()) {
+        self.iter().for_each(f)
+    }
+
+    fn for_each_rev<F: FnMut(&T)>(&self, f: F, _: &()) {
+        self.iter().rev().for_each(f)
+    }
+
+    fn is_empty(&self, _: &()) -> bool {
+        ninth::LinkedList::is_empty(self)
+    }
+
+    fn clear(&mut self, _: &mut ()) {
+        ninth::LinkedList::clear(self)
+    }
+
+    fn insert(&mut self, index: usize, value: T, _: &mut ()) {
+        ninth::LinkedList::insert(self, index, value)
+    }
+
+    fn remove(&mut self, index: usize, _: &mut ()) -> Option<T> {
+        ninth::LinkedList::remove(self, index)
+    }
+
+    fn append(&mut self, values: Vec<T>, _: &mut ()) {
+        let mut other = values.into_iter().collect();
+        ninth::LinkedList::append(self, &mut other)
+    }
+
+    fn split_off(&mut self, index: usize, _: &mut ()) -> Vec<T> {
+        ninth::LinkedList::split_off(self, index)
+            .into_iter()
+            .collect()
+    }
+}
+
+impl<T> ListOps<T> for tenth::LinkedList<T> {
+    type Context = ();
+
+    fn push_front(&mut self, value: T, _: &mut ()) {
+        tenth::LinkedList::push_front(self, value)
+    }
+
+    fn push_back(&mut self, value: T, _: &mut ()) {
+        tenth::LinkedList::push_back(self, value)
+    }
+
+    fn pop_front(&mut self, _: &mut ()) -> Option<T> {
+        tenth::LinkedList::pop_front(self)
+    }
+
+    fn pop_back(&mut self, _: &mut ()) -> Option<T> {
+        tenth::LinkedList::pop_back(self)
+    }
+
+    fn len(&self, _: &()) -> usize {
+        tenth::LinkedList::len(self)
+    }
+
+    fn to_vec(&self, _: &()) -> Vec<T>
+    where
+        T: Clone,
+    {
+        tenth::LinkedList::to_vec(self)
+    }
+
+    fn for_each<F: FnMut(&T)>(&self, mut f: F, _: &()) {
+        let mut cursor = self.lock();
+        loop {
+            match cursor.current() {
+                Some(value) => f(&value),
+                None => break,
+            }
+            cursor.move_next();
+        }
+    }
+
+    fn for_each_rev<F: FnMut(&T)>(&self, mut f: F, _: &()) {
+        let mut cursor = self.lock();
+        // from the front through the ghost position to the back
+        cursor.move_prev();
+        cursor.move_prev();
+        loop {
+            match cursor.current() {
+                Some(value) => f(&value),
+                None => break,
+            }
+            cursor.move_prev();
+        }
+    }
+
+    fn is_empty(&self, _: &()) -> bool {
+        tenth::LinkedList::is_empty(self)
+    }
+
+    fn clear(&mut self, _: &mut ()) {
+        tenth::LinkedList::clear(self)
+    }
+
+    fn insert(&mut self, index: usize, value: T, _: &mut ()) {
+        let mut cursor = self.lock();
+        // the ghost position before the front, then on to the element before `index`
+        cursor.move_prev();
+        for _ in 0..index {
+            cursor.move_next();
+        }
+        cursor.insert_after(value)
+    }
+
+    fn remove(&mut self, index: usize, _: &mut ()) -> Option<T> {
+        let mut cursor = self.lock();
+        for _ in 0..index {
+            cursor.move_next();
+        }
+        cursor.remove_current()
+    }
+}
+
+impl<T: Clone> ListOps<T> for eleventh::LinkedList<T> {
+    type Context = ();
+
+    fn push_front(&mut self, value: T, _: &mut ()) {
+        eleventh::LinkedList::push_front(self, value)
+    }
+
+    fn push_back(&mut self, value: T, _: &mut ()) {
+        eleventh::LinkedList::push_back(self, value)
+    }
+
+    fn pop_front(&mut self, _: &mut ()) -> Option<T> {
+        eleventh::LinkedList::pop_front(self)
+    }
+
+    fn pop_back(&mut self, _: &mut ()) -> Option<T> {
+        eleventh::LinkedList::pop_back(self)
+    }
+
+    fn len(&self, _: &()) -> usize {
+        eleventh::LinkedList::len(self)
+    }
+
+    fn to_vec(&self, _: &()) -> Vec<T>
+    where
+        T: Clone,
+    {
+        eleventh::LinkedList::to_vec(self)
+    }
+
+    fn for_each<F: FnMut(&T)>(&self, f: F, _: &()) {
+        self.iter().for_each(f)
+    }
+
+    /// The iterator only goes front to back, so the elements are collected first.
+    fn for_each_rev<F: FnMut(&T)>(&self, f: F, _: &()) {
+        self.iter()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .for_each(f)
+    }
+
+    fn is_empty(&self, _: &()) -> bool {
+        eleventh::LinkedList::is_empty(self)
+    }
+
+    fn clear(&mut self, _: &mut ()) {
+        eleventh::LinkedList::clear(self)
+    }
+}
+
+impl<'arena, 'id, T> ListOps<T> for twelfth::LinkedList<'arena, 'id, T> {
+    type Context = GhostToken<'id>;
+
+    fn push_front(&mut self, value: T, token: &mut GhostToken<'id>) {
+        twelfth::LinkedList::push_front(self, value, token)
+    }
+
+    fn push_back(&mut self, value: T, token: &mut GhostToken<'id>) {
+        twelfth::LinkedList::push_back(self, value, token)
+    }
+
+    fn pop_front(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+        twelfth::LinkedList::pop_front(self, token)
+    }
+
+    fn pop_back(&mut self, token: &mut GhostToken<'id>) -> Option<T> {
+        twelfth::LinkedList::pop_back(self, token)
+    }
+
+    fn len(&self, _: &GhostToken<'id>) -> usize {
+        twelfth::LinkedList::len(self)
+    }
+
+    fn to_vec(&self, token: &GhostToken<'id>) -> Vec<T>
+    where
+        T: Clone,
+    {
+        twelfth::LinkedList::to_vec(self, token)
+    }
+
+    fn for_each<F: FnMut(&T)>(&self, f: F, token: &GhostToken<'id>) {
+        self.iter(token).for_each(f)
+    }
+
+    fn for_each_rev<F: FnMut(&T)>(&self, f: F, token: &GhostToken<'id>) {
+        self.iter(token).rev().for_each(f)
+    }
+
+    fn clear(&mut self, token: &mut GhostToken<'id>) {
+        twelfth::LinkedList::clear(self, token)
+    }
+}
+
+impl<T> ListOps<T> for thirteenth::LinkedList<T> {
+    type Context = ();
+
+    fn push_front(&mut self, value: T, _: &mut ()) {
+        thirteenth::LinkedList::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T, _: &mut ()) {
+        thirteenth::LinkedList::push_back(self, value);
+    }
+
+    fn pop_front(&mut self, _: &mut ()) -> Option<T> {
+        thirteenth::LinkedList::pop_front(self)
+    }
+
+    fn pop_back(&mut self, _: &mut ()) -> Option<T> {
+        thirteenth::LinkedList::pop_back(self)
+    }
+
+    fn len(&self, _: &()) -> usize {
+        thirteenth::LinkedList::len(self)
+    }
+
+    fn to_vec(&self, _: &()) -> Vec<T>
+    where
+        T: Clone,
+    {
+        thirteenth::LinkedList::to_vec(self)
+    }
+
+    fn for_each<F: FnMut(&T)>(&self, f: F, _: &()) {
+        self.iter().for_each(f)
+    }
+
+    fn for_each_rev<F: FnMut(&T)>(&self, f: F, _: &()) {
+        self.iter().rev().for_each(f)
+    }
+
+    fn is_empty(&self, _: &()) -> bool {
+        thirteenth::LinkedList::is_empty(self)
+    }
+
+    fn clear(&mut self, _: &mut ()) {
+        thirteenth::LinkedList::clear(self)
+    }
+}
+
+impl<T> ListOps<T> for fourteenth::LinkedList<T> {
+    type Context = ();
+
+    fn push_front(&mut self, value: T, _: &mut ()) {
+        fourteenth::LinkedList::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T, _: &mut ()) {
+        fourteenth::LinkedList::push_back(self, value);
+    }
+
+    fn pop_front(&mut self, _: &mut ()) -> Option<T> {
+        fourteenth::LinkedList::pop_front(self)
+    }
+
+    fn pop_back(&mut self, _: &mut ()) -> Option<T> {
+        fourteenth::LinkedList::pop_back(self)
+    }
+
+    fn len(&self, _: &()) -> usize {
+        fourteenth::LinkedList::len(self)
+    }
+
+    fn to_vec(&self, _: &()) -> Vec<T>
+    where
+        T: Clone,
+    {
+        fourteenth::LinkedList::to_vec(self)
+    }
+
+    fn for_each<F: FnMut(&T)>(&self, f: F, _: &()) {
+        self.iter().for_each(f)
+    }
+
+    fn for_each_rev<F: FnMut(&T)>(&self, f: F, _: &()) {
+        self.iter().rev().for_each(f)
+    }
+
+    fn is_empty(&self, _: &()) -> bool {
+        fourteenth::LinkedList::is_empty(self)
+    }
+
+    fn clear(&mut self, _: &mut ()) {
+        fourteenth::LinkedList::clear(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bumpalo::Bump;
+    use typed_arena::Arena;
+
+    fn exercise<L: ListOps<i32>>(list: &mut L, cx: &mut L::Context) {
+        assert!(list.is_empty(cx));
+
+        list.push_back(2, cx);
+        list.push_back(3, cx);
+        list.push_front(1, cx);
+        assert_eq!(list.len(cx), 3);
+        assert_eq!(list.to_vec(cx), vec![1, 2, 3]);
+
+        assert_eq!(list.pop_front(cx), Some(1));
+        assert_eq!(list.pop_front(cx), Some(2));
+        assert_eq!(list.pop_back(cx), Some(3));
+        assert_eq!(list.pop_back(cx), None);
+
+        list.push_back(4, cx);
+        list.clear(cx);
+        assert!(list.is_empty(cx));
+    }
+
+    #[test]
+    fn every_variant() {
+        exercise(&mut first::LinkedList::new(), &mut ());
+        exercise(&mut third::LinkedList::new(), &mut ());
+        exercise(&mut seventh::LinkedList::new(), &mut ());
+        exercise(&mut eighth::LinkedList::new(), &mut ());
+        exercise(&mut ninth::LinkedList::new(), &mut ());
+        exercise(&mut tenth::LinkedList::new(), &mut ());
+        exercise(&mut eleventh::LinkedList::new(), &mut ());
+        exercise(&mut thirteenth::LinkedList::new(), &mut ());
+        exercise(&mut fourteenth::LinkedList::new(), &mut ());
+
+        GhostToken::new(|ref mut token| {
+            exercise(&mut second::LinkedList::new(), token);
+
+            let arena = Arena::new();
+            exercise(&mut fourth::LinkedList::new(&arena), token);
+
+            let arena = Arena::new();
+            exercise(&mut fifth::LinkedList::new(&arena), token);
+
+            let list = sixth::LinkedList::new();
+            exercise(&mut &list, token);
+
+            let bump = Bump::new();
+            exercise(&mut twelfth::LinkedList::new(&bump), token);
+        });
+    }
+}